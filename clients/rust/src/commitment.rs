@@ -0,0 +1,278 @@
+//! Produce and verify the commitments stored by Attestations under a Schema
+//! with a commitment scheme.
+
+use std::fmt;
+
+use solana_poseidon::{Endianness, Parameters};
+use solana_program::hash::hashv;
+
+use crate::layout::{
+    scalar_size, split_fields, LayoutError, STRING, VEC_BOOL, VEC_I8, VEC_STRING, VEC_U8,
+};
+
+/// Length of the salt mixed into a commitment.
+pub const SALT_LEN: usize = 32;
+
+/// Values for the `commitment_scheme` argument of `CreateSchema`.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommitmentScheme {
+    /// Attestation data is stored in plaintext.
+    None = 0,
+    /// Attestation data is `sha256(data || salt)`.
+    Sha256 = 1,
+    /// Attestation data is one `sha256(field || salt)` per Schema field.
+    Sha256PerField = 2,
+    /// Attestation data is a Poseidon commitment to the data and a salt.
+    Poseidon = 3,
+}
+
+/// Compute the sha256 commitment to `data`, which must be encoded with the
+/// Schema `layout`. `salt` should be random and kept by the holder until the
+/// data is disclosed, otherwise low-entropy data can be brute forced.
+pub fn sha256_commitment(
+    layout: &[u8],
+    data: &[u8],
+    salt: &[u8; SALT_LEN],
+) -> Result<[u8; 32], LayoutError> {
+    split_fields(layout, data)?;
+    Ok(hashv(&[data, salt.as_slice()]).to_bytes())
+}
+
+/// Verify that the revealed `data` and `salt` open `commitment`, the data
+/// stored in the Attestation. Fails if `data` does not conform to `layout`.
+pub fn verify_sha256_commitment(
+    commitment: &[u8],
+    layout: &[u8],
+    data: &[u8],
+    salt: &[u8; SALT_LEN],
+) -> Result<bool, LayoutError> {
+    Ok(sha256_commitment(layout, data, salt)?.as_slice() == commitment)
+}
+
+/// A single field revealed from an Attestation under a
+/// `CommitmentScheme::Sha256PerField` Schema.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldDisclosure {
+    /// Index of the field in the Schema layout.
+    pub index: usize,
+    /// The field value, encoded with its Schema data type.
+    pub value: Vec<u8>,
+    /// The salt hashed with this field.
+    pub salt: [u8; SALT_LEN],
+}
+
+/// Derive the salt of each of `field_count` fields from `seed`. `seed` should
+/// be random and kept by the holder; disclosing one field's salt does not
+/// reveal the seed or the salts of other fields.
+pub fn derive_field_salts(seed: &[u8; SALT_LEN], field_count: usize) -> Vec<[u8; SALT_LEN]> {
+    (0..field_count as u32)
+        .map(|index| hashv(&[seed.as_slice(), &index.to_le_bytes()]).to_bytes())
+        .collect()
+}
+
+/// Compute the per-field commitment to `data`, which must be encoded with the
+/// Schema `layout`. The result is the Attestation data: one 32 byte hash per
+/// field, ordered by `layout`.
+pub fn per_field_commitment(
+    layout: &[u8],
+    data: &[u8],
+    seed: &[u8; SALT_LEN],
+) -> Result<Vec<u8>, LayoutError> {
+    let fields = split_fields(layout, data)?;
+    let salts = derive_field_salts(seed, fields.len());
+    Ok(fields
+        .iter()
+        .zip(&salts)
+        .flat_map(|(field, salt)| hashv(&[*field, salt.as_slice()]).to_bytes())
+        .collect())
+}
+
+/// Build the disclosures of the fields at `indices` of `data`, which must be
+/// encoded with the Schema `layout`.
+pub fn disclose_fields(
+    layout: &[u8],
+    data: &[u8],
+    seed: &[u8; SALT_LEN],
+    indices: &[usize],
+) -> Result<Vec<FieldDisclosure>, LayoutError> {
+    let fields = split_fields(layout, data)?;
+    let salts = derive_field_salts(seed, fields.len());
+    indices
+        .iter()
+        .map(|&index| {
+            let value = fields.get(index).ok_or(LayoutError::InvalidField(index))?;
+            Ok(FieldDisclosure {
+                index,
+                value: value.to_vec(),
+                salt: salts[index],
+            })
+        })
+        .collect()
+}
+
+/// Verify that `disclosure` matches the hash at its index in `commitment`, the
+/// data stored in the Attestation. Fails if the disclosed value does not
+/// conform to the field's type in `layout`.
+pub fn verify_field_disclosure(
+    commitment: &[u8],
+    layout: &[u8],
+    disclosure: &FieldDisclosure,
+) -> Result<bool, LayoutError> {
+    let data_type = layout
+        .get(disclosure.index)
+        .ok_or(LayoutError::InvalidField(disclosure.index))?;
+    split_fields(&[*data_type], &disclosure.value).map_err(|err| match err {
+        LayoutError::InvalidDataType(_) => err,
+        _ => LayoutError::InvalidField(disclosure.index),
+    })?;
+
+    if commitment.len() != layout.len() * 32 {
+        return Ok(false);
+    }
+    let start = disclosure.index * 32;
+    let hash = hashv(&[&disclosure.value, disclosure.salt.as_slice()]);
+    Ok(commitment[start..start + 32] == hash.to_bytes())
+}
+
+/// Modulus of the BN254 scalar field, big-endian. The program rejects
+/// Poseidon commitments that are not below it.
+pub const BN254_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// Field elements absorbed per Poseidon hash, leaving one input for the
+/// running commitment. The syscall accepts at most 12 inputs.
+pub const POSEIDON_RATE: usize = 11;
+
+/// Error returned when a Poseidon commitment cannot be computed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PoseidonCommitmentError {
+    /// The data does not conform to the Schema layout.
+    Layout(LayoutError),
+    /// The salt is not a BN254 field element.
+    InvalidSalt,
+}
+
+impl fmt::Display for PoseidonCommitmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PoseidonCommitmentError::Layout(err) => err.fmt(f),
+            PoseidonCommitmentError::InvalidSalt => write!(f, "salt is not a field element"),
+        }
+    }
+}
+
+impl std::error::Error for PoseidonCommitmentError {}
+
+impl From<LayoutError> for PoseidonCommitmentError {
+    fn from(err: LayoutError) -> Self {
+        PoseidonCommitmentError::Layout(err)
+    }
+}
+
+/// Reduce 32 random bytes to a salt that is a BN254 field element by
+/// clearing the top bits.
+pub fn poseidon_salt(mut random: [u8; SALT_LEN]) -> [u8; SALT_LEN] {
+    random[0] &= 0x1f;
+    random
+}
+
+/// BN254 field elements encoding `data`, which must be encoded with the Schema
+/// `layout`, as 32 byte big-endian values. This is the encoding
+/// [`poseidon_commitment`] commits to:
+/// - integers, bools and chars are one element holding the little-endian
+///   value read as unsigned (two's complement for signed integers);
+/// - Strings, Vec<u8>, Vec<i8> and Vec<bool> are their length followed by
+///   their bytes packed 31 per element;
+/// - other Vecs are their length followed by the elements of each item.
+pub fn poseidon_field_elements(layout: &[u8], data: &[u8]) -> Result<Vec<[u8; 32]>, LayoutError> {
+    let mut elements = Vec::new();
+    for (data_type, value) in layout.iter().zip(split_fields(layout, data)?) {
+        push_field_elements(*data_type, value, &mut elements);
+    }
+    Ok(elements)
+}
+
+/// Compute the Poseidon commitment to `data`, which must be encoded with the
+/// Schema `layout`, that is the data of an Attestation of a Poseidon Schema.
+/// The program does not recompute it, so this is the only encoder; it only
+/// checks the commitment is below [`BN254_MODULUS`].
+pub fn poseidon_commitment(
+    layout: &[u8],
+    data: &[u8],
+    salt: &[u8; SALT_LEN],
+) -> Result<[u8; 32], PoseidonCommitmentError> {
+    let elements = poseidon_field_elements(layout, data)?;
+    // Always hash at least once so the salt is never stored as is.
+    let chunks: Vec<&[[u8; 32]]> = if elements.is_empty() {
+        vec![&[]]
+    } else {
+        elements.chunks(POSEIDON_RATE).collect()
+    };
+
+    let mut acc = *salt;
+    for chunk in chunks {
+        let mut inputs: Vec<&[u8]> = Vec::with_capacity(chunk.len() + 1);
+        inputs.push(&acc);
+        inputs.extend(chunk.iter().map(|element| element.as_slice()));
+        acc = solana_poseidon::hashv(Parameters::Bn254X5, Endianness::BigEndian, &inputs)
+            .map_err(|_| PoseidonCommitmentError::InvalidSalt)?
+            .to_bytes();
+    }
+    Ok(acc)
+}
+
+/// Verify that the revealed `data` and `salt` open `commitment`, the data
+/// stored in a Poseidon Attestation.
+pub fn verify_poseidon_commitment(
+    commitment: &[u8],
+    layout: &[u8],
+    data: &[u8],
+    salt: &[u8; SALT_LEN],
+) -> Result<bool, PoseidonCommitmentError> {
+    Ok(poseidon_commitment(layout, data, salt)?.as_slice() == commitment)
+}
+
+fn push_field_elements(data_type: u8, value: &[u8], elements: &mut Vec<[u8; 32]>) {
+    match data_type {
+        STRING | VEC_U8 | VEC_I8 | VEC_BOOL => {
+            elements.push(scalar_element(&value[..4]));
+            elements.extend(value[4..].chunks(31).map(packed_element));
+        }
+        VEC_STRING => {
+            elements.push(scalar_element(&value[..4]));
+            let mut offset = 4;
+            while offset < value.len() {
+                let len = u32::from_le_bytes(value[offset..offset + 4].try_into().unwrap());
+                let end = offset + 4 + len as usize;
+                push_field_elements(STRING, &value[offset..end], elements);
+                offset = end;
+            }
+        }
+        // Vec element types follow the same order as the scalar types.
+        VEC_U8..=VEC_STRING => {
+            elements.push(scalar_element(&value[..4]));
+            let element_size = scalar_size(data_type - VEC_U8);
+            elements.extend(value[4..].chunks_exact(element_size).map(scalar_element));
+        }
+        _ => elements.push(scalar_element(value)),
+    }
+}
+
+/// Field element holding the little-endian integer `value`.
+fn scalar_element(value: &[u8]) -> [u8; 32] {
+    let mut element = [0; 32];
+    for (i, byte) in value.iter().enumerate() {
+        element[31 - i] = *byte;
+    }
+    element
+}
+
+/// Field element holding up to 31 `bytes` read as a big-endian integer.
+fn packed_element(bytes: &[u8]) -> [u8; 32] {
+    let mut element = [0; 32];
+    element[32 - bytes.len()..].copy_from_slice(bytes);
+    element
+}
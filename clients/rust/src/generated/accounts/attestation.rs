@@ -13,6 +13,7 @@ use solana_program::pubkey::Pubkey;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Attestation {
     pub discriminator: u8,
+    pub layout_version: u8,
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
@@ -40,6 +41,19 @@ pub struct Attestation {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub token_account: Pubkey,
+    pub bump: u8,
+    pub signer_kind: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub ref_attestation: Pubkey,
+    pub ref_count: u32,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub resolver_program: Pubkey,
 }
 
 impl Attestation {
@@ -13,6 +13,7 @@ use solana_program::pubkey::Pubkey;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Credential {
     pub discriminator: u8,
+    pub layout_version: u8,
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
@@ -24,6 +25,15 @@ pub struct Credential {
         serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
     )]
     pub authorized_signers: Vec<Pubkey>,
+    pub bump: u8,
+    pub display_name: Vec<u8>,
+    pub description: Vec<u8>,
+    pub uri: Vec<u8>,
+    pub logo_uri: Vec<u8>,
+    pub website: Vec<u8>,
+    pub is_paused: bool,
+    pub passkey_signers: Vec<[u8; 33]>,
+    pub authorized_signer_kinds: Vec<u8>,
 }
 
 impl Credential {
@@ -6,9 +6,13 @@
 //!
 
 pub(crate) mod r#attestation;
+pub(crate) mod r#attestation_request;
 pub(crate) mod r#credential;
+pub(crate) mod r#merkle_attestation_batch;
 pub(crate) mod r#schema;
 
 pub use self::r#attestation::*;
+pub use self::r#attestation_request::*;
 pub use self::r#credential::*;
+pub use self::r#merkle_attestation_batch::*;
 pub use self::r#schema::*;
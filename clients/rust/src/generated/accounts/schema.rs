@@ -13,6 +13,7 @@ use solana_program::pubkey::Pubkey;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Schema {
     pub discriminator: u8,
+    pub layout_version: u8,
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
@@ -24,6 +25,33 @@ pub struct Schema {
     pub field_names: Vec<u8>,
     pub is_paused: bool,
     pub version: u8,
+    pub field_constraints: Vec<u8>,
+    pub bump: u8,
+    pub commitment_scheme: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub ref_schema: Pubkey,
+    pub ref_close_policy: u8,
+    pub fee: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub fee_mint: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub fee_treasury: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub resolver_program: Pubkey,
+    pub token_metadata_fields: Vec<u8>,
+    pub token_policy: u8,
 }
 
 impl Schema {
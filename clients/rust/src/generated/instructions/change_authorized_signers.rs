@@ -86,6 +86,7 @@ impl Default for ChangeAuthorizedSignersInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeAuthorizedSignersInstructionArgs {
     pub signers: Vec<Pubkey>,
+    pub signer_kinds: Vec<u8>,
 }
 
 /// Instruction builder for `ChangeAuthorizedSigners`.
@@ -103,6 +104,7 @@ pub struct ChangeAuthorizedSignersBuilder {
     credential: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
     signers: Option<Vec<Pubkey>>,
+    signer_kinds: Option<Vec<u8>>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -137,6 +139,12 @@ impl ChangeAuthorizedSignersBuilder {
         self.signers = Some(signers);
         self
     }
+    /// `[optional argument, defaults to 'vec![]']`
+    #[inline(always)]
+    pub fn signer_kinds(&mut self, signer_kinds: Vec<u8>) -> &mut Self {
+        self.signer_kinds = Some(signer_kinds);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -167,6 +175,7 @@ impl ChangeAuthorizedSignersBuilder {
         };
         let args = ChangeAuthorizedSignersInstructionArgs {
             signers: self.signers.clone().expect("signers is not set"),
+            signer_kinds: self.signer_kinds.clone().unwrap_or(vec![]),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -322,6 +331,7 @@ impl<'a, 'b> ChangeAuthorizedSignersCpiBuilder<'a, 'b> {
             credential: None,
             system_program: None,
             signers: None,
+            signer_kinds: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -361,6 +371,12 @@ impl<'a, 'b> ChangeAuthorizedSignersCpiBuilder<'a, 'b> {
         self.instruction.signers = Some(signers);
         self
     }
+    /// `[optional argument, defaults to 'vec![]']`
+    #[inline(always)]
+    pub fn signer_kinds(&mut self, signer_kinds: Vec<u8>) -> &mut Self {
+        self.instruction.signer_kinds = Some(signer_kinds);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -408,6 +424,7 @@ impl<'a, 'b> ChangeAuthorizedSignersCpiBuilder<'a, 'b> {
                 .signers
                 .clone()
                 .expect("signers is not set"),
+            signer_kinds: self.instruction.signer_kinds.clone().unwrap_or(vec![]),
         };
         let instruction = ChangeAuthorizedSignersCpi {
             __program: self.instruction.__program,
@@ -439,6 +456,7 @@ struct ChangeAuthorizedSignersCpiBuilderInstruction<'a, 'b> {
     credential: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     signers: Option<Vec<Pubkey>>,
+    signer_kinds: Option<Vec<u8>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
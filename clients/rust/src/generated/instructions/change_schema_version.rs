@@ -98,6 +98,8 @@ impl Default for ChangeSchemaVersionInstructionData {
 pub struct ChangeSchemaVersionInstructionArgs {
     pub layout: Vec<u8>,
     pub field_names: Vec<String>,
+    pub bump: u8,
+    pub field_constraints: Vec<u8>,
 }
 
 /// Instruction builder for `ChangeSchemaVersion`.
@@ -120,6 +122,8 @@ pub struct ChangeSchemaVersionBuilder {
     system_program: Option<solana_program::pubkey::Pubkey>,
     layout: Option<Vec<u8>>,
     field_names: Option<Vec<String>>,
+    bump: Option<u8>,
+    field_constraints: Option<Vec<u8>>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -172,6 +176,17 @@ impl ChangeSchemaVersionBuilder {
         self.field_names = Some(field_names);
         self
     }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.bump = Some(bump);
+        self
+    }
+    /// `[optional argument, defaults to 'vec![]']`
+    #[inline(always)]
+    pub fn field_constraints(&mut self, field_constraints: Vec<u8>) -> &mut Self {
+        self.field_constraints = Some(field_constraints);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -205,6 +220,8 @@ impl ChangeSchemaVersionBuilder {
         let args = ChangeSchemaVersionInstructionArgs {
             layout: self.layout.clone().expect("layout is not set"),
             field_names: self.field_names.clone().expect("field_names is not set"),
+            bump: self.bump.clone().expect("bump is not set"),
+            field_constraints: self.field_constraints.clone().unwrap_or(vec![]),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -385,6 +402,8 @@ impl<'a, 'b> ChangeSchemaVersionCpiBuilder<'a, 'b> {
             system_program: None,
             layout: None,
             field_names: None,
+            bump: None,
+            field_constraints: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -445,6 +464,17 @@ impl<'a, 'b> ChangeSchemaVersionCpiBuilder<'a, 'b> {
         self.instruction.field_names = Some(field_names);
         self
     }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.instruction.bump = Some(bump);
+        self
+    }
+    /// `[optional argument, defaults to 'vec![]']`
+    #[inline(always)]
+    pub fn field_constraints(&mut self, field_constraints: Vec<u8>) -> &mut Self {
+        self.instruction.field_constraints = Some(field_constraints);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -493,6 +523,8 @@ impl<'a, 'b> ChangeSchemaVersionCpiBuilder<'a, 'b> {
                 .field_names
                 .clone()
                 .expect("field_names is not set"),
+            bump: self.instruction.bump.clone().expect("bump is not set"),
+            field_constraints: self.instruction.field_constraints.clone().unwrap_or(vec![]),
         };
         let instruction = ChangeSchemaVersionCpi {
             __program: self.instruction.__program,
@@ -534,6 +566,8 @@ struct ChangeSchemaVersionCpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     layout: Option<Vec<u8>>,
     field_names: Option<Vec<String>>,
+    bump: Option<u8>,
+    field_constraints: Option<Vec<u8>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
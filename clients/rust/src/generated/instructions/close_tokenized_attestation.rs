@@ -28,20 +28,24 @@ pub struct CloseTokenizedAttestation {
     pub attestation_mint: solana_program::pubkey::Pubkey,
     /// Program derived address used as program signer authority
     pub sas_pda: solana_program::pubkey::Pubkey,
-    /// Associated token account of the related Attestation Token
+    /// Token account holding the Attestation Token
     pub attestation_token_account: solana_program::pubkey::Pubkey,
 
     pub token_program: solana_program::pubkey::Pubkey,
 }
 
 impl CloseTokenizedAttestation {
-    pub fn instruction(&self) -> solana_program::instruction::Instruction {
-        self.instruction_with_remaining_accounts(&[])
+    pub fn instruction(
+        &self,
+        args: CloseTokenizedAttestationInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::arithmetic_side_effects)]
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
+        args: CloseTokenizedAttestationInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
         let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
@@ -89,7 +93,9 @@ impl CloseTokenizedAttestation {
             false,
         ));
         accounts.extend_from_slice(remaining_accounts);
-        let data = borsh::to_vec(&CloseTokenizedAttestationInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&CloseTokenizedAttestationInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
 
         solana_program::instruction::Instruction {
             program_id: crate::SOLANA_ATTESTATION_SERVICE_ID,
@@ -117,6 +123,12 @@ impl Default for CloseTokenizedAttestationInstructionData {
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CloseTokenizedAttestationInstructionArgs {
+    pub attestation_mint_bump: u8,
+}
+
 /// Instruction builder for `CloseTokenizedAttestation`.
 ///
 /// ### Accounts:
@@ -145,6 +157,7 @@ pub struct CloseTokenizedAttestationBuilder {
     sas_pda: Option<solana_program::pubkey::Pubkey>,
     attestation_token_account: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
+    attestation_mint_bump: Option<u8>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -212,7 +225,7 @@ impl CloseTokenizedAttestationBuilder {
         self.sas_pda = Some(sas_pda);
         self
     }
-    /// Associated token account of the related Attestation Token
+    /// Token account holding the Attestation Token
     #[inline(always)]
     pub fn attestation_token_account(
         &mut self,
@@ -227,6 +240,11 @@ impl CloseTokenizedAttestationBuilder {
         self.token_program = Some(token_program);
         self
     }
+    #[inline(always)]
+    pub fn attestation_mint_bump(&mut self, attestation_mint_bump: u8) -> &mut Self {
+        self.attestation_mint_bump = Some(attestation_mint_bump);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -270,8 +288,14 @@ impl CloseTokenizedAttestationBuilder {
                 "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
             )),
         };
+        let args = CloseTokenizedAttestationInstructionArgs {
+            attestation_mint_bump: self
+                .attestation_mint_bump
+                .clone()
+                .expect("attestation_mint_bump is not set"),
+        };
 
-        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
@@ -294,7 +318,7 @@ pub struct CloseTokenizedAttestationCpiAccounts<'a, 'b> {
     pub attestation_mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// Program derived address used as program signer authority
     pub sas_pda: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Associated token account of the related Attestation Token
+    /// Token account holding the Attestation Token
     pub attestation_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
@@ -322,16 +346,19 @@ pub struct CloseTokenizedAttestationCpi<'a, 'b> {
     pub attestation_mint: &'b solana_program::account_info::AccountInfo<'a>,
     /// Program derived address used as program signer authority
     pub sas_pda: &'b solana_program::account_info::AccountInfo<'a>,
-    /// Associated token account of the related Attestation Token
+    /// Token account holding the Attestation Token
     pub attestation_token_account: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: CloseTokenizedAttestationInstructionArgs,
 }
 
 impl<'a, 'b> CloseTokenizedAttestationCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_program::account_info::AccountInfo<'a>,
         accounts: CloseTokenizedAttestationCpiAccounts<'a, 'b>,
+        args: CloseTokenizedAttestationInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -346,6 +373,7 @@ impl<'a, 'b> CloseTokenizedAttestationCpi<'a, 'b> {
             sas_pda: accounts.sas_pda,
             attestation_token_account: accounts.attestation_token_account,
             token_program: accounts.token_program,
+            __args: args,
         }
     }
     #[inline(always)]
@@ -434,7 +462,9 @@ impl<'a, 'b> CloseTokenizedAttestationCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let data = borsh::to_vec(&CloseTokenizedAttestationInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&CloseTokenizedAttestationInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
 
         let instruction = solana_program::instruction::Instruction {
             program_id: crate::SOLANA_ATTESTATION_SERVICE_ID,
@@ -501,6 +531,7 @@ impl<'a, 'b> CloseTokenizedAttestationCpiBuilder<'a, 'b> {
             sas_pda: None,
             attestation_token_account: None,
             token_program: None,
+            attestation_mint_bump: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -577,7 +608,7 @@ impl<'a, 'b> CloseTokenizedAttestationCpiBuilder<'a, 'b> {
         self.instruction.sas_pda = Some(sas_pda);
         self
     }
-    /// Associated token account of the related Attestation Token
+    /// Token account holding the Attestation Token
     #[inline(always)]
     pub fn attestation_token_account(
         &mut self,
@@ -594,6 +625,11 @@ impl<'a, 'b> CloseTokenizedAttestationCpiBuilder<'a, 'b> {
         self.instruction.token_program = Some(token_program);
         self
     }
+    #[inline(always)]
+    pub fn attestation_mint_bump(&mut self, attestation_mint_bump: u8) -> &mut Self {
+        self.instruction.attestation_mint_bump = Some(attestation_mint_bump);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -635,6 +671,13 @@ impl<'a, 'b> CloseTokenizedAttestationCpiBuilder<'a, 'b> {
         &self,
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
+        let args = CloseTokenizedAttestationInstructionArgs {
+            attestation_mint_bump: self
+                .instruction
+                .attestation_mint_bump
+                .clone()
+                .expect("attestation_mint_bump is not set"),
+        };
         let instruction = CloseTokenizedAttestationCpi {
             __program: self.instruction.__program,
 
@@ -680,6 +723,7 @@ impl<'a, 'b> CloseTokenizedAttestationCpiBuilder<'a, 'b> {
                 .instruction
                 .token_program
                 .expect("token_program is not set"),
+            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -702,6 +746,7 @@ struct CloseTokenizedAttestationCpiBuilderInstruction<'a, 'b> {
     sas_pda: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     attestation_token_account: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    attestation_mint_bump: Option<u8>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -100,6 +100,7 @@ pub struct CreateAttestationInstructionArgs {
     pub nonce: Pubkey,
     pub data: Vec<u8>,
     pub expiry: i64,
+    pub bump: u8,
 }
 
 /// Instruction builder for `CreateAttestation`.
@@ -123,6 +124,7 @@ pub struct CreateAttestationBuilder {
     nonce: Option<Pubkey>,
     data: Option<Vec<u8>>,
     expiry: Option<i64>,
+    bump: Option<u8>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -179,6 +181,11 @@ impl CreateAttestationBuilder {
         self.expiry = Some(expiry);
         self
     }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.bump = Some(bump);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -213,6 +220,7 @@ impl CreateAttestationBuilder {
             nonce: self.nonce.clone().expect("nonce is not set"),
             data: self.data.clone().expect("data is not set"),
             expiry: self.expiry.clone().expect("expiry is not set"),
+            bump: self.bump.clone().expect("bump is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -394,6 +402,7 @@ impl<'a, 'b> CreateAttestationCpiBuilder<'a, 'b> {
             nonce: None,
             data: None,
             expiry: None,
+            bump: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -461,6 +470,11 @@ impl<'a, 'b> CreateAttestationCpiBuilder<'a, 'b> {
         self.instruction.expiry = Some(expiry);
         self
     }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.instruction.bump = Some(bump);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -506,6 +520,7 @@ impl<'a, 'b> CreateAttestationCpiBuilder<'a, 'b> {
             nonce: self.instruction.nonce.clone().expect("nonce is not set"),
             data: self.instruction.data.clone().expect("data is not set"),
             expiry: self.instruction.expiry.clone().expect("expiry is not set"),
+            bump: self.instruction.bump.clone().expect("bump is not set"),
         };
         let instruction = CreateAttestationCpi {
             __program: self.instruction.__program,
@@ -548,6 +563,7 @@ struct CreateAttestationCpiBuilderInstruction<'a, 'b> {
     nonce: Option<Pubkey>,
     data: Option<Vec<u8>>,
     expiry: Option<i64>,
+    bump: Option<u8>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -87,6 +87,8 @@ impl Default for CreateCredentialInstructionData {
 pub struct CreateCredentialInstructionArgs {
     pub name: String,
     pub signers: Vec<Pubkey>,
+    pub bump: u8,
+    pub signer_kinds: Vec<u8>,
 }
 
 /// Instruction builder for `CreateCredential`.
@@ -105,6 +107,8 @@ pub struct CreateCredentialBuilder {
     system_program: Option<solana_program::pubkey::Pubkey>,
     name: Option<String>,
     signers: Option<Vec<Pubkey>>,
+    bump: Option<u8>,
+    signer_kinds: Option<Vec<u8>>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -143,6 +147,17 @@ impl CreateCredentialBuilder {
         self.signers = Some(signers);
         self
     }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.bump = Some(bump);
+        self
+    }
+    /// `[optional argument, defaults to 'vec![]']`
+    #[inline(always)]
+    pub fn signer_kinds(&mut self, signer_kinds: Vec<u8>) -> &mut Self {
+        self.signer_kinds = Some(signer_kinds);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -174,6 +189,8 @@ impl CreateCredentialBuilder {
         let args = CreateCredentialInstructionArgs {
             name: self.name.clone().expect("name is not set"),
             signers: self.signers.clone().expect("signers is not set"),
+            bump: self.bump.clone().expect("bump is not set"),
+            signer_kinds: self.signer_kinds.clone().unwrap_or(vec![]),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -330,6 +347,8 @@ impl<'a, 'b> CreateCredentialCpiBuilder<'a, 'b> {
             system_program: None,
             name: None,
             signers: None,
+            bump: None,
+            signer_kinds: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -373,6 +392,17 @@ impl<'a, 'b> CreateCredentialCpiBuilder<'a, 'b> {
         self.instruction.signers = Some(signers);
         self
     }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.instruction.bump = Some(bump);
+        self
+    }
+    /// `[optional argument, defaults to 'vec![]']`
+    #[inline(always)]
+    pub fn signer_kinds(&mut self, signer_kinds: Vec<u8>) -> &mut Self {
+        self.instruction.signer_kinds = Some(signer_kinds);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -421,6 +451,8 @@ impl<'a, 'b> CreateCredentialCpiBuilder<'a, 'b> {
                 .signers
                 .clone()
                 .expect("signers is not set"),
+            bump: self.instruction.bump.clone().expect("bump is not set"),
+            signer_kinds: self.instruction.signer_kinds.clone().unwrap_or(vec![]),
         };
         let instruction = CreateCredentialCpi {
             __program: self.instruction.__program,
@@ -453,6 +485,8 @@ struct CreateCredentialCpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     name: Option<String>,
     signers: Option<Vec<Pubkey>>,
+    bump: Option<u8>,
+    signer_kinds: Option<Vec<u8>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
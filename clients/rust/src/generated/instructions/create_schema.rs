@@ -7,6 +7,7 @@
 
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
+use solana_program::pubkey::Pubkey;
 
 /// Accounts.
 #[derive(Debug)]
@@ -94,6 +95,12 @@ pub struct CreateSchemaInstructionArgs {
     pub description: String,
     pub layout: Vec<u8>,
     pub field_names: Vec<String>,
+    pub bump: u8,
+    pub commitment_scheme: u8,
+    pub ref_schema: Pubkey,
+    pub ref_close_policy: u8,
+    pub resolver_program: Pubkey,
+    pub field_constraints: Vec<u8>,
 }
 
 /// Instruction builder for `CreateSchema`.
@@ -116,6 +123,12 @@ pub struct CreateSchemaBuilder {
     description: Option<String>,
     layout: Option<Vec<u8>>,
     field_names: Option<Vec<String>>,
+    bump: Option<u8>,
+    commitment_scheme: Option<u8>,
+    ref_schema: Option<Pubkey>,
+    ref_close_policy: Option<u8>,
+    resolver_program: Option<Pubkey>,
+    field_constraints: Option<Vec<u8>>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -170,6 +183,41 @@ impl CreateSchemaBuilder {
         self.field_names = Some(field_names);
         self
     }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.bump = Some(bump);
+        self
+    }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn commitment_scheme(&mut self, commitment_scheme: u8) -> &mut Self {
+        self.commitment_scheme = Some(commitment_scheme);
+        self
+    }
+    /// `[optional argument, defaults to 'solana_program::pubkey!("11111111111111111111111111111111")']`
+    #[inline(always)]
+    pub fn ref_schema(&mut self, ref_schema: Pubkey) -> &mut Self {
+        self.ref_schema = Some(ref_schema);
+        self
+    }
+    /// `[optional argument, defaults to '1']`
+    #[inline(always)]
+    pub fn ref_close_policy(&mut self, ref_close_policy: u8) -> &mut Self {
+        self.ref_close_policy = Some(ref_close_policy);
+        self
+    }
+    /// `[optional argument, defaults to 'solana_program::pubkey!("11111111111111111111111111111111")']`
+    #[inline(always)]
+    pub fn resolver_program(&mut self, resolver_program: Pubkey) -> &mut Self {
+        self.resolver_program = Some(resolver_program);
+        self
+    }
+    /// `[optional argument, defaults to 'vec![]']`
+    #[inline(always)]
+    pub fn field_constraints(&mut self, field_constraints: Vec<u8>) -> &mut Self {
+        self.field_constraints = Some(field_constraints);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -204,6 +252,18 @@ impl CreateSchemaBuilder {
             description: self.description.clone().expect("description is not set"),
             layout: self.layout.clone().expect("layout is not set"),
             field_names: self.field_names.clone().expect("field_names is not set"),
+            bump: self.bump.clone().expect("bump is not set"),
+            commitment_scheme: self.commitment_scheme.clone().unwrap_or(0),
+            ref_schema: self
+                .ref_schema
+                .clone()
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            ref_close_policy: self.ref_close_policy.clone().unwrap_or(1),
+            resolver_program: self
+                .resolver_program
+                .clone()
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            field_constraints: self.field_constraints.clone().unwrap_or(vec![]),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -374,6 +434,12 @@ impl<'a, 'b> CreateSchemaCpiBuilder<'a, 'b> {
             description: None,
             layout: None,
             field_names: None,
+            bump: None,
+            commitment_scheme: None,
+            ref_schema: None,
+            ref_close_policy: None,
+            resolver_program: None,
+            field_constraints: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -436,6 +502,41 @@ impl<'a, 'b> CreateSchemaCpiBuilder<'a, 'b> {
         self.instruction.field_names = Some(field_names);
         self
     }
+    #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.instruction.bump = Some(bump);
+        self
+    }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn commitment_scheme(&mut self, commitment_scheme: u8) -> &mut Self {
+        self.instruction.commitment_scheme = Some(commitment_scheme);
+        self
+    }
+    /// `[optional argument, defaults to 'solana_program::pubkey!("11111111111111111111111111111111")']`
+    #[inline(always)]
+    pub fn ref_schema(&mut self, ref_schema: Pubkey) -> &mut Self {
+        self.instruction.ref_schema = Some(ref_schema);
+        self
+    }
+    /// `[optional argument, defaults to '1']`
+    #[inline(always)]
+    pub fn ref_close_policy(&mut self, ref_close_policy: u8) -> &mut Self {
+        self.instruction.ref_close_policy = Some(ref_close_policy);
+        self
+    }
+    /// `[optional argument, defaults to 'solana_program::pubkey!("11111111111111111111111111111111")']`
+    #[inline(always)]
+    pub fn resolver_program(&mut self, resolver_program: Pubkey) -> &mut Self {
+        self.instruction.resolver_program = Some(resolver_program);
+        self
+    }
+    /// `[optional argument, defaults to 'vec![]']`
+    #[inline(always)]
+    pub fn field_constraints(&mut self, field_constraints: Vec<u8>) -> &mut Self {
+        self.instruction.field_constraints = Some(field_constraints);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -490,6 +591,20 @@ impl<'a, 'b> CreateSchemaCpiBuilder<'a, 'b> {
                 .field_names
                 .clone()
                 .expect("field_names is not set"),
+            bump: self.instruction.bump.clone().expect("bump is not set"),
+            commitment_scheme: self.instruction.commitment_scheme.clone().unwrap_or(0),
+            ref_schema: self
+                .instruction
+                .ref_schema
+                .clone()
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            ref_close_policy: self.instruction.ref_close_policy.clone().unwrap_or(1),
+            resolver_program: self
+                .instruction
+                .resolver_program
+                .clone()
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            field_constraints: self.instruction.field_constraints.clone().unwrap_or(vec![]),
         };
         let instruction = CreateSchemaCpi {
             __program: self.instruction.__program,
@@ -527,6 +642,12 @@ struct CreateSchemaCpiBuilderInstruction<'a, 'b> {
     description: Option<String>,
     layout: Option<Vec<u8>>,
     field_names: Option<Vec<String>>,
+    bump: Option<u8>,
+    commitment_scheme: Option<u8>,
+    ref_schema: Option<Pubkey>,
+    ref_close_policy: Option<u8>,
+    resolver_program: Option<Pubkey>,
+    field_constraints: Option<Vec<u8>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
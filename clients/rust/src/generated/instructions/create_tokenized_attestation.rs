@@ -142,10 +142,13 @@ pub struct CreateTokenizedAttestationInstructionArgs {
     pub nonce: Pubkey,
     pub data: Vec<u8>,
     pub expiry: i64,
+    pub bump: u8,
     pub name: String,
     pub uri: String,
     pub symbol: String,
     pub mint_account_space: u16,
+    pub schema_mint_bump: u8,
+    pub attestation_mint_bump: u8,
 }
 
 /// Instruction builder for `CreateTokenizedAttestation`.
@@ -183,10 +186,13 @@ pub struct CreateTokenizedAttestationBuilder {
     nonce: Option<Pubkey>,
     data: Option<Vec<u8>>,
     expiry: Option<i64>,
+    bump: Option<u8>,
     name: Option<String>,
     uri: Option<String>,
     symbol: Option<String>,
     mint_account_space: Option<u16>,
+    schema_mint_bump: Option<u8>,
+    attestation_mint_bump: Option<u8>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -295,6 +301,11 @@ impl CreateTokenizedAttestationBuilder {
         self
     }
     #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.bump = Some(bump);
+        self
+    }
+    #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
         self.name = Some(name);
         self
@@ -309,11 +320,22 @@ impl CreateTokenizedAttestationBuilder {
         self.symbol = Some(symbol);
         self
     }
+    /// `[optional argument, defaults to '0']`
     #[inline(always)]
     pub fn mint_account_space(&mut self, mint_account_space: u16) -> &mut Self {
         self.mint_account_space = Some(mint_account_space);
         self
     }
+    #[inline(always)]
+    pub fn schema_mint_bump(&mut self, schema_mint_bump: u8) -> &mut Self {
+        self.schema_mint_bump = Some(schema_mint_bump);
+        self
+    }
+    #[inline(always)]
+    pub fn attestation_mint_bump(&mut self, attestation_mint_bump: u8) -> &mut Self {
+        self.attestation_mint_bump = Some(attestation_mint_bump);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -361,13 +383,19 @@ impl CreateTokenizedAttestationBuilder {
             nonce: self.nonce.clone().expect("nonce is not set"),
             data: self.data.clone().expect("data is not set"),
             expiry: self.expiry.clone().expect("expiry is not set"),
+            bump: self.bump.clone().expect("bump is not set"),
             name: self.name.clone().expect("name is not set"),
             uri: self.uri.clone().expect("uri is not set"),
             symbol: self.symbol.clone().expect("symbol is not set"),
-            mint_account_space: self
-                .mint_account_space
+            mint_account_space: self.mint_account_space.clone().unwrap_or(0),
+            schema_mint_bump: self
+                .schema_mint_bump
                 .clone()
-                .expect("mint_account_space is not set"),
+                .expect("schema_mint_bump is not set"),
+            attestation_mint_bump: self
+                .attestation_mint_bump
+                .clone()
+                .expect("attestation_mint_bump is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -633,10 +661,13 @@ impl<'a, 'b> CreateTokenizedAttestationCpiBuilder<'a, 'b> {
             nonce: None,
             data: None,
             expiry: None,
+            bump: None,
             name: None,
             uri: None,
             symbol: None,
             mint_account_space: None,
+            schema_mint_bump: None,
+            attestation_mint_bump: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -766,6 +797,11 @@ impl<'a, 'b> CreateTokenizedAttestationCpiBuilder<'a, 'b> {
         self
     }
     #[inline(always)]
+    pub fn bump(&mut self, bump: u8) -> &mut Self {
+        self.instruction.bump = Some(bump);
+        self
+    }
+    #[inline(always)]
     pub fn name(&mut self, name: String) -> &mut Self {
         self.instruction.name = Some(name);
         self
@@ -780,11 +816,22 @@ impl<'a, 'b> CreateTokenizedAttestationCpiBuilder<'a, 'b> {
         self.instruction.symbol = Some(symbol);
         self
     }
+    /// `[optional argument, defaults to '0']`
     #[inline(always)]
     pub fn mint_account_space(&mut self, mint_account_space: u16) -> &mut Self {
         self.instruction.mint_account_space = Some(mint_account_space);
         self
     }
+    #[inline(always)]
+    pub fn schema_mint_bump(&mut self, schema_mint_bump: u8) -> &mut Self {
+        self.instruction.schema_mint_bump = Some(schema_mint_bump);
+        self
+    }
+    #[inline(always)]
+    pub fn attestation_mint_bump(&mut self, attestation_mint_bump: u8) -> &mut Self {
+        self.instruction.attestation_mint_bump = Some(attestation_mint_bump);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -830,14 +877,21 @@ impl<'a, 'b> CreateTokenizedAttestationCpiBuilder<'a, 'b> {
             nonce: self.instruction.nonce.clone().expect("nonce is not set"),
             data: self.instruction.data.clone().expect("data is not set"),
             expiry: self.instruction.expiry.clone().expect("expiry is not set"),
+            bump: self.instruction.bump.clone().expect("bump is not set"),
             name: self.instruction.name.clone().expect("name is not set"),
             uri: self.instruction.uri.clone().expect("uri is not set"),
             symbol: self.instruction.symbol.clone().expect("symbol is not set"),
-            mint_account_space: self
+            mint_account_space: self.instruction.mint_account_space.clone().unwrap_or(0),
+            schema_mint_bump: self
+                .instruction
+                .schema_mint_bump
+                .clone()
+                .expect("schema_mint_bump is not set"),
+            attestation_mint_bump: self
                 .instruction
-                .mint_account_space
+                .attestation_mint_bump
                 .clone()
-                .expect("mint_account_space is not set"),
+                .expect("attestation_mint_bump is not set"),
         };
         let instruction = CreateTokenizedAttestationCpi {
             __program: self.instruction.__program,
@@ -916,10 +970,13 @@ struct CreateTokenizedAttestationCpiBuilderInstruction<'a, 'b> {
     nonce: Option<Pubkey>,
     data: Option<Vec<u8>>,
     expiry: Option<i64>,
+    bump: Option<u8>,
     name: Option<String>,
     uri: Option<String>,
     symbol: Option<String>,
     mint_account_space: Option<u16>,
+    schema_mint_bump: Option<u8>,
+    attestation_mint_bump: Option<u8>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
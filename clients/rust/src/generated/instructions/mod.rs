@@ -5,28 +5,64 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#approve_attestation_request;
 pub(crate) mod r#change_authorized_signers;
+pub(crate) mod r#change_credential_status;
+pub(crate) mod r#change_passkey_signers;
 pub(crate) mod r#change_schema_description;
+pub(crate) mod r#change_schema_fee;
 pub(crate) mod r#change_schema_status;
+pub(crate) mod r#change_schema_token_metadata_fields;
 pub(crate) mod r#change_schema_version;
 pub(crate) mod r#close_attestation;
 pub(crate) mod r#close_tokenized_attestation;
 pub(crate) mod r#create_attestation;
+pub(crate) mod r#create_attestation_request;
+pub(crate) mod r#create_attestation_with_passkey;
+pub(crate) mod r#create_attestation_with_signature;
 pub(crate) mod r#create_credential;
+pub(crate) mod r#create_merkle_attestation_batch;
 pub(crate) mod r#create_schema;
 pub(crate) mod r#create_tokenized_attestation;
 pub(crate) mod r#emit_event;
+pub(crate) mod r#freeze_expired_attestation_token;
+pub(crate) mod r#migrate_account;
+pub(crate) mod r#reject_attestation_request;
+pub(crate) mod r#revoke_merkle_attestation_batch;
+pub(crate) mod r#tokenize_attestation;
 pub(crate) mod r#tokenize_schema;
+pub(crate) mod r#update_credential_metadata;
+pub(crate) mod r#update_schema_token_metadata;
+pub(crate) mod r#verify_merkle_attestation;
+pub(crate) mod r#withdraw_treasury;
 
+pub use self::r#approve_attestation_request::*;
 pub use self::r#change_authorized_signers::*;
+pub use self::r#change_credential_status::*;
+pub use self::r#change_passkey_signers::*;
 pub use self::r#change_schema_description::*;
+pub use self::r#change_schema_fee::*;
 pub use self::r#change_schema_status::*;
+pub use self::r#change_schema_token_metadata_fields::*;
 pub use self::r#change_schema_version::*;
 pub use self::r#close_attestation::*;
 pub use self::r#close_tokenized_attestation::*;
 pub use self::r#create_attestation::*;
+pub use self::r#create_attestation_request::*;
+pub use self::r#create_attestation_with_passkey::*;
+pub use self::r#create_attestation_with_signature::*;
 pub use self::r#create_credential::*;
+pub use self::r#create_merkle_attestation_batch::*;
 pub use self::r#create_schema::*;
 pub use self::r#create_tokenized_attestation::*;
 pub use self::r#emit_event::*;
+pub use self::r#freeze_expired_attestation_token::*;
+pub use self::r#migrate_account::*;
+pub use self::r#reject_attestation_request::*;
+pub use self::r#revoke_merkle_attestation_batch::*;
+pub use self::r#tokenize_attestation::*;
 pub use self::r#tokenize_schema::*;
+pub use self::r#update_credential_metadata::*;
+pub use self::r#update_schema_token_metadata::*;
+pub use self::r#verify_merkle_attestation::*;
+pub use self::r#withdraw_treasury::*;
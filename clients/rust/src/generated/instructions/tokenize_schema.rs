@@ -54,7 +54,7 @@ impl TokenizeSchema {
             self.credential,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.schema,
             false,
         ));
@@ -108,6 +108,11 @@ impl Default for TokenizeSchemaInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TokenizeSchemaInstructionArgs {
     pub max_size: u64,
+    pub mint_bump: u8,
+    pub token_policy: u8,
+    pub name: String,
+    pub uri: String,
+    pub symbol: String,
 }
 
 /// Instruction builder for `TokenizeSchema`.
@@ -117,7 +122,7 @@ pub struct TokenizeSchemaInstructionArgs {
 ///   0. `[writable, signer]` payer
 ///   1. `[signer]` authority
 ///   2. `[]` credential
-///   3. `[]` schema
+///   3. `[writable]` schema
 ///   4. `[writable]` mint
 ///   5. `[]` sas_pda
 ///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
@@ -133,6 +138,11 @@ pub struct TokenizeSchemaBuilder {
     system_program: Option<solana_program::pubkey::Pubkey>,
     token_program: Option<solana_program::pubkey::Pubkey>,
     max_size: Option<u64>,
+    mint_bump: Option<u8>,
+    token_policy: Option<u8>,
+    name: Option<String>,
+    uri: Option<String>,
+    symbol: Option<String>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -190,6 +200,32 @@ impl TokenizeSchemaBuilder {
         self.max_size = Some(max_size);
         self
     }
+    #[inline(always)]
+    pub fn mint_bump(&mut self, mint_bump: u8) -> &mut Self {
+        self.mint_bump = Some(mint_bump);
+        self
+    }
+    /// `[optional argument, defaults to '3']`
+    #[inline(always)]
+    pub fn token_policy(&mut self, token_policy: u8) -> &mut Self {
+        self.token_policy = Some(token_policy);
+        self
+    }
+    #[inline(always)]
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+    #[inline(always)]
+    pub fn uri(&mut self, uri: String) -> &mut Self {
+        self.uri = Some(uri);
+        self
+    }
+    #[inline(always)]
+    pub fn symbol(&mut self, symbol: String) -> &mut Self {
+        self.symbol = Some(symbol);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -226,6 +262,11 @@ impl TokenizeSchemaBuilder {
         };
         let args = TokenizeSchemaInstructionArgs {
             max_size: self.max_size.clone().expect("max_size is not set"),
+            mint_bump: self.mint_bump.clone().expect("mint_bump is not set"),
+            token_policy: self.token_policy.clone().unwrap_or(3),
+            name: self.name.clone().expect("name is not set"),
+            uri: self.uri.clone().expect("uri is not set"),
+            symbol: self.symbol.clone().expect("symbol is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -341,7 +382,7 @@ impl<'a, 'b> TokenizeSchemaCpi<'a, 'b> {
             *self.credential.key,
             false,
         ));
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.schema.key,
             false,
         ));
@@ -406,7 +447,7 @@ impl<'a, 'b> TokenizeSchemaCpi<'a, 'b> {
 ///   0. `[writable, signer]` payer
 ///   1. `[signer]` authority
 ///   2. `[]` credential
-///   3. `[]` schema
+///   3. `[writable]` schema
 ///   4. `[writable]` mint
 ///   5. `[]` sas_pda
 ///   6. `[]` system_program
@@ -429,6 +470,11 @@ impl<'a, 'b> TokenizeSchemaCpiBuilder<'a, 'b> {
             system_program: None,
             token_program: None,
             max_size: None,
+            mint_bump: None,
+            token_policy: None,
+            name: None,
+            uri: None,
+            symbol: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -499,6 +545,32 @@ impl<'a, 'b> TokenizeSchemaCpiBuilder<'a, 'b> {
         self.instruction.max_size = Some(max_size);
         self
     }
+    #[inline(always)]
+    pub fn mint_bump(&mut self, mint_bump: u8) -> &mut Self {
+        self.instruction.mint_bump = Some(mint_bump);
+        self
+    }
+    /// `[optional argument, defaults to '3']`
+    #[inline(always)]
+    pub fn token_policy(&mut self, token_policy: u8) -> &mut Self {
+        self.instruction.token_policy = Some(token_policy);
+        self
+    }
+    #[inline(always)]
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.instruction.name = Some(name);
+        self
+    }
+    #[inline(always)]
+    pub fn uri(&mut self, uri: String) -> &mut Self {
+        self.instruction.uri = Some(uri);
+        self
+    }
+    #[inline(always)]
+    pub fn symbol(&mut self, symbol: String) -> &mut Self {
+        self.instruction.symbol = Some(symbol);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -546,6 +618,15 @@ impl<'a, 'b> TokenizeSchemaCpiBuilder<'a, 'b> {
                 .max_size
                 .clone()
                 .expect("max_size is not set"),
+            mint_bump: self
+                .instruction
+                .mint_bump
+                .clone()
+                .expect("mint_bump is not set"),
+            token_policy: self.instruction.token_policy.clone().unwrap_or(3),
+            name: self.instruction.name.clone().expect("name is not set"),
+            uri: self.instruction.uri.clone().expect("uri is not set"),
+            symbol: self.instruction.symbol.clone().expect("symbol is not set"),
         };
         let instruction = TokenizeSchemaCpi {
             __program: self.instruction.__program,
@@ -592,6 +673,11 @@ struct TokenizeSchemaCpiBuilderInstruction<'a, 'b> {
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     max_size: Option<u64>,
+    mint_bump: Option<u8>,
+    token_policy: Option<u8>,
+    name: Option<String>,
+    uri: Option<String>,
+    symbol: Option<String>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
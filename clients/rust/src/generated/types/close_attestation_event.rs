@@ -19,4 +19,5 @@ pub struct CloseAttestationEvent {
     )]
     pub schema: Pubkey,
     pub attestation_data: Vec<u8>,
+    pub ref_count: u32,
 }
@@ -0,0 +1,105 @@
+//! Helpers for Attestation data encoded with a Schema layout.
+
+use std::fmt;
+
+/// Error returned when data does not conform to a Schema layout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LayoutError {
+    /// The layout contains a byte that is not a Schema data type.
+    InvalidDataType(u8),
+    /// The field at this index overflows the data or is not canonically encoded.
+    InvalidField(usize),
+    /// The data continues past the last field of the layout.
+    TrailingData,
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutError::InvalidDataType(data_type) => {
+                write!(f, "invalid schema data type {data_type}")
+            }
+            LayoutError::InvalidField(index) => write!(f, "field {index} is not valid"),
+            LayoutError::TrailingData => write!(f, "data continues past the last field"),
+        }
+    }
+}
+
+impl std::error::Error for LayoutError {}
+
+// Schema data types, matching `SchemaDataTypes` in the program.
+pub(crate) const BOOL: u8 = 10;
+pub(crate) const CHAR: u8 = 11;
+pub(crate) const STRING: u8 = 12;
+pub(crate) const VEC_U8: u8 = 13;
+pub(crate) const VEC_I8: u8 = 18;
+pub(crate) const VEC_BOOL: u8 = 23;
+pub(crate) const VEC_STRING: u8 = 25;
+
+/// Split `data` into the encoding of each field in `layout`. Fails if a field
+/// does not fit, is not canonically encoded as the program requires, or if
+/// bytes remain after the last field.
+pub fn split_fields<'a>(layout: &[u8], data: &'a [u8]) -> Result<Vec<&'a [u8]>, LayoutError> {
+    if let Some(data_type) = layout.iter().find(|t| **t > VEC_STRING) {
+        return Err(LayoutError::InvalidDataType(*data_type));
+    }
+
+    let mut fields = Vec::with_capacity(layout.len());
+    let mut offset = 0;
+    for (index, data_type) in layout.iter().enumerate() {
+        let size = field_size(*data_type, data, offset).ok_or(LayoutError::InvalidField(index))?;
+        fields.push(&data[offset..offset + size]);
+        offset += size;
+    }
+    if offset != data.len() {
+        return Err(LayoutError::TrailingData);
+    }
+    Ok(fields)
+}
+
+/// Size of the canonically encoded value of `data_type` at `offset`.
+fn field_size(data_type: u8, data: &[u8], offset: usize) -> Option<usize> {
+    match data_type {
+        STRING => {
+            let len = read_len(data, offset)?;
+            let value = data.get(offset + 4..)?.get(..len)?;
+            std::str::from_utf8(value).ok()?;
+            Some(4 + len)
+        }
+        // Vec element types follow the same order as the scalar types.
+        VEC_U8..=VEC_STRING => {
+            let mut size = 4;
+            for _ in 0..read_len(data, offset)? {
+                size += field_size(data_type - VEC_U8, data, offset + size)?;
+            }
+            Some(size)
+        }
+        _ => {
+            let size = scalar_size(data_type);
+            let value = data.get(offset..)?.get(..size)?;
+            let canonical = match data_type {
+                BOOL => value[0] <= 1,
+                CHAR => char::from_u32(u32::from_le_bytes(value.try_into().unwrap())).is_some(),
+                _ => true,
+            };
+            canonical.then_some(size)
+        }
+    }
+}
+
+/// Size of an integer, bool or char data type.
+pub(crate) fn scalar_size(data_type: u8) -> usize {
+    match data_type {
+        0 | 5 | BOOL => 1,
+        1 | 6 => 2,
+        2 | 7 | CHAR => 4,
+        3 | 8 => 8,
+        _ => 16,
+    }
+}
+
+/// Read the u32 length prefix at `offset`.
+fn read_len(data: &[u8], offset: usize) -> Option<usize> {
+    let bytes = data.get(offset..)?.get(..4)?;
+    Some(u32::from_le_bytes(bytes.try_into().unwrap()) as usize)
+}
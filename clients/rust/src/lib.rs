@@ -1,5 +1,11 @@
 mod generated;
 
+pub mod commitment;
+pub mod layout;
+pub mod merkle;
+pub mod signature;
+pub mod token;
+
 use generated::*;
 
 pub mod accounts {
@@ -0,0 +1,101 @@
+//! Build Merkle trees and inclusion proofs for `MerkleAttestationBatch`
+//! accounts, hashing leaves and nodes the same way as the program.
+
+use solana_program::{hash::hashv, pubkey::Pubkey};
+
+/// An Attestation issued as a leaf of a `MerkleAttestationBatch`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleAttestationRecord {
+    /// The holder the Attestation is issued to.
+    pub subject: Pubkey,
+    /// Attestation data, encoded as the Schema requires.
+    pub data: Vec<u8>,
+    /// Unix timestamp the Attestation expires at. 0 means it never expires.
+    pub expiry: i64,
+}
+
+impl MerkleAttestationRecord {
+    /// Hash of the leaf for this record.
+    pub fn leaf(&self) -> [u8; 32] {
+        hashv(&[
+            &[0],
+            self.subject.as_ref(),
+            &(self.data.len() as u32).to_le_bytes(),
+            &self.data,
+            &self.expiry.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
+}
+
+/// Hash of an inner node. Children are sorted so proofs need no direction bits.
+pub fn merkle_node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[&[1], left, right]).to_bytes()
+}
+
+/// A Merkle tree over attestation records. The last node of a level with an
+/// odd number of nodes is carried up to the next level unchanged.
+#[derive(Clone, Debug)]
+pub struct MerkleTree {
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    /// Build the tree over `records`, in order.
+    pub fn new(records: &[MerkleAttestationRecord]) -> Self {
+        let mut levels = vec![records.iter().map(|r| r.leaf()).collect::<Vec<_>>()];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => merkle_node(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        Self { levels }
+    }
+
+    /// Number of leaves in the tree.
+    pub fn leaf_count(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Root to store in the batch, or None for an empty tree.
+    pub fn root(&self) -> Option<[u8; 32]> {
+        self.levels.last().unwrap().first().copied()
+    }
+
+    /// Sibling hashes from the leaf at `index` up to the root, as expected by
+    /// `VerifyMerkleAttestation`.
+    pub fn proof(&self, mut index: usize) -> Option<Vec<[u8; 32]>> {
+        if index >= self.leaf_count() {
+            return None;
+        }
+        let mut proof = Vec::new();
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        Some(proof)
+    }
+}
+
+/// Verify that `proof` proves `record` is in the tree with `root`.
+pub fn verify_merkle_proof(
+    root: &[u8; 32],
+    record: &MerkleAttestationRecord,
+    proof: &[[u8; 32]],
+) -> bool {
+    proof
+        .iter()
+        .fold(record.leaf(), |node, sibling| merkle_node(&node, sibling))
+        == *root
+}
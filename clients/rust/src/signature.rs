@@ -0,0 +1,228 @@
+//! Build the messages authorized signers sign off-chain, and the signature
+//! verification instructions that must precede `CreateAttestationWithSignature`.
+
+use solana_program::{
+    ed25519_program, hash::hashv, instruction::Instruction, pubkey, pubkey::Pubkey,
+    secp256k1_program,
+};
+
+use crate::programs::SOLANA_ATTESTATION_SERVICE_ID;
+
+/// Prefix of every attestation message.
+pub const ATTESTATION_MESSAGE_DOMAIN: &[u8] = b"SAS_CREATE_ATTESTATION";
+
+/// Seed of the PDA marking an attestation message as used.
+pub const USED_MESSAGE_SEED: &[u8] = b"usedMessage";
+
+/// Prefix of EIP-191 personal messages, followed by the decimal message length.
+pub const EIP191_MESSAGE_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n";
+
+/// Secp256r1 signature verification program, used to verify passkeys.
+pub const SECP256R1_PROGRAM_ID: Pubkey = pubkey!("Secp256r1SigVerify1111111111111111111111111");
+
+/// Length of a compressed secp256r1 public key.
+pub const PASSKEY_LEN: usize = 33;
+
+const BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+// Instruction index the Ed25519 and secp256r1 programs reads as "this instruction".
+const CURRENT_INSTRUCTION_INDEX: u16 = u16::MAX;
+
+/// Values of `Attestation.signer_kind`.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignerKind {
+    /// `signer` is a Solana ed25519 public key.
+    Ed25519 = 0,
+    /// `signer` is an Ethereum address, see [`ethereum_signer`].
+    Secp256k1 = 1,
+    /// `signer` is the hash of a passkey, see [`passkey_signer`].
+    Secp256r1 = 2,
+}
+
+/// Ethereum address as stored in `Credential.authorized_signers` and
+/// `Attestation.signer`: 12 zero bytes followed by the address. Its entry in
+/// `signer_kinds` is [`SignerKind::Secp256k1`].
+pub fn ethereum_signer(address: &[u8; 20]) -> Pubkey {
+    let mut signer = [0; 32];
+    signer[12..].copy_from_slice(address);
+    Pubkey::new_from_array(signer)
+}
+
+/// Signer of a passkey, as used for the `authority` of
+/// `CreateAttestationWithPasskey` and `Attestation.signer`: the sha256 hash of
+/// its compressed public key.
+pub fn passkey_signer(public_key: &[u8; PASSKEY_LEN]) -> Pubkey {
+    Pubkey::new_from_array(hashv(&[public_key]).to_bytes())
+}
+
+/// WebAuthn challenge a passkey asserts to approve an Attestation: the
+/// unpadded base64url encoding of the sha256 hash of the attestation message.
+pub fn webauthn_challenge(message: &[u8]) -> String {
+    let digest = hashv(&[message]).to_bytes();
+    let mut encoded = String::with_capacity(43);
+    for chunk in digest.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, byte)| {
+            group | ((*byte as u32) << (16 - 8 * i))
+        });
+        for i in 0..=chunk.len() {
+            encoded.push(BASE64URL_ALPHABET[((group >> (18 - 6 * i)) & 0x3f) as usize] as char);
+        }
+    }
+    encoded
+}
+
+/// Message a passkey signs in a WebAuthn assertion: the authenticator data
+/// followed by the sha256 hash of the client data JSON.
+pub fn webauthn_signed_message(authenticator_data: &[u8], client_data_json: &[u8]) -> Vec<u8> {
+    [authenticator_data, hashv(&[client_data_json]).as_ref()].concat()
+}
+
+/// `message` prefixed as an EIP-191 personal message. Ethereum signers sign
+/// the attestation message in this form, e.g. with `personal_sign`.
+pub fn eip191_message(message: &[u8]) -> Vec<u8> {
+    [
+        EIP191_MESSAGE_PREFIX,
+        message.len().to_string().as_bytes(),
+        message,
+    ]
+    .concat()
+}
+
+/// Message an authorized signer of `credential` signs to approve an
+/// Attestation with these arguments, valid until the `deadline` timestamp.
+pub fn attestation_message(
+    credential: &Pubkey,
+    schema: &Pubkey,
+    nonce: &Pubkey,
+    data: &[u8],
+    expiry: i64,
+    deadline: i64,
+) -> Vec<u8> {
+    [
+        ATTESTATION_MESSAGE_DOMAIN,
+        SOLANA_ATTESTATION_SERVICE_ID.as_ref(),
+        credential.as_ref(),
+        schema.as_ref(),
+        nonce.as_ref(),
+        (data.len() as u32).to_le_bytes().as_slice(),
+        data,
+        expiry.to_le_bytes().as_slice(),
+        deadline.to_le_bytes().as_slice(),
+    ]
+    .concat()
+}
+
+/// PDA and bump marking the attestation `message` as used, passed as the
+/// `used_message` account so the message can only create one Attestation.
+pub fn find_used_message_pda(message: &[u8]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[USED_MESSAGE_SEED, hashv(&[message]).as_ref()],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+}
+
+/// Ed25519 program instruction verifying `signature` by `signer` over
+/// `message`, with all three stored in the instruction itself.
+pub fn ed25519_instruction(signer: &Pubkey, signature: &[u8; 64], message: &[u8]) -> Instruction {
+    // signature count - 1, padding - 1, offsets - 14
+    let public_key_offset: u16 = 2 + 14;
+    let signature_offset = public_key_offset + 32;
+    let message_offset = signature_offset + 64;
+
+    let mut data = vec![1, 0];
+    for value in [
+        signature_offset,
+        CURRENT_INSTRUCTION_INDEX,
+        public_key_offset,
+        CURRENT_INSTRUCTION_INDEX,
+        message_offset,
+        message.len() as u16,
+        CURRENT_INSTRUCTION_INDEX,
+    ] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(signer.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Secp256k1 program instruction verifying `signature` and `recovery_id` by
+/// the Ethereum `address` over the EIP-191 form of `message`. The instruction
+/// must be at `instruction_index` in the transaction, immediately before
+/// `CreateAttestationWithSignature`.
+pub fn secp256k1_instruction(
+    address: &[u8; 20],
+    signature: &[u8; 64],
+    recovery_id: u8,
+    message: &[u8],
+    instruction_index: u8,
+) -> Instruction {
+    let message = eip191_message(message);
+    // signature count - 1, offsets - 11
+    let address_offset: u16 = 1 + 11;
+    let signature_offset = address_offset + 20;
+    let message_offset = signature_offset + 64 + 1;
+
+    let mut data = vec![1];
+    data.extend_from_slice(&signature_offset.to_le_bytes());
+    data.push(instruction_index);
+    data.extend_from_slice(&address_offset.to_le_bytes());
+    data.push(instruction_index);
+    data.extend_from_slice(&message_offset.to_le_bytes());
+    data.extend_from_slice(&(message.len() as u16).to_le_bytes());
+    data.push(instruction_index);
+    data.extend_from_slice(address);
+    data.extend_from_slice(signature);
+    data.push(recovery_id);
+    data.extend_from_slice(&message);
+
+    Instruction {
+        program_id: secp256k1_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+/// Secp256r1 program instruction verifying `signature` by the compressed
+/// `public_key` over `message`, with all three stored in the instruction
+/// itself. The signature must be in low-S form, which the program requires.
+pub fn secp256r1_instruction(
+    public_key: &[u8; PASSKEY_LEN],
+    signature: &[u8; 64],
+    message: &[u8],
+) -> Instruction {
+    // signature count - 1, padding - 1, offsets - 14
+    let public_key_offset: u16 = 2 + 14;
+    let signature_offset = public_key_offset + PASSKEY_LEN as u16;
+    let message_offset = signature_offset + 64;
+
+    let mut data = vec![1, 0];
+    for value in [
+        signature_offset,
+        CURRENT_INSTRUCTION_INDEX,
+        public_key_offset,
+        CURRENT_INSTRUCTION_INDEX,
+        message_offset,
+        message.len() as u16,
+        CURRENT_INSTRUCTION_INDEX,
+    ] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(public_key);
+    data.extend_from_slice(signature);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: SECP256R1_PROGRAM_ID,
+        accounts: vec![],
+        data,
+    }
+}
@@ -0,0 +1,79 @@
+//! Token policies of tokenized Schemas, and the sizes of the Token-2022 mints
+//! the program creates for tokenized Schemas and Attestations.
+
+/// `Schema.token_policy` flag: Attestation tokens cannot be transferred.
+pub const TOKEN_POLICY_NON_TRANSFERABLE: u8 = 1 << 0;
+/// `Schema.token_policy` flag: the program is the permanent delegate of
+/// Attestation tokens, so closing an Attestation burns its token.
+pub const TOKEN_POLICY_PERMANENT_DELEGATE: u8 = 1 << 1;
+/// `Schema.token_policy` flag: anyone can freeze the tokens of expired
+/// Attestations with `FreezeExpiredAttestationToken`.
+pub const TOKEN_POLICY_FREEZE_ON_EXPIRY: u8 = 1 << 2;
+/// Token policy `TokenizeSchema` defaults to.
+pub const DEFAULT_TOKEN_POLICY: u8 =
+    TOKEN_POLICY_NON_TRANSFERABLE | TOKEN_POLICY_PERMANENT_DELEGATE;
+
+// Sizes of the parts of a Token-2022 mint account, matching the program: the
+// mint padded to the size of a token account followed by the account type,
+// and each extension with its 4 byte type and length header.
+const MINT_ACCOUNT_LEN: usize = 166;
+const POINTER_EXTENSION_LEN: usize = 4 + 64;
+const MINT_CLOSE_AUTHORITY_EXTENSION_LEN: usize = 4 + 32;
+const NON_TRANSFERABLE_EXTENSION_LEN: usize = 4;
+const PERMANENT_DELEGATE_EXTENSION_LEN: usize = 4 + 32;
+const TOKEN_GROUP_EXTENSION_LEN: usize = 4 + 80;
+const TOKEN_GROUP_MEMBER_EXTENSION_LEN: usize = 4 + 72;
+
+/// Size of a tokenized Schema's group mint with its token metadata.
+/// `additional_metadata` holds its `credential`, `schema` and `version` keys.
+pub fn schema_mint_space(
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    additional_metadata: &[(&str, &str)],
+) -> usize {
+    MINT_ACCOUNT_LEN
+        + 2 * POINTER_EXTENSION_LEN
+        + TOKEN_GROUP_EXTENSION_LEN
+        + token_metadata_space(name, symbol, uri, additional_metadata)
+}
+
+/// Size of a tokenized Attestation's mint with its token metadata, for a
+/// Schema with `token_policy`. `additional_metadata` holds its `attestation`,
+/// `schema`, `expiry` and `signer` keys and the mirrored Schema fields. The
+/// program funds the mint for the `attestation` and `schema` keys when it
+/// creates it, and the payer tops it up for the others.
+pub fn attestation_mint_space(
+    token_policy: u8,
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    additional_metadata: &[(&str, &str)],
+) -> usize {
+    let mut space = MINT_ACCOUNT_LEN
+        + 2 * POINTER_EXTENSION_LEN
+        + MINT_CLOSE_AUTHORITY_EXTENSION_LEN
+        + TOKEN_GROUP_MEMBER_EXTENSION_LEN
+        + token_metadata_space(name, symbol, uri, additional_metadata);
+    if token_policy & TOKEN_POLICY_NON_TRANSFERABLE != 0 {
+        space += NON_TRANSFERABLE_EXTENSION_LEN;
+    }
+    if token_policy & TOKEN_POLICY_PERMANENT_DELEGATE != 0 {
+        space += PERMANENT_DELEGATE_EXTENSION_LEN;
+    }
+    space
+}
+
+/// Size of a TokenMetadata extension with its header.
+pub fn token_metadata_space(
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    additional_metadata: &[(&str, &str)],
+) -> usize {
+    let additional_len: usize = additional_metadata
+        .iter()
+        .map(|(key, value)| 4 + key.len() + 4 + value.len())
+        .sum();
+    4 + 32 + 32 + (4 + name.len()) + (4 + symbol.len()) + (4 + uri.len()) + 4 + additional_len
+}
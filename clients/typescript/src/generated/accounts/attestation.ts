@@ -42,6 +42,7 @@ import {
 
 export type Attestation = {
   discriminator: number;
+  layoutVersion: number;
   nonce: Address;
   credential: Address;
   schema: Address;
@@ -49,10 +50,16 @@ export type Attestation = {
   signer: Address;
   expiry: bigint;
   tokenAccount: Address;
+  bump: number;
+  signerKind: number;
+  refAttestation: Address;
+  refCount: number;
+  resolverProgram: Address;
 };
 
 export type AttestationArgs = {
   discriminator: number;
+  layoutVersion: number;
   nonce: Address;
   credential: Address;
   schema: Address;
@@ -60,12 +67,18 @@ export type AttestationArgs = {
   signer: Address;
   expiry: number | bigint;
   tokenAccount: Address;
+  bump: number;
+  signerKind: number;
+  refAttestation: Address;
+  refCount: number;
+  resolverProgram: Address;
 };
 
 /** Gets the encoder for {@link AttestationArgs} account data. */
 export function getAttestationEncoder(): Encoder<AttestationArgs> {
   return getStructEncoder([
     ['discriminator', getU8Encoder()],
+    ['layoutVersion', getU8Encoder()],
     ['nonce', getAddressEncoder()],
     ['credential', getAddressEncoder()],
     ['schema', getAddressEncoder()],
@@ -73,6 +86,11 @@ export function getAttestationEncoder(): Encoder<AttestationArgs> {
     ['signer', getAddressEncoder()],
     ['expiry', getI64Encoder()],
     ['tokenAccount', getAddressEncoder()],
+    ['bump', getU8Encoder()],
+    ['signerKind', getU8Encoder()],
+    ['refAttestation', getAddressEncoder()],
+    ['refCount', getU32Encoder()],
+    ['resolverProgram', getAddressEncoder()],
   ]);
 }
 
@@ -80,6 +98,7 @@ export function getAttestationEncoder(): Encoder<AttestationArgs> {
 export function getAttestationDecoder(): Decoder<Attestation> {
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
+    ['layoutVersion', getU8Decoder()],
     ['nonce', getAddressDecoder()],
     ['credential', getAddressDecoder()],
     ['schema', getAddressDecoder()],
@@ -87,6 +106,11 @@ export function getAttestationDecoder(): Decoder<Attestation> {
     ['signer', getAddressDecoder()],
     ['expiry', getI64Decoder()],
     ['tokenAccount', getAddressDecoder()],
+    ['bump', getU8Decoder()],
+    ['signerKind', getU8Decoder()],
+    ['refAttestation', getAddressDecoder()],
+    ['refCount', getU32Decoder()],
+    ['resolverProgram', getAddressDecoder()],
   ]);
 }
 
@@ -15,10 +15,14 @@ import {
   decodeAccount,
   fetchEncodedAccount,
   fetchEncodedAccounts,
+  fixDecoderSize,
+  fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
@@ -42,9 +46,19 @@ import {
 
 export type Credential = {
   discriminator: number;
+  layoutVersion: number;
   authority: Address;
   name: ReadonlyUint8Array;
   authorizedSigners: Array<Address>;
+  bump: number;
+  displayName: ReadonlyUint8Array;
+  description: ReadonlyUint8Array;
+  uri: ReadonlyUint8Array;
+  logoUri: ReadonlyUint8Array;
+  website: ReadonlyUint8Array;
+  isPaused: boolean;
+  passkeySigners: Array<ReadonlyUint8Array>;
+  authorizedSignerKinds: ReadonlyUint8Array;
 };
 
 export type CredentialArgs = Credential;
@@ -53,9 +67,22 @@ export type CredentialArgs = Credential;
 export function getCredentialEncoder(): Encoder<CredentialArgs> {
   return getStructEncoder([
     ['discriminator', getU8Encoder()],
+    ['layoutVersion', getU8Encoder()],
     ['authority', getAddressEncoder()],
     ['name', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
     ['authorizedSigners', getArrayEncoder(getAddressEncoder())],
+    ['bump', getU8Encoder()],
+    ['displayName', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
+    ['description', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
+    ['uri', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
+    ['logoUri', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
+    ['website', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
+    ['isPaused', getBooleanEncoder()],
+    ['passkeySigners', getArrayEncoder(fixEncoderSize(getBytesEncoder(), 33))],
+    [
+      'authorizedSignerKinds',
+      addEncoderSizePrefix(getBytesEncoder(), getU32Encoder()),
+    ],
   ]);
 }
 
@@ -63,9 +90,22 @@ export function getCredentialEncoder(): Encoder<CredentialArgs> {
 export function getCredentialDecoder(): Decoder<Credential> {
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
+    ['layoutVersion', getU8Decoder()],
     ['authority', getAddressDecoder()],
     ['name', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
     ['authorizedSigners', getArrayDecoder(getAddressDecoder())],
+    ['bump', getU8Decoder()],
+    ['displayName', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+    ['description', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+    ['uri', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+    ['logoUri', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+    ['website', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+    ['isPaused', getBooleanDecoder()],
+    ['passkeySigners', getArrayDecoder(fixDecoderSize(getBytesDecoder(), 33))],
+    [
+      'authorizedSignerKinds',
+      addDecoderSizePrefix(getBytesDecoder(), getU32Decoder()),
+    ],
   ]);
 }
 
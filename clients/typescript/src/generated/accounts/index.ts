@@ -7,5 +7,7 @@
  */
 
 export * from './attestation';
+export * from './attestationRequest';
 export * from './credential';
+export * from './merkleAttestationBatch';
 export * from './schema';
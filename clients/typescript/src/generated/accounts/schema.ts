@@ -25,6 +25,8 @@ import {
   getStructEncoder,
   getU32Decoder,
   getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   type Account,
@@ -42,6 +44,7 @@ import {
 
 export type Schema = {
   discriminator: number;
+  layoutVersion: number;
   credential: Address;
   name: ReadonlyUint8Array;
   description: ReadonlyUint8Array;
@@ -49,14 +52,47 @@ export type Schema = {
   fieldNames: ReadonlyUint8Array;
   isPaused: boolean;
   version: number;
+  fieldConstraints: ReadonlyUint8Array;
+  bump: number;
+  commitmentScheme: number;
+  refSchema: Address;
+  refClosePolicy: number;
+  fee: bigint;
+  feeMint: Address;
+  feeTreasury: Address;
+  resolverProgram: Address;
+  tokenMetadataFields: ReadonlyUint8Array;
+  tokenPolicy: number;
 };
 
-export type SchemaArgs = Schema;
+export type SchemaArgs = {
+  discriminator: number;
+  layoutVersion: number;
+  credential: Address;
+  name: ReadonlyUint8Array;
+  description: ReadonlyUint8Array;
+  layout: ReadonlyUint8Array;
+  fieldNames: ReadonlyUint8Array;
+  isPaused: boolean;
+  version: number;
+  fieldConstraints: ReadonlyUint8Array;
+  bump: number;
+  commitmentScheme: number;
+  refSchema: Address;
+  refClosePolicy: number;
+  fee: number | bigint;
+  feeMint: Address;
+  feeTreasury: Address;
+  resolverProgram: Address;
+  tokenMetadataFields: ReadonlyUint8Array;
+  tokenPolicy: number;
+};
 
 /** Gets the encoder for {@link SchemaArgs} account data. */
 export function getSchemaEncoder(): Encoder<SchemaArgs> {
   return getStructEncoder([
     ['discriminator', getU8Encoder()],
+    ['layoutVersion', getU8Encoder()],
     ['credential', getAddressEncoder()],
     ['name', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
     ['description', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
@@ -64,6 +100,23 @@ export function getSchemaEncoder(): Encoder<SchemaArgs> {
     ['fieldNames', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
     ['isPaused', getBooleanEncoder()],
     ['version', getU8Encoder()],
+    [
+      'fieldConstraints',
+      addEncoderSizePrefix(getBytesEncoder(), getU32Encoder()),
+    ],
+    ['bump', getU8Encoder()],
+    ['commitmentScheme', getU8Encoder()],
+    ['refSchema', getAddressEncoder()],
+    ['refClosePolicy', getU8Encoder()],
+    ['fee', getU64Encoder()],
+    ['feeMint', getAddressEncoder()],
+    ['feeTreasury', getAddressEncoder()],
+    ['resolverProgram', getAddressEncoder()],
+    [
+      'tokenMetadataFields',
+      addEncoderSizePrefix(getBytesEncoder(), getU32Encoder()),
+    ],
+    ['tokenPolicy', getU8Encoder()],
   ]);
 }
 
@@ -71,6 +124,7 @@ export function getSchemaEncoder(): Encoder<SchemaArgs> {
 export function getSchemaDecoder(): Decoder<Schema> {
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
+    ['layoutVersion', getU8Decoder()],
     ['credential', getAddressDecoder()],
     ['name', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
     ['description', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
@@ -78,6 +132,23 @@ export function getSchemaDecoder(): Decoder<Schema> {
     ['fieldNames', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
     ['isPaused', getBooleanDecoder()],
     ['version', getU8Decoder()],
+    [
+      'fieldConstraints',
+      addDecoderSizePrefix(getBytesDecoder(), getU32Decoder()),
+    ],
+    ['bump', getU8Decoder()],
+    ['commitmentScheme', getU8Decoder()],
+    ['refSchema', getAddressDecoder()],
+    ['refClosePolicy', getU8Decoder()],
+    ['fee', getU64Decoder()],
+    ['feeMint', getAddressDecoder()],
+    ['feeTreasury', getAddressDecoder()],
+    ['resolverProgram', getAddressDecoder()],
+    [
+      'tokenMetadataFields',
+      addDecoderSizePrefix(getBytesDecoder(), getU32Decoder()),
+    ],
+    ['tokenPolicy', getU8Decoder()],
   ]);
 }
 
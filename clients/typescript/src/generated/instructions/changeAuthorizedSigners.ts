@@ -7,13 +7,19 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
   getAddressDecoder,
   getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -76,10 +82,12 @@ export type ChangeAuthorizedSignersInstruction<
 export type ChangeAuthorizedSignersInstructionData = {
   discriminator: number;
   signers: Array<Address>;
+  signerKinds: ReadonlyUint8Array;
 };
 
 export type ChangeAuthorizedSignersInstructionDataArgs = {
   signers: Array<Address>;
+  signerKinds?: ReadonlyUint8Array;
 };
 
 export function getChangeAuthorizedSignersInstructionDataEncoder(): Encoder<ChangeAuthorizedSignersInstructionDataArgs> {
@@ -87,10 +95,12 @@ export function getChangeAuthorizedSignersInstructionDataEncoder(): Encoder<Chan
     getStructEncoder([
       ['discriminator', getU8Encoder()],
       ['signers', getArrayEncoder(getAddressEncoder())],
+      ['signerKinds', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
     ]),
     (value) => ({
       ...value,
       discriminator: CHANGE_AUTHORIZED_SIGNERS_DISCRIMINATOR,
+      signerKinds: value.signerKinds ?? new Uint8Array([]),
     })
   );
 }
@@ -99,6 +109,7 @@ export function getChangeAuthorizedSignersInstructionDataDecoder(): Decoder<Chan
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
     ['signers', getArrayDecoder(getAddressDecoder())],
+    ['signerKinds', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
   ]);
 }
 
@@ -124,6 +135,7 @@ export type ChangeAuthorizedSignersInput<
   credential: Address<TAccountCredential>;
   systemProgram?: Address<TAccountSystemProgram>;
   signers: ChangeAuthorizedSignersInstructionDataArgs['signers'];
+  signerKinds?: ChangeAuthorizedSignersInstructionDataArgs['signerKinds'];
 };
 
 export function getChangeAuthorizedSignersInstruction<
@@ -91,11 +91,15 @@ export type ChangeSchemaVersionInstructionData = {
   discriminator: number;
   layout: ReadonlyUint8Array;
   fieldNames: Array<string>;
+  bump: number;
+  fieldConstraints: ReadonlyUint8Array;
 };
 
 export type ChangeSchemaVersionInstructionDataArgs = {
   layout: ReadonlyUint8Array;
   fieldNames: Array<string>;
+  bump: number;
+  fieldConstraints?: ReadonlyUint8Array;
 };
 
 export function getChangeSchemaVersionInstructionDataEncoder(): Encoder<ChangeSchemaVersionInstructionDataArgs> {
@@ -109,10 +113,16 @@ export function getChangeSchemaVersionInstructionDataEncoder(): Encoder<ChangeSc
           addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
         ),
       ],
+      ['bump', getU8Encoder()],
+      [
+        'fieldConstraints',
+        addEncoderSizePrefix(getBytesEncoder(), getU32Encoder()),
+      ],
     ]),
     (value) => ({
       ...value,
       discriminator: CHANGE_SCHEMA_VERSION_DISCRIMINATOR,
+      fieldConstraints: value.fieldConstraints ?? new Uint8Array([]),
     })
   );
 }
@@ -125,6 +135,11 @@ export function getChangeSchemaVersionInstructionDataDecoder(): Decoder<ChangeSc
       'fieldNames',
       getArrayDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())),
     ],
+    ['bump', getU8Decoder()],
+    [
+      'fieldConstraints',
+      addDecoderSizePrefix(getBytesDecoder(), getU32Decoder()),
+    ],
   ]);
 }
 
@@ -155,6 +170,8 @@ export type ChangeSchemaVersionInput<
   systemProgram?: Address<TAccountSystemProgram>;
   layout: ChangeSchemaVersionInstructionDataArgs['layout'];
   fieldNames: ChangeSchemaVersionInstructionDataArgs['fieldNames'];
+  bump: ChangeSchemaVersionInstructionDataArgs['bump'];
+  fieldConstraints?: ChangeSchemaVersionInstructionDataArgs['fieldConstraints'];
 };
 
 export function getChangeSchemaVersionInstruction<
@@ -105,13 +105,19 @@ export type CloseTokenizedAttestationInstruction<
 
 export type CloseTokenizedAttestationInstructionData = {
   discriminator: number;
+  attestationMintBump: number;
 };
 
-export type CloseTokenizedAttestationInstructionDataArgs = {};
+export type CloseTokenizedAttestationInstructionDataArgs = {
+  attestationMintBump: number;
+};
 
 export function getCloseTokenizedAttestationInstructionDataEncoder(): FixedSizeEncoder<CloseTokenizedAttestationInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', getU8Encoder()]]),
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['attestationMintBump', getU8Encoder()],
+    ]),
     (value) => ({
       ...value,
       discriminator: CLOSE_TOKENIZED_ATTESTATION_DISCRIMINATOR,
@@ -120,7 +126,10 @@ export function getCloseTokenizedAttestationInstructionDataEncoder(): FixedSizeE
 }
 
 export function getCloseTokenizedAttestationInstructionDataDecoder(): FixedSizeDecoder<CloseTokenizedAttestationInstructionData> {
-  return getStructDecoder([['discriminator', getU8Decoder()]]);
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['attestationMintBump', getU8Decoder()],
+  ]);
 }
 
 export function getCloseTokenizedAttestationInstructionDataCodec(): FixedSizeCodec<
@@ -158,9 +167,10 @@ export type CloseTokenizedAttestationInput<
   attestationMint: Address<TAccountAttestationMint>;
   /** Program derived address used as program signer authority */
   sasPda: Address<TAccountSasPda>;
-  /** Associated token account of the related Attestation Token */
+  /** Token account holding the Attestation Token */
   attestationTokenAccount: Address<TAccountAttestationTokenAccount>;
   tokenProgram?: Address<TAccountTokenProgram>;
+  attestationMintBump: CloseTokenizedAttestationInstructionDataArgs['attestationMintBump'];
 };
 
 export function getCloseTokenizedAttestationInstruction<
@@ -235,6 +245,9 @@ export function getCloseTokenizedAttestationInstruction<
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.eventAuthority.value) {
     accounts.eventAuthority.value =
@@ -268,7 +281,9 @@ export function getCloseTokenizedAttestationInstruction<
       getAccountMeta(accounts.attestationTokenAccount),
       getAccountMeta(accounts.tokenProgram),
     ],
-    data: getCloseTokenizedAttestationInstructionDataEncoder().encode({}),
+    data: getCloseTokenizedAttestationInstructionDataEncoder().encode(
+      args as CloseTokenizedAttestationInstructionDataArgs
+    ),
     programAddress,
   } as CloseTokenizedAttestationInstruction<
     TProgramAddress,
@@ -304,7 +319,7 @@ export type ParsedCloseTokenizedAttestationInstruction<
     attestationMint: TAccountMetas[7];
     /** Program derived address used as program signer authority */
     sasPda: TAccountMetas[8];
-    /** Associated token account of the related Attestation Token */
+    /** Token account holding the Attestation Token */
     attestationTokenAccount: TAccountMetas[9];
     tokenProgram: TAccountMetas[10];
   };
@@ -92,12 +92,14 @@ export type CreateAttestationInstructionData = {
   nonce: Address;
   data: ReadonlyUint8Array;
   expiry: bigint;
+  bump: number;
 };
 
 export type CreateAttestationInstructionDataArgs = {
   nonce: Address;
   data: ReadonlyUint8Array;
   expiry: number | bigint;
+  bump: number;
 };
 
 export function getCreateAttestationInstructionDataEncoder(): Encoder<CreateAttestationInstructionDataArgs> {
@@ -107,6 +109,7 @@ export function getCreateAttestationInstructionDataEncoder(): Encoder<CreateAtte
       ['nonce', getAddressEncoder()],
       ['data', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
       ['expiry', getI64Encoder()],
+      ['bump', getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: CREATE_ATTESTATION_DISCRIMINATOR })
   );
@@ -118,6 +121,7 @@ export function getCreateAttestationInstructionDataDecoder(): Decoder<CreateAtte
     ['nonce', getAddressDecoder()],
     ['data', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
     ['expiry', getI64Decoder()],
+    ['bump', getU8Decoder()],
   ]);
 }
 
@@ -151,6 +155,7 @@ export type CreateAttestationInput<
   nonce: CreateAttestationInstructionDataArgs['nonce'];
   data: CreateAttestationInstructionDataArgs['data'];
   expiry: CreateAttestationInstructionDataArgs['expiry'];
+  bump: CreateAttestationInstructionDataArgs['bump'];
 };
 
 export function getCreateAttestationInstruction<
@@ -14,6 +14,8 @@ import {
   getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
   getU32Decoder,
@@ -83,11 +85,15 @@ export type CreateCredentialInstructionData = {
   discriminator: number;
   name: string;
   signers: Array<Address>;
+  bump: number;
+  signerKinds: ReadonlyUint8Array;
 };
 
 export type CreateCredentialInstructionDataArgs = {
   name: string;
   signers: Array<Address>;
+  bump: number;
+  signerKinds?: ReadonlyUint8Array;
 };
 
 export function getCreateCredentialInstructionDataEncoder(): Encoder<CreateCredentialInstructionDataArgs> {
@@ -96,8 +102,14 @@ export function getCreateCredentialInstructionDataEncoder(): Encoder<CreateCrede
       ['discriminator', getU8Encoder()],
       ['name', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['signers', getArrayEncoder(getAddressEncoder())],
+      ['bump', getU8Encoder()],
+      ['signerKinds', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
     ]),
-    (value) => ({ ...value, discriminator: CREATE_CREDENTIAL_DISCRIMINATOR })
+    (value) => ({
+      ...value,
+      discriminator: CREATE_CREDENTIAL_DISCRIMINATOR,
+      signerKinds: value.signerKinds ?? new Uint8Array([]),
+    })
   );
 }
 
@@ -106,6 +118,8 @@ export function getCreateCredentialInstructionDataDecoder(): Decoder<CreateCrede
     ['discriminator', getU8Decoder()],
     ['name', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['signers', getArrayDecoder(getAddressDecoder())],
+    ['bump', getU8Decoder()],
+    ['signerKinds', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
   ]);
 }
 
@@ -131,6 +145,8 @@ export type CreateCredentialInput<
   systemProgram?: Address<TAccountSystemProgram>;
   name: CreateCredentialInstructionDataArgs['name'];
   signers: CreateCredentialInstructionDataArgs['signers'];
+  bump: CreateCredentialInstructionDataArgs['bump'];
+  signerKinds?: CreateCredentialInstructionDataArgs['signerKinds'];
 };
 
 export function getCreateCredentialInstruction<
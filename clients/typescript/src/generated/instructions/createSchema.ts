@@ -9,7 +9,10 @@
 import {
   addDecoderSizePrefix,
   addEncoderSizePrefix,
+  address,
   combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
   getBytesDecoder,
@@ -89,6 +92,12 @@ export type CreateSchemaInstructionData = {
   description: string;
   layout: ReadonlyUint8Array;
   fieldNames: Array<string>;
+  bump: number;
+  commitmentScheme: number;
+  refSchema: Address;
+  refClosePolicy: number;
+  resolverProgram: Address;
+  fieldConstraints: ReadonlyUint8Array;
 };
 
 export type CreateSchemaInstructionDataArgs = {
@@ -96,6 +105,12 @@ export type CreateSchemaInstructionDataArgs = {
   description: string;
   layout: ReadonlyUint8Array;
   fieldNames: Array<string>;
+  bump: number;
+  commitmentScheme?: number;
+  refSchema?: Address;
+  refClosePolicy?: number;
+  resolverProgram?: Address;
+  fieldConstraints?: ReadonlyUint8Array;
 };
 
 export function getCreateSchemaInstructionDataEncoder(): Encoder<CreateSchemaInstructionDataArgs> {
@@ -111,8 +126,25 @@ export function getCreateSchemaInstructionDataEncoder(): Encoder<CreateSchemaIns
           addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
         ),
       ],
+      ['bump', getU8Encoder()],
+      ['commitmentScheme', getU8Encoder()],
+      ['refSchema', getAddressEncoder()],
+      ['refClosePolicy', getU8Encoder()],
+      ['resolverProgram', getAddressEncoder()],
+      [
+        'fieldConstraints',
+        addEncoderSizePrefix(getBytesEncoder(), getU32Encoder()),
+      ],
     ]),
-    (value) => ({ ...value, discriminator: CREATE_SCHEMA_DISCRIMINATOR })
+    (value) => ({
+      ...value,
+      discriminator: CREATE_SCHEMA_DISCRIMINATOR,
+      commitmentScheme: value.commitmentScheme ?? 0,
+      refSchema: value.refSchema ?? address('11111111111111111111111111111111'),
+      refClosePolicy: value.refClosePolicy ?? 1,
+      resolverProgram: value.resolverProgram ?? address('11111111111111111111111111111111'),
+      fieldConstraints: value.fieldConstraints ?? new Uint8Array([]),
+    })
   );
 }
 
@@ -126,6 +158,15 @@ export function getCreateSchemaInstructionDataDecoder(): Decoder<CreateSchemaIns
       'fieldNames',
       getArrayDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())),
     ],
+    ['bump', getU8Decoder()],
+    ['commitmentScheme', getU8Decoder()],
+    ['refSchema', getAddressDecoder()],
+    ['refClosePolicy', getU8Decoder()],
+    ['resolverProgram', getAddressDecoder()],
+    [
+      'fieldConstraints',
+      addDecoderSizePrefix(getBytesDecoder(), getU32Decoder()),
+    ],
   ]);
 }
 
@@ -156,6 +197,12 @@ export type CreateSchemaInput<
   description: CreateSchemaInstructionDataArgs['description'];
   layout: CreateSchemaInstructionDataArgs['layout'];
   fieldNames: CreateSchemaInstructionDataArgs['fieldNames'];
+  bump: CreateSchemaInstructionDataArgs['bump'];
+  commitmentScheme?: CreateSchemaInstructionDataArgs['commitmentScheme'];
+  refSchema?: CreateSchemaInstructionDataArgs['refSchema'];
+  refClosePolicy?: CreateSchemaInstructionDataArgs['refClosePolicy'];
+  resolverProgram?: CreateSchemaInstructionDataArgs['resolverProgram'];
+  fieldConstraints?: CreateSchemaInstructionDataArgs['fieldConstraints'];
 };
 
 export function getCreateSchemaInstruction<
@@ -128,20 +128,26 @@ export type CreateTokenizedAttestationInstructionData = {
   nonce: Address;
   data: ReadonlyUint8Array;
   expiry: bigint;
+  bump: number;
   name: string;
   uri: string;
   symbol: string;
   mintAccountSpace: number;
+  schemaMintBump: number;
+  attestationMintBump: number;
 };
 
 export type CreateTokenizedAttestationInstructionDataArgs = {
   nonce: Address;
   data: ReadonlyUint8Array;
   expiry: number | bigint;
+  bump: number;
   name: string;
   uri: string;
   symbol: string;
-  mintAccountSpace: number;
+  mintAccountSpace?: number;
+  schemaMintBump: number;
+  attestationMintBump: number;
 };
 
 export function getCreateTokenizedAttestationInstructionDataEncoder(): Encoder<CreateTokenizedAttestationInstructionDataArgs> {
@@ -151,14 +157,18 @@ export function getCreateTokenizedAttestationInstructionDataEncoder(): Encoder<C
       ['nonce', getAddressEncoder()],
       ['data', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
       ['expiry', getI64Encoder()],
+      ['bump', getU8Encoder()],
       ['name', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['uri', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['symbol', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
       ['mintAccountSpace', getU16Encoder()],
+      ['schemaMintBump', getU8Encoder()],
+      ['attestationMintBump', getU8Encoder()],
     ]),
     (value) => ({
       ...value,
       discriminator: CREATE_TOKENIZED_ATTESTATION_DISCRIMINATOR,
+      mintAccountSpace: value.mintAccountSpace ?? 0,
     })
   );
 }
@@ -169,10 +179,13 @@ export function getCreateTokenizedAttestationInstructionDataDecoder(): Decoder<C
     ['nonce', getAddressDecoder()],
     ['data', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
     ['expiry', getI64Decoder()],
+    ['bump', getU8Decoder()],
     ['name', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['uri', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['symbol', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
     ['mintAccountSpace', getU16Decoder()],
+    ['schemaMintBump', getU8Decoder()],
+    ['attestationMintBump', getU8Decoder()],
   ]);
 }
 
@@ -225,10 +238,13 @@ export type CreateTokenizedAttestationInput<
   nonce: CreateTokenizedAttestationInstructionDataArgs['nonce'];
   data: CreateTokenizedAttestationInstructionDataArgs['data'];
   expiry: CreateTokenizedAttestationInstructionDataArgs['expiry'];
+  bump: CreateTokenizedAttestationInstructionDataArgs['bump'];
   name: CreateTokenizedAttestationInstructionDataArgs['name'];
   uri: CreateTokenizedAttestationInstructionDataArgs['uri'];
   symbol: CreateTokenizedAttestationInstructionDataArgs['symbol'];
-  mintAccountSpace: CreateTokenizedAttestationInstructionDataArgs['mintAccountSpace'];
+  mintAccountSpace?: CreateTokenizedAttestationInstructionDataArgs['mintAccountSpace'];
+  schemaMintBump: CreateTokenizedAttestationInstructionDataArgs['schemaMintBump'];
+  attestationMintBump: CreateTokenizedAttestationInstructionDataArgs['attestationMintBump'];
 };
 
 export function getCreateTokenizedAttestationInstruction<
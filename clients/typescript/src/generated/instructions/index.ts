@@ -6,15 +6,33 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './approveAttestationRequest';
 export * from './changeAuthorizedSigners';
+export * from './changeCredentialStatus';
+export * from './changePasskeySigners';
 export * from './changeSchemaDescription';
+export * from './changeSchemaFee';
 export * from './changeSchemaStatus';
+export * from './changeSchemaTokenMetadataFields';
 export * from './changeSchemaVersion';
 export * from './closeAttestation';
 export * from './closeTokenizedAttestation';
 export * from './createAttestation';
+export * from './createAttestationRequest';
+export * from './createAttestationWithPasskey';
+export * from './createAttestationWithSignature';
 export * from './createCredential';
+export * from './createMerkleAttestationBatch';
 export * from './createSchema';
 export * from './createTokenizedAttestation';
 export * from './emitEvent';
+export * from './freezeExpiredAttestationToken';
+export * from './migrateAccount';
+export * from './rejectAttestationRequest';
+export * from './revokeMerkleAttestationBatch';
+export * from './tokenizeAttestation';
 export * from './tokenizeSchema';
+export * from './updateCredentialMetadata';
+export * from './updateSchemaTokenMetadata';
+export * from './verifyMerkleAttestation';
+export * from './withdrawTreasury';
@@ -7,20 +7,26 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
   getU8Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
@@ -71,7 +77,7 @@ export type TokenizeSchemaInstruction<
         ? ReadonlyAccount<TAccountCredential>
         : TAccountCredential,
       TAccountSchema extends string
-        ? ReadonlyAccount<TAccountSchema>
+        ? WritableAccount<TAccountSchema>
         : TAccountSchema,
       TAccountMint extends string
         ? WritableAccount<TAccountMint>
@@ -92,28 +98,54 @@ export type TokenizeSchemaInstruction<
 export type TokenizeSchemaInstructionData = {
   discriminator: number;
   maxSize: bigint;
+  mintBump: number;
+  tokenPolicy: number;
+  name: string;
+  uri: string;
+  symbol: string;
 };
 
-export type TokenizeSchemaInstructionDataArgs = { maxSize: number | bigint };
+export type TokenizeSchemaInstructionDataArgs = {
+  maxSize: number | bigint;
+  mintBump: number;
+  tokenPolicy?: number;
+  name: string;
+  uri: string;
+  symbol: string;
+};
 
-export function getTokenizeSchemaInstructionDataEncoder(): FixedSizeEncoder<TokenizeSchemaInstructionDataArgs> {
+export function getTokenizeSchemaInstructionDataEncoder(): Encoder<TokenizeSchemaInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', getU8Encoder()],
       ['maxSize', getU64Encoder()],
+      ['mintBump', getU8Encoder()],
+      ['tokenPolicy', getU8Encoder()],
+      ['name', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['uri', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['symbol', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
     ]),
-    (value) => ({ ...value, discriminator: TOKENIZE_SCHEMA_DISCRIMINATOR })
+    (value) => ({
+      ...value,
+      discriminator: TOKENIZE_SCHEMA_DISCRIMINATOR,
+      tokenPolicy: value.tokenPolicy ?? 3,
+    })
   );
 }
 
-export function getTokenizeSchemaInstructionDataDecoder(): FixedSizeDecoder<TokenizeSchemaInstructionData> {
+export function getTokenizeSchemaInstructionDataDecoder(): Decoder<TokenizeSchemaInstructionData> {
   return getStructDecoder([
     ['discriminator', getU8Decoder()],
     ['maxSize', getU64Decoder()],
+    ['mintBump', getU8Decoder()],
+    ['tokenPolicy', getU8Decoder()],
+    ['name', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['uri', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['symbol', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
   ]);
 }
 
-export function getTokenizeSchemaInstructionDataCodec(): FixedSizeCodec<
+export function getTokenizeSchemaInstructionDataCodec(): Codec<
   TokenizeSchemaInstructionDataArgs,
   TokenizeSchemaInstructionData
 > {
@@ -145,6 +177,11 @@ export type TokenizeSchemaInput<
   systemProgram?: Address<TAccountSystemProgram>;
   tokenProgram?: Address<TAccountTokenProgram>;
   maxSize: TokenizeSchemaInstructionDataArgs['maxSize'];
+  mintBump: TokenizeSchemaInstructionDataArgs['mintBump'];
+  tokenPolicy?: TokenizeSchemaInstructionDataArgs['tokenPolicy'];
+  name: TokenizeSchemaInstructionDataArgs['name'];
+  uri: TokenizeSchemaInstructionDataArgs['uri'];
+  symbol: TokenizeSchemaInstructionDataArgs['symbol'];
 };
 
 export function getTokenizeSchemaInstruction<
@@ -190,7 +227,7 @@ export function getTokenizeSchemaInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     authority: { value: input.authority ?? null, isWritable: false },
     credential: { value: input.credential ?? null, isWritable: false },
-    schema: { value: input.schema ?? null, isWritable: false },
+    schema: { value: input.schema ?? null, isWritable: true },
     mint: { value: input.mint ?? null, isWritable: true },
     sasPda: { value: input.sasPda ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
@@ -13,18 +13,36 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedApproveAttestationRequestInstruction,
   type ParsedChangeAuthorizedSignersInstruction,
+  type ParsedChangeCredentialStatusInstruction,
+  type ParsedChangePasskeySignersInstruction,
   type ParsedChangeSchemaDescriptionInstruction,
+  type ParsedChangeSchemaFeeInstruction,
   type ParsedChangeSchemaStatusInstruction,
+  type ParsedChangeSchemaTokenMetadataFieldsInstruction,
   type ParsedChangeSchemaVersionInstruction,
   type ParsedCloseAttestationInstruction,
   type ParsedCloseTokenizedAttestationInstruction,
   type ParsedCreateAttestationInstruction,
+  type ParsedCreateAttestationRequestInstruction,
+  type ParsedCreateAttestationWithPasskeyInstruction,
+  type ParsedCreateAttestationWithSignatureInstruction,
   type ParsedCreateCredentialInstruction,
+  type ParsedCreateMerkleAttestationBatchInstruction,
   type ParsedCreateSchemaInstruction,
   type ParsedCreateTokenizedAttestationInstruction,
   type ParsedEmitEventInstruction,
+  type ParsedFreezeExpiredAttestationTokenInstruction,
+  type ParsedMigrateAccountInstruction,
+  type ParsedRejectAttestationRequestInstruction,
+  type ParsedRevokeMerkleAttestationBatchInstruction,
+  type ParsedTokenizeAttestationInstruction,
   type ParsedTokenizeSchemaInstruction,
+  type ParsedUpdateCredentialMetadataInstruction,
+  type ParsedUpdateSchemaTokenMetadataInstruction,
+  type ParsedVerifyMerkleAttestationInstruction,
+  type ParsedWithdrawTreasuryInstruction,
 } from '../instructions';
 
 export const SOLANA_ATTESTATION_SERVICE_PROGRAM_ADDRESS =
@@ -32,7 +50,9 @@ export const SOLANA_ATTESTATION_SERVICE_PROGRAM_ADDRESS =
 
 export enum SolanaAttestationServiceAccount {
   Attestation,
+  AttestationRequest,
   Credential,
+  MerkleAttestationBatch,
   Schema,
 }
 
@@ -48,6 +68,24 @@ export enum SolanaAttestationServiceInstruction {
   TokenizeSchema,
   CreateTokenizedAttestation,
   CloseTokenizedAttestation,
+  MigrateAccount,
+  UpdateCredentialMetadata,
+  ChangeCredentialStatus,
+  CreateMerkleAttestationBatch,
+  VerifyMerkleAttestation,
+  RevokeMerkleAttestationBatch,
+  CreateAttestationWithSignature,
+  ChangePasskeySigners,
+  CreateAttestationWithPasskey,
+  CreateAttestationRequest,
+  ApproveAttestationRequest,
+  RejectAttestationRequest,
+  ChangeSchemaFee,
+  WithdrawTreasury,
+  ChangeSchemaTokenMetadataFields,
+  FreezeExpiredAttestationToken,
+  UpdateSchemaTokenMetadata,
+  TokenizeAttestation,
   EmitEvent,
 }
 
@@ -88,6 +126,60 @@ export function identifySolanaAttestationServiceInstruction(
   if (containsBytes(data, getU8Encoder().encode(11), 0)) {
     return SolanaAttestationServiceInstruction.CloseTokenizedAttestation;
   }
+  if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+    return SolanaAttestationServiceInstruction.MigrateAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+    return SolanaAttestationServiceInstruction.UpdateCredentialMetadata;
+  }
+  if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+    return SolanaAttestationServiceInstruction.ChangeCredentialStatus;
+  }
+  if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+    return SolanaAttestationServiceInstruction.CreateMerkleAttestationBatch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+    return SolanaAttestationServiceInstruction.VerifyMerkleAttestation;
+  }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return SolanaAttestationServiceInstruction.RevokeMerkleAttestationBatch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return SolanaAttestationServiceInstruction.CreateAttestationWithSignature;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return SolanaAttestationServiceInstruction.ChangePasskeySigners;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return SolanaAttestationServiceInstruction.CreateAttestationWithPasskey;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return SolanaAttestationServiceInstruction.CreateAttestationRequest;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return SolanaAttestationServiceInstruction.ApproveAttestationRequest;
+  }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return SolanaAttestationServiceInstruction.RejectAttestationRequest;
+  }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return SolanaAttestationServiceInstruction.ChangeSchemaFee;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return SolanaAttestationServiceInstruction.WithdrawTreasury;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return SolanaAttestationServiceInstruction.ChangeSchemaTokenMetadataFields;
+  }
+  if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+    return SolanaAttestationServiceInstruction.FreezeExpiredAttestationToken;
+  }
+  if (containsBytes(data, getU8Encoder().encode(29), 0)) {
+    return SolanaAttestationServiceInstruction.UpdateSchemaTokenMetadata;
+  }
+  if (containsBytes(data, getU8Encoder().encode(30), 0)) {
+    return SolanaAttestationServiceInstruction.TokenizeAttestation;
+  }
   if (containsBytes(data, getU8Encoder().encode(228), 0)) {
     return SolanaAttestationServiceInstruction.EmitEvent;
   }
@@ -132,6 +224,60 @@ export type ParsedSolanaAttestationServiceInstruction<
   | ({
       instructionType: SolanaAttestationServiceInstruction.CloseTokenizedAttestation;
     } & ParsedCloseTokenizedAttestationInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.MigrateAccount;
+    } & ParsedMigrateAccountInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.UpdateCredentialMetadata;
+    } & ParsedUpdateCredentialMetadataInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.ChangeCredentialStatus;
+    } & ParsedChangeCredentialStatusInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.CreateMerkleAttestationBatch;
+    } & ParsedCreateMerkleAttestationBatchInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.VerifyMerkleAttestation;
+    } & ParsedVerifyMerkleAttestationInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.RevokeMerkleAttestationBatch;
+    } & ParsedRevokeMerkleAttestationBatchInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.CreateAttestationWithSignature;
+    } & ParsedCreateAttestationWithSignatureInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.ChangePasskeySigners;
+    } & ParsedChangePasskeySignersInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.CreateAttestationWithPasskey;
+    } & ParsedCreateAttestationWithPasskeyInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.CreateAttestationRequest;
+    } & ParsedCreateAttestationRequestInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.ApproveAttestationRequest;
+    } & ParsedApproveAttestationRequestInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.RejectAttestationRequest;
+    } & ParsedRejectAttestationRequestInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.ChangeSchemaFee;
+    } & ParsedChangeSchemaFeeInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.WithdrawTreasury;
+    } & ParsedWithdrawTreasuryInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.ChangeSchemaTokenMetadataFields;
+    } & ParsedChangeSchemaTokenMetadataFieldsInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.FreezeExpiredAttestationToken;
+    } & ParsedFreezeExpiredAttestationTokenInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.UpdateSchemaTokenMetadata;
+    } & ParsedUpdateSchemaTokenMetadataInstruction<TProgram>)
+  | ({
+      instructionType: SolanaAttestationServiceInstruction.TokenizeAttestation;
+    } & ParsedTokenizeAttestationInstruction<TProgram>)
   | ({
       instructionType: SolanaAttestationServiceInstruction.EmitEvent;
     } & ParsedEmitEventInstruction<TProgram>);
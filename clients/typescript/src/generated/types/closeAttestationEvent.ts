@@ -31,6 +31,7 @@ export type CloseAttestationEvent = {
   discriminator: number;
   schema: Address;
   attestationData: ReadonlyUint8Array;
+  refCount: number;
 };
 
 export type CloseAttestationEventArgs = CloseAttestationEvent;
@@ -43,6 +44,7 @@ export function getCloseAttestationEventEncoder(): Encoder<CloseAttestationEvent
       'attestationData',
       addEncoderSizePrefix(getBytesEncoder(), getU32Encoder()),
     ],
+    ['refCount', getU32Encoder()],
   ]);
 }
 
@@ -54,6 +56,7 @@ export function getCloseAttestationEventDecoder(): Decoder<CloseAttestationEvent
       'attestationData',
       addDecoderSizePrefix(getBytesDecoder(), getU32Decoder()),
     ],
+    ['refCount', getU32Decoder()],
   ]);
 }
 
@@ -33,7 +33,6 @@ use solana_attestation_service_client::{
 };
 
 use spl_associated_token_account::get_associated_token_address_with_program_id;
-use spl_pod::optional_keys::OptionalNonZeroPubkey;
 use spl_token_2022::{
     extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
     state::Mint,
@@ -211,46 +210,6 @@ impl SasDemo {
         expected_acc_size
     }
 
-    fn calculate_token_metadata_size(&self, config: &TokenizedConfig) -> Result<usize> {
-        // Create dummy addresses for size calculation
-        let dummy_key = Pubkey::new_unique();
-
-        // Prepare additional metadata (use placeholder keys for calculating size)
-        let additional_metadata = vec![
-            ("attestation".to_string(), dummy_key.to_string()),
-            ("schema".to_string(), dummy_key.to_string()),
-        ];
-
-        // Create TokenMetadata for size calculation
-        let token_metadata = TokenMetadata {
-            update_authority: OptionalNonZeroPubkey::try_from(Some(dummy_key))?,
-            mint: dummy_key,
-            name: config.token_name.clone(),
-            symbol: config.token_symbol.clone(),
-            uri: config.token_metadata_uri.clone(),
-            additional_metadata,
-        };
-
-        // Calculate the TLV (Type-Length-Value) size
-        Ok(token_metadata.tlv_size_of()?)
-    }
-
-    fn calculate_attestation_mint_size(&self, config: &TokenizedConfig) -> usize {
-        let attestation_extensions = vec![
-            ExtensionType::GroupMemberPointer,
-            ExtensionType::NonTransferable,
-            ExtensionType::MetadataPointer,
-            ExtensionType::PermanentDelegate,
-            ExtensionType::MintCloseAuthority,
-            ExtensionType::TokenGroupMember,
-        ];
-        let base_size =
-            ExtensionType::try_calculate_account_len::<Mint>(&attestation_extensions).unwrap();
-        let metadata_size = self.calculate_token_metadata_size(config).unwrap();
-
-        base_size + metadata_size
-    }
-
     async fn send_and_confirm_instruction(
         &self,
         instruction: Instruction,
@@ -496,10 +455,11 @@ impl SasDemo {
         &self,
         credential_pda: &Pubkey,
         schema_pda: &Pubkey,
+        config: &TokenizedConfig,
     ) -> Result<Pubkey> {
         println!("\n4. Tokenizing Schema...");
 
-        let (schema_mint_pda, _bump) = self.derive_schema_mint_pda(schema_pda);
+        let (schema_mint_pda, schema_mint_bump) = self.derive_schema_mint_pda(schema_pda);
         let (sas_authority, _bump) = Self::derive_sas_authority_address();
 
         let instruction = TokenizeSchemaBuilder::new()
@@ -510,6 +470,10 @@ impl SasDemo {
             .schema(*schema_pda)
             .mint(schema_mint_pda)
             .max_size(self.calculate_schema_mint_size() as u64)
+            .mint_bump(schema_mint_bump)
+            .name(config.token_name.clone())
+            .uri(config.token_metadata_uri.clone())
+            .symbol(config.token_symbol.clone())
             .instruction();
 
         self.send_and_confirm_instruction(instruction, &[&self.wallets.issuer], "Schema tokenized")
@@ -575,7 +539,6 @@ impl SasDemo {
             .name(config.token_name.clone())
             .uri(config.token_metadata_uri.clone())
             .symbol(config.token_symbol.clone())
-            .mint_account_space(self.calculate_attestation_mint_size(config) as u16)
             .recipient_token_account(recipient_token_account)
             .instruction();
 
@@ -728,7 +691,9 @@ impl SasDemo {
         let schema_pda = self.create_schema(&credential_pda).await?;
 
         // Step 4: Tokenize Schema
-        let schema_mint_pda = self.tokenize_schema(&credential_pda, &schema_pda).await?;
+        let schema_mint_pda = self
+            .tokenize_schema(&credential_pda, &schema_pda, &config)
+            .await?;
 
         // Step 5: Create Tokenized Attestation
         let (attestation_pda, attestation_mint_pda) = self
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use solana_attestation_service::{processor::process_change_authorized_signers, ID};
+
+// Instruction data is decoded before any account is read, so an empty
+// account list only exercises the decoder.
+fuzz_target!(|data: &[u8]| {
+    let _ = process_change_authorized_signers(&ID, &[], data);
+});
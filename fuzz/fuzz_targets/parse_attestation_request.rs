@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use solana_attestation_service::state::AttestationRequest;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = AttestationRequest::try_from_bytes(data);
+});
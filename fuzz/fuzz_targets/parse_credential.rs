@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use solana_attestation_service::state::Credential;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(credential) = Credential::try_from_bytes(data) {
+        let _ = credential.validate();
+        let _ = credential.validate_authorized_signers();
+    }
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use solana_attestation_service::state::MerkleAttestationBatch;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = MerkleAttestationBatch::try_from_bytes(data);
+});
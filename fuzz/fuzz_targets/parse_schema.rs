@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use solana_attestation_service::state::Schema;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(schema) = Schema::try_from_bytes(data) {
+        let _ = schema.validate(schema.layout.len() as u32);
+    }
+});
@@ -0,0 +1,23 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use solana_attestation_service::state::Attestation;
+
+fuzz_target!(|input: (Vec<u8>, Vec<u8>, Vec<u8>)| {
+    let (layout, field_constraints, data) = input;
+    let attestation = Attestation {
+        nonce: Default::default(),
+        credential: Default::default(),
+        schema: Default::default(),
+        data,
+        signer: Default::default(),
+        expiry: 0,
+        token_account: Default::default(),
+        bump: 0,
+        signer_kind: 0,
+        ref_attestation: Default::default(),
+        ref_count: 0,
+        resolver_program: Default::default(),
+    };
+    let _ = attestation.validate_data(&layout, &field_constraints);
+});
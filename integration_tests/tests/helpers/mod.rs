@@ -1,4 +1,14 @@
+use openssl::{
+    bn::{BigNum, BigNumContext},
+    ec::{EcGroup, EcKey, PointConversionForm},
+    ecdsa::EcdsaSig,
+    nid::Nid,
+    pkey::Private,
+    sha::sha256,
+};
+use solana_attestation_service_client::signature::PASSKEY_LEN;
 use solana_program_test::{ProgramTest, ProgramTestContext};
+use solana_sdk::pubkey::Pubkey;
 
 /// Get ProgramTestContext with SAS program loaded.
 pub async fn program_test_context() -> ProgramTestContext {
@@ -11,3 +21,66 @@ pub async fn program_test_context() -> ProgramTestContext {
     let ctx = program_test.start_with_context().await;
     ctx
 }
+
+/// A program address of the attestation service for `seeds` derived with a
+/// bump below the canonical one, and that bump.
+#[allow(dead_code)]
+pub fn non_canonical_program_address(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    let program_id = solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID;
+    let (_, canonical_bump) = Pubkey::find_program_address(seeds, &program_id);
+    (0..canonical_bump)
+        .rev()
+        .find_map(|bump| {
+            let bump_seed = [bump];
+            let mut seeds = seeds.to_vec();
+            seeds.push(&bump_seed);
+            Pubkey::create_program_address(&seeds, &program_id)
+                .ok()
+                .map(|address| (address, bump))
+        })
+        .expect("non-canonical bump")
+}
+
+/// A secp256r1 key standing in for a passkey.
+#[allow(dead_code)]
+pub struct Passkey {
+    key: EcKey<Private>,
+    pub public_key: [u8; PASSKEY_LEN],
+}
+
+#[allow(dead_code)]
+impl Passkey {
+    pub fn new() -> Self {
+        let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+        let key = EcKey::generate(&group).unwrap();
+        let mut ctx = BigNumContext::new().unwrap();
+        let public_key = key
+            .public_key()
+            .to_bytes(&group, PointConversionForm::COMPRESSED, &mut ctx)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Self { key, public_key }
+    }
+
+    /// Signature of `message` in the low-S form the secp256r1 program requires.
+    pub fn sign(&self, message: &[u8]) -> [u8; 64] {
+        let signature = EcdsaSig::sign(&sha256(message), &self.key).unwrap();
+        let mut order = BigNum::new().unwrap();
+        let mut ctx = BigNumContext::new().unwrap();
+        self.key.group().order(&mut order, &mut ctx).unwrap();
+        let mut half_order = BigNum::new().unwrap();
+        half_order.rshift1(&order).unwrap();
+
+        let mut s = signature.s().to_owned().unwrap();
+        if s > half_order {
+            let mut low_s = BigNum::new().unwrap();
+            low_s.checked_sub(&order, &s).unwrap();
+            s = low_s;
+        }
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&signature.r().to_vec_padded(32).unwrap());
+        bytes[32..].copy_from_slice(&s.to_vec_padded(32).unwrap());
+        bytes
+    }
+}
@@ -0,0 +1,586 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::Attestation,
+    instructions::{
+        CloseAttestationBuilder, CreateAttestationBuilder, CreateCredentialBuilder,
+        CreateSchemaBuilder, MigrateAccountBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    account::AccountSharedData,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    authority: Keypair,
+    /// Schema whose Attestations block closing while referenced.
+    identity_schema: Pubkey,
+    /// Schema whose Attestations may be closed while referenced.
+    flagged_schema: Pubkey,
+    /// Schema whose Attestations must reference an identity Attestation.
+    employment_schema: Pubkey,
+}
+
+fn create_schema_ix(
+    ctx: &ProgramTestContext,
+    authority: &Keypair,
+    credential: &Pubkey,
+    name: &str,
+    ref_schema: Pubkey,
+    ref_close_policy: u8,
+) -> (Pubkey, Instruction) {
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
+        &[b"schema", &credential.to_bytes(), name.as_bytes(), &[1]],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(*credential)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("schema for test data".to_string())
+        .name(name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into()])
+        .bump(schema_bump)
+        .ref_schema(ref_schema)
+        .ref_close_policy(ref_close_policy)
+        .instruction();
+    (schema_pda, ix)
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
+        .instruction();
+
+    let (identity_schema, identity_ix) = create_schema_ix(
+        &ctx,
+        &authority,
+        &credential_pda,
+        "identity",
+        Pubkey::default(),
+        0,
+    );
+    let (flagged_schema, flagged_ix) = create_schema_ix(
+        &ctx,
+        &authority,
+        &credential_pda,
+        "flagged",
+        Pubkey::default(),
+        1,
+    );
+    let (employment_schema, employment_ix) = create_schema_ix(
+        &ctx,
+        &authority,
+        &credential_pda,
+        "employment",
+        identity_schema,
+        0,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, identity_ix, flagged_ix, employment_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        authority,
+        identity_schema,
+        flagged_schema,
+        employment_schema,
+    }
+}
+
+async fn process(fixtures: &TestFixtures, ix: Instruction) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+/// CreateAttestation instruction for `schema`, referencing `ref_attestation`
+/// when it is set, with the address of the Attestation it creates.
+fn create_attestation_ix(
+    fixtures: &TestFixtures,
+    schema: &Pubkey,
+    ref_attestation: Option<Pubkey>,
+) -> (Pubkey, Instruction) {
+    let nonce = Pubkey::new_unique();
+    let (attestation_pda, attestation_bump) = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &fixtures.credential.to_bytes(),
+            &schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let mut builder = CreateAttestationBuilder::new();
+    builder
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(*schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .nonce(nonce)
+        .data(
+            borsh::to_vec(&TestData {
+                name: "attest".to_string(),
+                location: 11,
+            })
+            .unwrap(),
+        )
+        .expiry(0)
+        .bump(attestation_bump);
+    if let Some(ref_attestation) = ref_attestation {
+        builder.add_remaining_account(AccountMeta::new(ref_attestation, false));
+    }
+    (attestation_pda, builder.instruction())
+}
+
+async fn create_attestation(
+    fixtures: &TestFixtures,
+    schema: &Pubkey,
+    ref_attestation: Option<Pubkey>,
+) -> Pubkey {
+    let (attestation_pda, ix) = create_attestation_ix(fixtures, schema, ref_attestation);
+    process(fixtures, ix).await.unwrap();
+    attestation_pda
+}
+
+/// CloseAttestation instruction with `remaining_accounts` for references.
+fn close_attestation_ix(
+    fixtures: &TestFixtures,
+    attestation: &Pubkey,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    let (event_auth_pda, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID);
+    CloseAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .attestation(*attestation)
+        .event_authority(event_auth_pda)
+        .system_program(system_program::ID)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .add_remaining_accounts(remaining_accounts)
+        .instruction()
+}
+
+async fn get_attestation(fixtures: &TestFixtures, attestation: &Pubkey) -> Option<Attestation> {
+    fixtures
+        .ctx
+        .banks_client
+        .get_account(*attestation)
+        .await
+        .expect("get_account")
+        .map(|account| Attestation::try_from_slice(&account.data).unwrap())
+}
+
+#[tokio::test]
+async fn create_attestation_with_ref_success() {
+    let fixtures = setup().await;
+    let identity = create_attestation(&fixtures, &fixtures.identity_schema, None).await;
+    let employment =
+        create_attestation(&fixtures, &fixtures.employment_schema, Some(identity)).await;
+
+    let employment_attestation = get_attestation(&fixtures, &employment).await.unwrap();
+    assert_eq!(employment_attestation.ref_attestation, identity);
+    assert_eq!(employment_attestation.ref_count, 0);
+    let identity_attestation = get_attestation(&fixtures, &identity).await.unwrap();
+    assert_eq!(identity_attestation.ref_attestation, Pubkey::default());
+    assert_eq!(identity_attestation.ref_count, 1);
+
+    // Schemas without a ref_schema accept a reference to any Attestation.
+    let flagged = create_attestation(&fixtures, &fixtures.flagged_schema, Some(employment)).await;
+    let flagged_attestation = get_attestation(&fixtures, &flagged).await.unwrap();
+    assert_eq!(flagged_attestation.ref_attestation, employment);
+    let employment_attestation = get_attestation(&fixtures, &employment).await.unwrap();
+    assert_eq!(employment_attestation.ref_count, 1);
+}
+
+#[tokio::test]
+async fn create_attestation_with_ref_fail_required_schema() {
+    let fixtures = setup().await;
+
+    // Schema requires a reference.
+    let (_, ix) = create_attestation_ix(&fixtures, &fixtures.employment_schema, None);
+    let tx_err = process(&fixtures, ix).await.unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(29))
+    );
+
+    // Reference to an Attestation of another Schema than the required one.
+    let flagged = create_attestation(&fixtures, &fixtures.flagged_schema, None).await;
+    let (_, ix) = create_attestation_ix(&fixtures, &fixtures.employment_schema, Some(flagged));
+    let tx_err = process(&fixtures, ix).await.unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(29))
+    );
+
+    // Reference to an account that is not an Attestation.
+    let (_, ix) = create_attestation_ix(
+        &fixtures,
+        &fixtures.employment_schema,
+        Some(fixtures.identity_schema),
+    );
+    let tx_err = process(&fixtures, ix).await.unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+    );
+}
+
+#[tokio::test]
+async fn close_referenced_attestation_blocked() {
+    let fixtures = setup().await;
+    let identity = create_attestation(&fixtures, &fixtures.identity_schema, None).await;
+    let employment =
+        create_attestation(&fixtures, &fixtures.employment_schema, Some(identity)).await;
+
+    // The Schema is passed to read its close policy.
+    let identity_schema = [AccountMeta::new_readonly(fixtures.identity_schema, false)];
+    let tx_err = process(
+        &fixtures,
+        close_attestation_ix(&fixtures, &identity, &identity_schema),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(30))
+    );
+
+    // Closing the referencing Attestation removes the reference.
+    process(
+        &fixtures,
+        close_attestation_ix(&fixtures, &employment, &[AccountMeta::new(identity, false)]),
+    )
+    .await
+    .unwrap();
+    let identity_attestation = get_attestation(&fixtures, &identity).await.unwrap();
+    assert_eq!(identity_attestation.ref_count, 0);
+
+    // Unreferenced Attestations are closed without their Schema.
+    process(&fixtures, close_attestation_ix(&fixtures, &identity, &[]))
+        .await
+        .unwrap();
+    assert!(get_attestation(&fixtures, &identity).await.is_none());
+}
+
+#[tokio::test]
+async fn close_referenced_attestation_allowed_and_flagged() {
+    let fixtures = setup().await;
+    let flagged = create_attestation(&fixtures, &fixtures.flagged_schema, None).await;
+    let referencing = create_attestation(&fixtures, &fixtures.identity_schema, Some(flagged)).await;
+
+    process(
+        &fixtures,
+        close_attestation_ix(
+            &fixtures,
+            &flagged,
+            &[AccountMeta::new_readonly(fixtures.flagged_schema, false)],
+        ),
+    )
+    .await
+    .unwrap();
+    assert!(get_attestation(&fixtures, &flagged).await.is_none());
+
+    // The referencing Attestation still points at the closed one, and can be
+    // closed itself.
+    let referencing_attestation = get_attestation(&fixtures, &referencing).await.unwrap();
+    assert_eq!(referencing_attestation.ref_attestation, flagged);
+    process(
+        &fixtures,
+        close_attestation_ix(&fixtures, &referencing, &[AccountMeta::new(flagged, false)]),
+    )
+    .await
+    .unwrap();
+    assert!(get_attestation(&fixtures, &referencing).await.is_none());
+}
+
+/// `attestation` data as written before accounts carried a layout version.
+fn legacy_attestation_data(attestation: &Attestation) -> Vec<u8> {
+    let mut data = vec![2];
+    data.extend_from_slice(attestation.nonce.as_ref());
+    data.extend_from_slice(attestation.credential.as_ref());
+    data.extend_from_slice(attestation.schema.as_ref());
+    data.extend((attestation.data.len() as u32).to_le_bytes());
+    data.extend_from_slice(&attestation.data);
+    data.extend_from_slice(attestation.signer.as_ref());
+    data.extend(attestation.expiry.to_le_bytes());
+    data.extend_from_slice(attestation.token_account.as_ref());
+    data
+}
+
+#[tokio::test]
+async fn close_attestation_referencing_legacy_attestation() {
+    let mut fixtures = setup().await;
+    let identity = create_attestation(&fixtures, &fixtures.identity_schema, None).await;
+    let employment =
+        create_attestation(&fixtures, &fixtures.employment_schema, Some(identity)).await;
+
+    // Rewrite the referenced Attestation with the legacy layout.
+    let identity_attestation = get_attestation(&fixtures, &identity).await.unwrap();
+    let mut account: AccountSharedData = fixtures
+        .ctx
+        .banks_client
+        .get_account(identity)
+        .await
+        .unwrap()
+        .unwrap()
+        .into();
+    account.set_data_from_slice(&legacy_attestation_data(&identity_attestation));
+    fixtures.ctx.set_account(&identity, &account);
+
+    // The reference cannot be removed before the legacy Attestation is
+    // migrated.
+    let close_ix =
+        close_attestation_ix(&fixtures, &employment, &[AccountMeta::new(identity, false)]);
+    let tx_err = process(&fixtures, close_ix.clone()).await.unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(20))
+    );
+
+    let migrate_ix = MigrateAccountBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .account(identity)
+        .system_program(system_program::ID)
+        .bump(identity_attestation.bump)
+        .instruction();
+    process(&fixtures, migrate_ix).await.unwrap();
+    fixtures.ctx.last_blockhash = fixtures.ctx.get_new_latest_blockhash().await.unwrap();
+    process(&fixtures, close_ix).await.unwrap();
+    assert!(get_attestation(&fixtures, &employment).await.is_none());
+    let identity_attestation = get_attestation(&fixtures, &identity).await.unwrap();
+    assert_eq!(identity_attestation.ref_count, 0);
+}
+
+/// Create a Credential of `authority` named `name`, with a Schema without
+/// references, returning both.
+async fn create_other_credential(
+    fixtures: &TestFixtures,
+    authority: &Keypair,
+    name: &str,
+) -> (Pubkey, Pubkey) {
+    let ctx = &fixtures.ctx;
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
+        .instruction();
+    let (schema_pda, schema_ix) = create_schema_ix(
+        ctx,
+        authority,
+        &credential_pda,
+        "other",
+        Pubkey::default(),
+        1,
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+    (credential_pda, schema_pda)
+}
+
+/// Create an Attestation of `credential` signed by `authority`, with
+/// `ref_accounts` after its accounts.
+async fn create_other_attestation(
+    fixtures: &TestFixtures,
+    authority: &Keypair,
+    credential: &Pubkey,
+    schema: &Pubkey,
+    ref_accounts: &[AccountMeta],
+) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let nonce = Pubkey::new_unique();
+    let (attestation_pda, attestation_bump) = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &credential.to_bytes(),
+            &schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let ix = CreateAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(*credential)
+        .schema(*schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .nonce(nonce)
+        .data(
+            borsh::to_vec(&TestData {
+                name: "attest".to_string(),
+                location: 11,
+            })
+            .unwrap(),
+        )
+        .expiry(0)
+        .bump(attestation_bump)
+        .add_remaining_accounts(ref_accounts)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+#[tokio::test]
+async fn create_attestation_with_ref_fail_other_credential() {
+    let fixtures = setup().await;
+    let identity = create_attestation(&fixtures, &fixtures.identity_schema, None).await;
+
+    // A Credential of another issuer cannot reference the identity
+    // Attestation, which would keep it from being closed.
+    let other_authority = Keypair::new();
+    let (other_credential, other_schema) =
+        create_other_credential(&fixtures, &other_authority, "other").await;
+    let tx_err = create_other_attestation(
+        &fixtures,
+        &other_authority,
+        &other_credential,
+        &other_schema,
+        &[AccountMeta::new(identity, false)],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(29))
+    );
+
+    // Passing the identity Attestation's Credential does not help, since the
+    // signer is not one of its authorized signers.
+    let tx_err = create_other_attestation(
+        &fixtures,
+        &other_authority,
+        &other_credential,
+        &other_schema,
+        &[
+            AccountMeta::new(identity, false),
+            AccountMeta::new_readonly(fixtures.credential, false),
+        ],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(5))
+    );
+
+    let identity_attestation = get_attestation(&fixtures, &identity).await.unwrap();
+    assert_eq!(identity_attestation.ref_count, 0);
+}
+
+#[tokio::test]
+async fn create_attestation_with_ref_other_credential_same_signer_success() {
+    let fixtures = setup().await;
+    let identity = create_attestation(&fixtures, &fixtures.identity_schema, None).await;
+
+    // The identity Attestation's signer may reference it from another of its
+    // Credentials, which is passed after the referenced Attestation.
+    let (other_credential, other_schema) =
+        create_other_credential(&fixtures, &fixtures.authority, "other").await;
+    create_other_attestation(
+        &fixtures,
+        &fixtures.authority,
+        &other_credential,
+        &other_schema,
+        &[
+            AccountMeta::new(identity, false),
+            AccountMeta::new_readonly(fixtures.credential, false),
+        ],
+    )
+    .await
+    .unwrap();
+
+    let identity_attestation = get_attestation(&fixtures, &identity).await.unwrap();
+    assert_eq!(identity_attestation.ref_count, 1);
+}
@@ -0,0 +1,496 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::{Attestation, AttestationRequest},
+    commitment::{sha256_commitment, CommitmentScheme},
+    instructions::{
+        ApproveAttestationRequestBuilder, CreateAttestationRequestBuilder, CreateCredentialBuilder,
+        CreateSchemaBuilder, RejectAttestationRequestBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    /// Schema storing a sha256 commitment instead of the data.
+    commitment_schema: Pubkey,
+    authority: Keypair,
+    subject: Keypair,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let subject = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
+        .instruction();
+
+    let schema_name = "test_data";
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("schema for test data".to_string())
+        .name(schema_name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into()])
+        .bump(schema_bump)
+        .instruction();
+
+    let commitment_schema_name = "private_data";
+    let (commitment_schema_pda, commitment_schema_bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            commitment_schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_commitment_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(commitment_schema_pda)
+        .system_program(system_program::ID)
+        .description("schema for private test data".to_string())
+        .name(commitment_schema_name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into()])
+        .bump(commitment_schema_bump)
+        .commitment_scheme(CommitmentScheme::Sha256 as u8)
+        .instruction();
+
+    // The subject pays for its requests.
+    let fund_subject_ix =
+        system_instruction::transfer(&ctx.payer.pubkey(), &subject.pubkey(), 1_000_000_000);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            create_credential_ix,
+            create_schema_ix,
+            create_commitment_schema_ix,
+            fund_subject_ix,
+        ],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        commitment_schema: commitment_schema_pda,
+        authority,
+        subject,
+    }
+}
+
+async fn process(
+    fixtures: &TestFixtures,
+    ix: Instruction,
+    signer: &Keypair,
+) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, signer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+async fn get_lamports(fixtures: &TestFixtures, address: &Pubkey) -> u64 {
+    fixtures
+        .ctx
+        .banks_client
+        .get_account(*address)
+        .await
+        .expect("get_account")
+        .map(|account| account.lamports)
+        .unwrap_or_default()
+}
+
+struct Request {
+    schema: Pubkey,
+    nonce: Pubkey,
+    data: Vec<u8>,
+    address: Pubkey,
+    bump: u8,
+}
+
+impl Request {
+    fn new(fixtures: &TestFixtures, data: Vec<u8>) -> Self {
+        Self::for_schema(fixtures, fixtures.schema, data)
+    }
+
+    fn for_schema(fixtures: &TestFixtures, schema: Pubkey, data: Vec<u8>) -> Self {
+        let nonce = Pubkey::new_unique();
+        let (address, bump) = Pubkey::find_program_address(
+            &[
+                b"attestationRequest",
+                &fixtures.credential.to_bytes(),
+                &schema.to_bytes(),
+                &nonce.to_bytes(),
+            ],
+            &SOLANA_ATTESTATION_SERVICE_ID,
+        );
+        Self {
+            schema,
+            nonce,
+            data,
+            address,
+            bump,
+        }
+    }
+
+    fn attestation_pda(&self, fixtures: &TestFixtures) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                b"attestation",
+                &fixtures.credential.to_bytes(),
+                &self.schema.to_bytes(),
+                &self.nonce.to_bytes(),
+            ],
+            &SOLANA_ATTESTATION_SERVICE_ID,
+        )
+    }
+
+    fn create_ix(&self, fixtures: &TestFixtures) -> Instruction {
+        CreateAttestationRequestBuilder::new()
+            .subject(fixtures.subject.pubkey())
+            .credential(fixtures.credential)
+            .schema(self.schema)
+            .attestation_request(self.address)
+            .system_program(system_program::ID)
+            .nonce(self.nonce)
+            .data(self.data.clone())
+            .expiry(0)
+            .bump(self.bump)
+            .instruction()
+    }
+
+    fn approve_ix(
+        &self,
+        fixtures: &TestFixtures,
+        authority: &Pubkey,
+        subject: &Pubkey,
+    ) -> Instruction {
+        let (attestation_pda, attestation_bump) = self.attestation_pda(fixtures);
+        ApproveAttestationRequestBuilder::new()
+            .payer(fixtures.ctx.payer.pubkey())
+            .authority(*authority)
+            .credential(fixtures.credential)
+            .schema(self.schema)
+            .attestation(attestation_pda)
+            .system_program(system_program::ID)
+            .attestation_request(self.address)
+            .subject(*subject)
+            .bump(attestation_bump)
+            .instruction()
+    }
+
+    fn reject_ix(&self, fixtures: &TestFixtures, authority: &Pubkey) -> Instruction {
+        RejectAttestationRequestBuilder::new()
+            .authority(*authority)
+            .credential(fixtures.credential)
+            .attestation_request(self.address)
+            .subject(fixtures.subject.pubkey())
+            .instruction()
+    }
+}
+
+fn test_data() -> Vec<u8> {
+    borsh::to_vec(&TestData {
+        name: "attest".to_string(),
+        location: 11,
+    })
+    .unwrap()
+}
+
+#[tokio::test]
+async fn approve_attestation_request_success() {
+    let fixtures = setup().await;
+    let subject_lamports = get_lamports(&fixtures, &fixtures.subject.pubkey()).await;
+    let request = Request::new(&fixtures, test_data());
+
+    process(&fixtures, request.create_ix(&fixtures), &fixtures.subject)
+        .await
+        .unwrap();
+
+    let request_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(request.address)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let attestation_request = AttestationRequest::try_from_slice(&request_account.data).unwrap();
+    assert_eq!(attestation_request.subject, fixtures.subject.pubkey());
+    assert_eq!(attestation_request.nonce, request.nonce);
+    assert_eq!(attestation_request.credential, fixtures.credential);
+    assert_eq!(attestation_request.schema, fixtures.schema);
+    assert_eq!(attestation_request.data, request.data);
+    assert_eq!(attestation_request.expiry, 0);
+    assert_eq!(attestation_request.bump, request.bump);
+    // The subject paid the request's rent.
+    assert_eq!(
+        get_lamports(&fixtures, &fixtures.subject.pubkey()).await,
+        subject_lamports - request_account.lamports
+    );
+
+    process(
+        &fixtures,
+        request.approve_ix(
+            &fixtures,
+            &fixtures.authority.pubkey(),
+            &fixtures.subject.pubkey(),
+        ),
+        &fixtures.authority,
+    )
+    .await
+    .unwrap();
+
+    let (attestation_pda, attestation_bump) = request.attestation_pda(&fixtures);
+    let attestation_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let attestation = Attestation::try_from_slice(&attestation_account.data).unwrap();
+    assert_eq!(attestation.nonce, request.nonce);
+    assert_eq!(attestation.credential, fixtures.credential);
+    assert_eq!(attestation.schema, fixtures.schema);
+    assert_eq!(attestation.data, request.data);
+    assert_eq!(attestation.signer, fixtures.authority.pubkey());
+    assert_eq!(attestation.bump, attestation_bump);
+
+    // The request is closed and its rent refunded to the subject.
+    assert_eq!(get_lamports(&fixtures, &request.address).await, 0);
+    assert_eq!(
+        get_lamports(&fixtures, &fixtures.subject.pubkey()).await,
+        subject_lamports
+    );
+}
+
+#[tokio::test]
+async fn reject_attestation_request_success() {
+    let fixtures = setup().await;
+    let subject_lamports = get_lamports(&fixtures, &fixtures.subject.pubkey()).await;
+    let request = Request::new(&fixtures, test_data());
+
+    process(&fixtures, request.create_ix(&fixtures), &fixtures.subject)
+        .await
+        .unwrap();
+    process(
+        &fixtures,
+        request.reject_ix(&fixtures, &fixtures.authority.pubkey()),
+        &fixtures.authority,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(get_lamports(&fixtures, &request.address).await, 0);
+    assert_eq!(
+        get_lamports(&fixtures, &fixtures.subject.pubkey()).await,
+        subject_lamports
+    );
+    let (attestation_pda, _) = request.attestation_pda(&fixtures);
+    assert_eq!(get_lamports(&fixtures, &attestation_pda).await, 0);
+}
+
+#[tokio::test]
+async fn create_attestation_request_fail_invalid_data() {
+    let fixtures = setup().await;
+    let request = Request::new(&fixtures, vec![1, 2, 3]);
+
+    let tx_err = process(&fixtures, request.create_ix(&fixtures), &fixtures.subject)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(6))
+    );
+}
+
+#[tokio::test]
+async fn approve_attestation_request_commitment_success() {
+    let fixtures = setup().await;
+    let layout = TestData::get_serialized_representation();
+    let salt = [7; 32];
+
+    // Requests against commitment Schemas carry only the commitment, so
+    // neither the data nor the salt are stored on-chain.
+    for data in [test_data(), [test_data().as_slice(), &salt].concat()] {
+        let request = Request::for_schema(&fixtures, fixtures.commitment_schema, data);
+        let tx_err = process(&fixtures, request.create_ix(&fixtures), &fixtures.subject)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            tx_err,
+            TransactionError::InstructionError(0, InstructionError::Custom(6))
+        );
+    }
+
+    let commitment = sha256_commitment(&layout, &test_data(), &salt).unwrap();
+    let request = Request::for_schema(&fixtures, fixtures.commitment_schema, commitment.to_vec());
+    process(&fixtures, request.create_ix(&fixtures), &fixtures.subject)
+        .await
+        .unwrap();
+    let request_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(request.address)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let attestation_request = AttestationRequest::try_from_slice(&request_account.data).unwrap();
+    assert_eq!(attestation_request.data, commitment);
+
+    process(
+        &fixtures,
+        request.approve_ix(
+            &fixtures,
+            &fixtures.authority.pubkey(),
+            &fixtures.subject.pubkey(),
+        ),
+        &fixtures.authority,
+    )
+    .await
+    .unwrap();
+    let (attestation_pda, _) = request.attestation_pda(&fixtures);
+    let attestation_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let attestation = Attestation::try_from_slice(&attestation_account.data).unwrap();
+    assert_eq!(attestation.data, commitment);
+}
+
+#[tokio::test]
+async fn approve_attestation_request_fail_unauthorized_signer() {
+    let fixtures = setup().await;
+    let request = Request::new(&fixtures, test_data());
+    let other_authority = Keypair::new();
+
+    process(&fixtures, request.create_ix(&fixtures), &fixtures.subject)
+        .await
+        .unwrap();
+
+    let tx_err = process(
+        &fixtures,
+        request.approve_ix(
+            &fixtures,
+            &other_authority.pubkey(),
+            &fixtures.subject.pubkey(),
+        ),
+        &other_authority,
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(5))
+    );
+
+    let tx_err = process(
+        &fixtures,
+        request.reject_ix(&fixtures, &other_authority.pubkey()),
+        &other_authority,
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(5))
+    );
+}
+
+#[tokio::test]
+async fn approve_attestation_request_fail_wrong_subject() {
+    let fixtures = setup().await;
+    let request = Request::new(&fixtures, test_data());
+
+    process(&fixtures, request.create_ix(&fixtures), &fixtures.subject)
+        .await
+        .unwrap();
+
+    // The refund must go to the subject that created the request.
+    let tx_err = process(
+        &fixtures,
+        request.approve_ix(
+            &fixtures,
+            &fixtures.authority.pubkey(),
+            &fixtures.ctx.payer.pubkey(),
+        ),
+        &fixtures.authority,
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(32))
+    );
+}
@@ -3,9 +3,15 @@ use helpers::program_test_context;
 use solana_attestation_service_client::{
     accounts::Credential,
     instructions::{ChangeAuthorizedSignersBuilder, CreateCredentialBuilder},
+    signature::{ethereum_signer, SignerKind},
 };
 use solana_sdk::{
-    pubkey::Pubkey, signature::Keypair, signer::Signer, system_program, transaction::Transaction,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
 };
 
 mod helpers;
@@ -17,7 +23,7 @@ async fn change_authorized_signers_success() {
     let authority = Keypair::new();
     let name = "test";
 
-    let (credential_pda, _bump) = Pubkey::find_program_address(
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
         &[
             b"credential",
             &authority.pubkey().to_bytes(),
@@ -33,6 +39,7 @@ async fn change_authorized_signers_success() {
         .system_program(system_program::ID)
         .name(name.to_string())
         .signers(vec![authority.pubkey(), ctx.payer.pubkey()])
+        .bump(credential_bump)
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -84,7 +91,11 @@ async fn change_authorized_signers_success() {
     }
 
     // Test upsizing authorized_signers.
-    let new_signers = vec![Keypair::new().pubkey(), Keypair::new().pubkey(), Keypair::new().pubkey()];
+    let new_signers = vec![
+        Keypair::new().pubkey(),
+        Keypair::new().pubkey(),
+        Keypair::new().pubkey(),
+    ];
     let ix = ChangeAuthorizedSignersBuilder::new()
         .payer(ctx.payer.pubkey())
         .authority(authority.pubkey())
@@ -156,4 +167,104 @@ async fn change_authorized_signers_success() {
     for i in 0..credential.authorized_signers.len() {
         assert_eq!(credential.authorized_signers[i], new_signers[i]);
     }
+
+    // Test adding an Ethereum signer with its kind.
+    let new_signers = vec![authority.pubkey(), ethereum_signer(&[7; 20])];
+    let new_signer_kinds = vec![SignerKind::Ed25519 as u8, SignerKind::Secp256k1 as u8];
+    let ix = ChangeAuthorizedSignersBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .system_program(system_program::ID)
+        .signers(new_signers.clone())
+        .signer_kinds(new_signer_kinds.clone())
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let credential_account = ctx
+        .banks_client
+        .get_account(credential_pda)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+
+    let credential = Credential::try_from_slice(&credential_account.data).unwrap();
+    assert_eq!(credential.authorized_signers, new_signers);
+    assert_eq!(credential.authorized_signer_kinds, new_signer_kinds);
+}
+
+#[tokio::test]
+async fn change_authorized_signers_fail_invalid_signer_kinds() {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let name = "test";
+
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            name.as_bytes(),
+        ],
+        &Pubkey::from(solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID),
+    );
+
+    let ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // An ed25519 key registered as an Ethereum address.
+    let ix = ChangeAuthorizedSignersBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .system_program(system_program::ID)
+        .signers(vec![authority.pubkey(), Keypair::new().pubkey()])
+        .signer_kinds(vec![SignerKind::Ed25519 as u8, SignerKind::Secp256k1 as u8])
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(41))
+    );
 }
@@ -0,0 +1,240 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::Credential,
+    instructions::{
+        ChangeCredentialStatusBuilder, CreateAttestationBuilder, CreateCredentialBuilder,
+        CreateSchemaBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    authority: Keypair,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
+        .instruction();
+
+    let schema_name = "test_data";
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("schema for test data".to_string())
+        .name(schema_name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into()])
+        .bump(schema_bump)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        authority,
+    }
+}
+
+fn create_attestation_ix(fixtures: &TestFixtures) -> (Instruction, Pubkey) {
+    let data = TestData {
+        name: "attest".to_string(),
+        location: 11,
+    };
+    let nonce = Pubkey::new_unique();
+    let (attestation_pda, attestation_bump) = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &fixtures.credential.to_bytes(),
+            &fixtures.schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let ix = CreateAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(borsh::to_vec(&data).unwrap())
+        .expiry(0)
+        .nonce(nonce)
+        .bump(attestation_bump)
+        .instruction();
+    (ix, attestation_pda)
+}
+
+async fn change_status(
+    fixtures: &TestFixtures,
+    authority: &Keypair,
+    is_paused: bool,
+) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let ix = ChangeCredentialStatusBuilder::new()
+        .authority(authority.pubkey())
+        .credential(fixtures.credential)
+        .is_paused(is_paused)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+async fn get_credential(fixtures: &TestFixtures) -> Credential {
+    let credential_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(fixtures.credential)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    Credential::try_from_slice(&credential_account.data).unwrap()
+}
+
+#[tokio::test]
+async fn pause_and_unpause_credential_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    assert!(!get_credential(&fixtures).await.is_paused);
+
+    change_status(&fixtures, &fixtures.authority, true)
+        .await
+        .unwrap();
+    let credential = get_credential(&fixtures).await;
+    assert!(credential.is_paused);
+    assert_eq!(credential.name, b"test");
+    assert_eq!(
+        credential.authorized_signers,
+        vec![fixtures.authority.pubkey()]
+    );
+
+    // Attestations cannot be created while the Credential is paused.
+    let (ix, _) = create_attestation_ix(&fixtures);
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(21))
+    );
+
+    change_status(&fixtures, &fixtures.authority, false)
+        .await
+        .unwrap();
+    assert!(!get_credential(&fixtures).await.is_paused);
+
+    let (ix, attestation_pda) = create_attestation_ix(&fixtures);
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+    assert!(ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[tokio::test]
+async fn change_credential_status_fail_wrong_authority() {
+    let fixtures = setup().await;
+    let other_authority = Keypair::new();
+
+    let tx_err = change_status(&fixtures, &other_authority, true)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+    assert!(!get_credential(&fixtures).await.is_paused);
+}
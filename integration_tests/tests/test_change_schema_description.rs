@@ -22,7 +22,7 @@ async fn change_schema_description_success() {
     let ctx = program_test_context().await;
     let authority = Keypair::new();
     let credential_name = "test";
-    let (credential_pda, _bump) = Pubkey::find_program_address(
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
         &[
             b"credential",
             &authority.pubkey().to_bytes(),
@@ -38,6 +38,7 @@ async fn change_schema_description_success() {
         .system_program(system_program::ID)
         .name(credential_name.to_string())
         .signers(vec![authority.pubkey(), ctx.payer.pubkey()])
+        .bump(credential_bump)
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -56,7 +57,7 @@ async fn change_schema_description_success() {
     let description = "first test";
     let schema_layout = TestData::get_serialized_representation();
     let field_names = vec!["name".into(), "location".into()];
-    let (schema_pda, _bump) = Pubkey::find_program_address(
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
         &[
             b"schema",
             &credential_pda.to_bytes(),
@@ -75,6 +76,7 @@ async fn change_schema_description_success() {
         .name(schema_name.to_string())
         .layout(schema_layout.clone())
         .field_names(field_names.clone())
+        .bump(schema_bump)
         .instruction();
     let transaction = Transaction::new_signed_with_payer(
         &[create_schema_ix],
@@ -7,7 +7,12 @@ use solana_attestation_service_client::{
 use solana_attestation_service_macros::SchemaStructSerialize;
 use solana_program_test::ProgramTestContext;
 use solana_sdk::{
-    instruction::InstructionError, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program, transaction::{Transaction, TransactionError}
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
 };
 mod helpers;
 
@@ -38,7 +43,7 @@ async fn setup() -> TestFixtures {
 
     let authority = Keypair::new();
     let credential_name = "test";
-    let (credential_pda, _bump) = Pubkey::find_program_address(
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
         &[
             b"credential",
             &authority.pubkey().to_bytes(),
@@ -54,6 +59,7 @@ async fn setup() -> TestFixtures {
         .system_program(system_program::ID)
         .name(credential_name.to_string())
         .signers(vec![authority.pubkey(), ctx.payer.pubkey()])
+        .bump(credential_bump)
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -72,7 +78,7 @@ async fn setup() -> TestFixtures {
     let description = "schema for test data";
     let schema_layout = TestData::get_serialized_representation();
     let field_names = vec!["name".into(), "location".into()];
-    let (schema_pda, _bump) = Pubkey::find_program_address(
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
         &[
             b"schema",
             &credential_pda.to_bytes(),
@@ -91,6 +97,7 @@ async fn setup() -> TestFixtures {
         .name(schema_name.to_string())
         .layout(schema_layout.clone())
         .field_names(field_names.clone())
+        .bump(schema_bump)
         .instruction();
     let transaction = Transaction::new_signed_with_payer(
         &[create_schema_ix],
@@ -125,7 +132,7 @@ async fn change_schema_version_success() {
     } = setup().await;
 
     // Update schema for version 2
-    let (schema_pda2, _bump) = Pubkey::find_program_address(
+    let (schema_pda2, schema_bump2) = Pubkey::find_program_address(
         &[
             b"schema",
             &credential_pda.to_bytes(),
@@ -146,6 +153,7 @@ async fn change_schema_version_success() {
         .system_program(system_program::ID)
         .layout(schema_layout2.clone())
         .field_names(field_names2.clone())
+        .bump(schema_bump2)
         .instruction();
     let transaction = Transaction::new_signed_with_payer(
         &[change_schema_version_ix],
@@ -191,7 +199,7 @@ async fn change_schema_version_fail_incorrect_credential() {
     } = setup().await;
 
     let credential_name = "test-2";
-    let (credential_pda_2, _bump) = Pubkey::find_program_address(
+    let (credential_pda_2, credential_bump_2) = Pubkey::find_program_address(
         &[
             b"credential",
             &authority.pubkey().to_bytes(),
@@ -207,6 +215,7 @@ async fn change_schema_version_fail_incorrect_credential() {
         .system_program(system_program::ID)
         .name(credential_name.to_string())
         .signers(vec![authority.pubkey(), ctx.payer.pubkey()])
+        .bump(credential_bump_2)
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -221,7 +230,7 @@ async fn change_schema_version_fail_incorrect_credential() {
         .unwrap();
 
     // Update schema for version 2
-    let (schema_pda2, _bump) = Pubkey::find_program_address(
+    let (schema_pda2, schema_bump2) = Pubkey::find_program_address(
         &[
             b"schema",
             &credential_pda_2.to_bytes(),
@@ -242,6 +251,59 @@ async fn change_schema_version_fail_incorrect_credential() {
         .system_program(system_program::ID)
         .layout(schema_layout2.clone())
         .field_names(field_names2.clone())
+        .bump(schema_bump2)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[change_schema_version_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    )
+}
+
+#[tokio::test]
+async fn change_schema_version_fail_incorrect_new_schema() {
+    let TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        authority,
+        schema_name,
+        schema_description: _,
+    } = setup().await;
+
+    // Schema PDA of version 3 instead of version 2.
+    let (schema_pda3, schema_bump3) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[3],
+        ],
+        &Pubkey::from(solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID),
+    );
+
+    let change_schema_version_ix = ChangeSchemaVersionBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .existing_schema(schema_pda)
+        .new_schema(schema_pda3)
+        .system_program(system_program::ID)
+        .layout(TestData2::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into(), "phone".into()])
+        .bump(schema_bump3)
         .instruction();
     let transaction = Transaction::new_signed_with_payer(
         &[change_schema_version_ix],
@@ -35,7 +35,7 @@ async fn setup() -> TestFixtures {
 
     let authority = Keypair::new();
     let credential_name = "test";
-    let (credential_pda, _bump) = Pubkey::find_program_address(
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
         &[
             b"credential",
             &authority.pubkey().to_bytes(),
@@ -51,6 +51,7 @@ async fn setup() -> TestFixtures {
         .system_program(system_program::ID)
         .name(credential_name.to_string())
         .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
         .instruction();
 
     // Create Schema
@@ -58,7 +59,7 @@ async fn setup() -> TestFixtures {
     let description = "schema for test data";
     let schema_data = TestData::get_serialized_representation();
     let field_names = vec!["name".into(), "location".into()];
-    let (schema_pda, _bump) = Pubkey::find_program_address(
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
         &[
             b"schema",
             &credential_pda.to_bytes(),
@@ -77,6 +78,7 @@ async fn setup() -> TestFixtures {
         .name(schema_name.to_string())
         .layout(schema_data.clone())
         .field_names(field_names)
+        .bump(schema_bump)
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -119,7 +121,7 @@ async fn close_attestation_success() {
         .serialize(&mut serialized_attestation_data)
         .unwrap();
     let nonce = Pubkey::new_unique();
-    let attestation_pda = Pubkey::find_program_address(
+    let (attestation_pda, attestation_bump) = Pubkey::find_program_address(
         &[
             b"attestation",
             &credential.to_bytes(),
@@ -127,8 +129,7 @@ async fn close_attestation_success() {
             &nonce.to_bytes(),
         ],
         &SOLANA_ATTESTATION_SERVICE_ID,
-    )
-    .0;
+    );
     let create_attestation_ix = CreateAttestationBuilder::new()
         .payer(ctx.payer.pubkey())
         .authority(authority.pubkey())
@@ -139,6 +140,7 @@ async fn close_attestation_success() {
         .data(serialized_attestation_data.clone())
         .expiry(expiry)
         .nonce(nonce)
+        .bump(attestation_bump)
         .instruction();
 
     let create_tx = Transaction::new_signed_with_payer(
@@ -220,6 +222,7 @@ async fn close_attestation_success() {
                     assert_eq!(event.discriminator, 0);
                     assert_eq!(event.schema, schema);
                     assert_eq!(event.attestation_data, serialized_attestation_data);
+                    assert_eq!(event.ref_count, 0);
                     event_found = true;
                 }
             }
@@ -0,0 +1,363 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::{Attestation, Schema},
+    commitment::{
+        disclose_fields, per_field_commitment, poseidon_commitment, poseidon_field_elements,
+        poseidon_salt, sha256_commitment, verify_field_disclosure, verify_poseidon_commitment,
+        verify_sha256_commitment, CommitmentScheme, PoseidonCommitmentError, BN254_MODULUS,
+    },
+    instructions::{CreateAttestationBuilder, CreateCredentialBuilder, CreateSchemaBuilder},
+    layout::LayoutError,
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    authority: Keypair,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        authority,
+    }
+}
+
+fn create_schema_ix(fixtures: &TestFixtures, commitment_scheme: u8) -> (Instruction, Pubkey) {
+    let schema_name = "private_data";
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &fixtures.credential.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let ix = CreateSchemaBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("schema for private data".to_string())
+        .name(schema_name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into()])
+        .bump(schema_bump)
+        .commitment_scheme(commitment_scheme)
+        .instruction();
+    (ix, schema_pda)
+}
+
+fn create_attestation_ix(
+    fixtures: &TestFixtures,
+    schema: Pubkey,
+    data: Vec<u8>,
+) -> (Instruction, Pubkey) {
+    let nonce = Pubkey::new_unique();
+    let (attestation_pda, attestation_bump) = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &fixtures.credential.to_bytes(),
+            &schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let ix = CreateAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(data)
+        .expiry(0)
+        .nonce(nonce)
+        .bump(attestation_bump)
+        .instruction();
+    (ix, attestation_pda)
+}
+
+async fn process(fixtures: &TestFixtures, ix: Instruction) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+#[tokio::test]
+async fn create_sha256_commitment_attestation_success() {
+    let fixtures = setup().await;
+    let layout = TestData::get_serialized_representation();
+
+    let (ix, schema_pda) = create_schema_ix(&fixtures, CommitmentScheme::Sha256 as u8);
+    process(&fixtures, ix).await.unwrap();
+    let schema_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(schema_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let schema = Schema::try_from_slice(&schema_account.data).unwrap();
+    assert_eq!(schema.commitment_scheme, CommitmentScheme::Sha256 as u8);
+
+    let data = borsh::to_vec(&TestData {
+        name: "attest".to_string(),
+        location: 11,
+    })
+    .unwrap();
+    let salt = [7; 32];
+    let commitment = sha256_commitment(&layout, &data, &salt).unwrap();
+
+    let (ix, attestation_pda) = create_attestation_ix(&fixtures, schema_pda, commitment.to_vec());
+    process(&fixtures, ix).await.unwrap();
+
+    let attestation_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let attestation = Attestation::try_from_slice(&attestation_account.data).unwrap();
+    assert_eq!(attestation.data, commitment);
+
+    // The holder reveals the data and salt to a verifier.
+    assert!(verify_sha256_commitment(&attestation.data, &layout, &data, &salt).unwrap());
+    assert!(!verify_sha256_commitment(&attestation.data, &layout, &data, &[8; 32]).unwrap());
+    let other_data = borsh::to_vec(&TestData {
+        name: "attest".to_string(),
+        location: 12,
+    })
+    .unwrap();
+    assert!(!verify_sha256_commitment(&attestation.data, &layout, &other_data, &salt).unwrap());
+    // Revealed data must still conform to the Schema layout.
+    assert_eq!(
+        verify_sha256_commitment(&attestation.data, &layout, &data[..data.len() - 1], &salt),
+        Err(LayoutError::InvalidField(1))
+    );
+}
+
+#[tokio::test]
+async fn create_per_field_commitment_attestation_success() {
+    let fixtures = setup().await;
+    let layout = TestData::get_serialized_representation();
+
+    let (ix, schema_pda) = create_schema_ix(&fixtures, CommitmentScheme::Sha256PerField as u8);
+    process(&fixtures, ix).await.unwrap();
+
+    let data = borsh::to_vec(&TestData {
+        name: "attest".to_string(),
+        location: 11,
+    })
+    .unwrap();
+    let seed = [7; 32];
+    let commitment = per_field_commitment(&layout, &data, &seed).unwrap();
+    assert_eq!(commitment.len(), 2 * 32);
+
+    let (ix, attestation_pda) = create_attestation_ix(&fixtures, schema_pda, commitment.clone());
+    process(&fixtures, ix).await.unwrap();
+
+    let attestation_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let attestation = Attestation::try_from_slice(&attestation_account.data).unwrap();
+    assert_eq!(attestation.data, commitment);
+
+    // The holder reveals only the location to a verifier.
+    let disclosures = disclose_fields(&layout, &data, &seed, &[1]).unwrap();
+    assert_eq!(disclosures.len(), 1);
+    let disclosure = &disclosures[0];
+    assert_eq!(disclosure.index, 1);
+    assert_eq!(disclosure.value, vec![11]);
+    assert!(verify_field_disclosure(&attestation.data, &layout, disclosure).unwrap());
+
+    let mut tampered = disclosure.clone();
+    tampered.value = vec![12];
+    assert!(!verify_field_disclosure(&attestation.data, &layout, &tampered).unwrap());
+    // The disclosure must match the hash at its own index.
+    let mut moved = disclosures[0].clone();
+    moved.index = 0;
+    assert_eq!(
+        verify_field_disclosure(&attestation.data, &layout, &moved),
+        Err(LayoutError::InvalidField(0))
+    );
+    assert_eq!(
+        disclose_fields(&layout, &data, &seed, &[2]),
+        Err(LayoutError::InvalidField(2))
+    );
+
+    // Data that is not one hash per field is rejected.
+    let (ix, _) = create_attestation_ix(&fixtures, schema_pda, commitment[..32].to_vec());
+    let tx_err = process(&fixtures, ix).await.unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(6))
+    );
+}
+
+#[tokio::test]
+async fn create_poseidon_commitment_attestation_success() {
+    let fixtures = setup().await;
+    let layout = TestData::get_serialized_representation();
+
+    let (ix, schema_pda) = create_schema_ix(&fixtures, CommitmentScheme::Poseidon as u8);
+    process(&fixtures, ix).await.unwrap();
+
+    let data = borsh::to_vec(&TestData {
+        name: "attest".to_string(),
+        location: 11,
+    })
+    .unwrap();
+    // name is its length and packed bytes, location is a single element.
+    let elements = poseidon_field_elements(&layout, &data).unwrap();
+    assert_eq!(elements.len(), 3);
+    assert_eq!(elements[0][31], 6);
+    assert_eq!(&elements[1][26..], b"attest");
+    assert_eq!(elements[2][31], 11);
+
+    let salt = poseidon_salt([7; 32]);
+    let commitment = poseidon_commitment(&layout, &data, &salt).unwrap();
+    // The salt must be a field element.
+    assert_eq!(
+        poseidon_commitment(&layout, &data, &[0xff; 32]),
+        Err(PoseidonCommitmentError::InvalidSalt)
+    );
+
+    // The Attestation stores the commitment computed by the client as is.
+    let (ix, attestation_pda) = create_attestation_ix(&fixtures, schema_pda, commitment.to_vec());
+    process(&fixtures, ix).await.unwrap();
+
+    let attestation_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let attestation = Attestation::try_from_slice(&attestation_account.data).unwrap();
+    assert_eq!(attestation.data, commitment);
+    assert!(verify_poseidon_commitment(&attestation.data, &layout, &data, &salt).unwrap());
+    assert!(!verify_poseidon_commitment(
+        &attestation.data,
+        &layout,
+        &data,
+        &poseidon_salt([8; 32])
+    )
+    .unwrap());
+
+    // Plaintext data, with or without the salt, is rejected so it never
+    // appears on-chain, and so are 32 byte values that are not field elements.
+    for data in [
+        data.clone(),
+        [data.as_slice(), &salt].concat(),
+        BN254_MODULUS.to_vec(),
+        vec![0xff; 32],
+    ] {
+        let (ix, _) = create_attestation_ix(&fixtures, schema_pda, data);
+        let tx_err = process(&fixtures, ix).await.unwrap_err();
+        assert_eq!(
+            tx_err,
+            TransactionError::InstructionError(0, InstructionError::Custom(6))
+        );
+    }
+}
+
+#[tokio::test]
+async fn create_commitment_attestation_fail_plaintext_data() {
+    let fixtures = setup().await;
+
+    let (ix, schema_pda) = create_schema_ix(&fixtures, CommitmentScheme::Sha256 as u8);
+    process(&fixtures, ix).await.unwrap();
+
+    let data = borsh::to_vec(&TestData {
+        name: "attest".to_string(),
+        location: 11,
+    })
+    .unwrap();
+    let (ix, _) = create_attestation_ix(&fixtures, schema_pda, data);
+    let tx_err = process(&fixtures, ix).await.unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(6))
+    );
+}
+
+#[tokio::test]
+async fn create_schema_fail_invalid_commitment_scheme() {
+    let fixtures = setup().await;
+
+    let (ix, _) = create_schema_ix(&fixtures, 9);
+    let tx_err = process(&fixtures, ix).await.unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(22))
+    );
+}
@@ -0,0 +1,942 @@
+use borsh::BorshSerialize;
+use helpers::{program_test_context, Passkey};
+use solana_attestation_service_client::{
+    instructions::{
+        ApproveAttestationRequestBuilder, ChangeAuthorizedSignersBuilder,
+        ChangeCredentialStatusBuilder, ChangePasskeySignersBuilder, ChangeSchemaDescriptionBuilder,
+        ChangeSchemaFeeBuilder, ChangeSchemaStatusBuilder, ChangeSchemaTokenMetadataFieldsBuilder,
+        ChangeSchemaVersionBuilder, CloseAttestationBuilder, CloseTokenizedAttestationBuilder,
+        CreateAttestationBuilder, CreateAttestationRequestBuilder,
+        CreateAttestationWithPasskeyBuilder, CreateAttestationWithSignatureBuilder,
+        CreateCredentialBuilder, CreateMerkleAttestationBatchBuilder, CreateSchemaBuilder,
+        CreateTokenizedAttestationBuilder, FreezeExpiredAttestationTokenBuilder,
+        MigrateAccountBuilder, RejectAttestationRequestBuilder,
+        RevokeMerkleAttestationBatchBuilder, TokenizeAttestationBuilder, TokenizeSchemaBuilder,
+        UpdateCredentialMetadataBuilder, UpdateSchemaTokenMetadataBuilder,
+        VerifyMerkleAttestationBuilder, WithdrawTreasuryBuilder,
+    },
+    merkle::{MerkleAttestationRecord, MerkleTree},
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+    signature::{
+        attestation_message, ed25519_instruction, find_used_message_pda, passkey_signer,
+        secp256r1_instruction, webauthn_challenge, webauthn_signed_message,
+    },
+    token::{
+        TOKEN_POLICY_FREEZE_ON_EXPIRY, TOKEN_POLICY_NON_TRANSFERABLE,
+        TOKEN_POLICY_PERMANENT_DELEGATE,
+    },
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program, sysvar,
+    transaction::Transaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id, ID as ATA_PROGRAM_ID,
+};
+use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+#[derive(SchemaStructSerialize)]
+struct TestDataV2 {
+    _name: String,
+    _location: u8,
+    _phone: u64,
+}
+
+/// Compute units of a `create_program_address` syscall.
+const CREATE_PROGRAM_ADDRESS_UNITS: u64 = 1_500;
+
+/// Compute units spent checking that `bumps` are canonical, which derives an
+/// address for every bump above each of them.
+fn canonical_bump_units(bumps: &[u8]) -> u64 {
+    bumps
+        .iter()
+        .map(|bump| (u8::MAX - bump) as u64 * CREATE_PROGRAM_ADDRESS_UNITS)
+        .sum()
+}
+
+fn find_pda(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &SOLANA_ATTESTATION_SERVICE_ID)
+}
+
+/// Process `instructions` in one transaction signed by the payer and
+/// `signers`, and return the compute units it consumed, failing if it
+/// consumed more than `max_compute_units`.
+async fn process_and_measure(
+    ctx: &mut ProgramTestContext,
+    label: &str,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+    max_compute_units: u64,
+) -> u64 {
+    ctx.last_blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&[&ctx.payer][..], signers].concat(),
+        ctx.last_blockhash,
+    );
+    let result = ctx
+        .banks_client
+        .process_transaction_with_metadata(transaction)
+        .await
+        .unwrap();
+    result.result.unwrap();
+    let compute_units = result.metadata.unwrap().compute_units_consumed;
+    println!("{label}: {compute_units} CU");
+    assert!(
+        compute_units <= max_compute_units,
+        "{label} consumed {compute_units} CU, more than {max_compute_units}"
+    );
+    compute_units
+}
+
+/// Checks the compute units used by every instruction stay within their
+/// budgets. `EmitEvent` is only invoked by the program itself and is measured
+/// as part of `CloseAttestation`. Run with
+/// `cargo test --test test_compute_units -- --nocapture` to see the numbers.
+#[tokio::test]
+async fn compute_units_within_budget() {
+    let mut ctx = program_test_context().await;
+    let authority = Keypair::new();
+    let passkey = Passkey::new();
+    let data = borsh::to_vec(&TestData {
+        name: "attest".to_string(),
+        location: 11,
+    })
+    .unwrap();
+
+    // Credential
+
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = find_pda(&[
+        b"credential",
+        &authority.pubkey().to_bytes(),
+        credential_name.as_bytes(),
+    ]);
+    let ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
+        .instruction();
+    let max_compute_units = 10_000 + canonical_bump_units(&[credential_bump]);
+    process_and_measure(
+        &mut ctx,
+        "CreateCredential",
+        &[ix],
+        &[&authority],
+        max_compute_units,
+    )
+    .await;
+
+    let ix = ChangeAuthorizedSignersBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .system_program(system_program::ID)
+        .signers(vec![authority.pubkey(), ctx.payer.pubkey()])
+        .instruction();
+    process_and_measure(
+        &mut ctx,
+        "ChangeAuthorizedSigners",
+        &[ix],
+        &[&authority],
+        10_000,
+    )
+    .await;
+
+    let ix = ChangePasskeySignersBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .system_program(system_program::ID)
+        .passkeys(vec![passkey.public_key])
+        .instruction();
+    process_and_measure(
+        &mut ctx,
+        "ChangePasskeySigners",
+        &[ix],
+        &[&authority],
+        10_000,
+    )
+    .await;
+
+    let ix = UpdateCredentialMetadataBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .system_program(system_program::ID)
+        .display_name("Test Issuer".to_string())
+        .description("Issues test attestations".to_string())
+        .uri("https://example.com/profile.json".to_string())
+        .logo_uri("https://example.com/logo.png".to_string())
+        .website("https://example.com".to_string())
+        .instruction();
+    process_and_measure(
+        &mut ctx,
+        "UpdateCredentialMetadata",
+        &[ix],
+        &[&authority],
+        15_000,
+    )
+    .await;
+
+    for is_paused in [true, false] {
+        let ix = ChangeCredentialStatusBuilder::new()
+            .authority(authority.pubkey())
+            .credential(credential_pda)
+            .is_paused(is_paused)
+            .instruction();
+        process_and_measure(
+            &mut ctx,
+            "ChangeCredentialStatus",
+            &[ix],
+            &[&authority],
+            5_000,
+        )
+        .await;
+    }
+
+    // Schema
+
+    let schema_name = "test_data";
+    let (schema_pda, schema_bump) = find_pda(&[
+        b"schema",
+        &credential_pda.to_bytes(),
+        schema_name.as_bytes(),
+        &[1],
+    ]);
+    let ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("schema for test data".to_string())
+        .name(schema_name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into()])
+        .bump(schema_bump)
+        .instruction();
+    let max_compute_units = 15_000 + canonical_bump_units(&[schema_bump]);
+    process_and_measure(
+        &mut ctx,
+        "CreateSchema",
+        &[ix],
+        &[&authority],
+        max_compute_units,
+    )
+    .await;
+
+    let ix = ChangeSchemaDescriptionBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("updated schema for test data".to_string())
+        .instruction();
+    process_and_measure(
+        &mut ctx,
+        "ChangeSchemaDescription",
+        &[ix],
+        &[&authority],
+        10_000,
+    )
+    .await;
+
+    for is_paused in [true, false] {
+        let ix = ChangeSchemaStatusBuilder::new()
+            .authority(authority.pubkey())
+            .credential(credential_pda)
+            .schema(schema_pda)
+            .is_paused(is_paused)
+            .instruction();
+        process_and_measure(&mut ctx, "ChangeSchemaStatus", &[ix], &[&authority], 5_000).await;
+    }
+
+    let (schema_v2_pda, schema_v2_bump) = find_pda(&[
+        b"schema",
+        &credential_pda.to_bytes(),
+        schema_name.as_bytes(),
+        &[2],
+    ]);
+    let ix = ChangeSchemaVersionBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .existing_schema(schema_pda)
+        .new_schema(schema_v2_pda)
+        .system_program(system_program::ID)
+        .layout(TestDataV2::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into(), "phone".into()])
+        .bump(schema_v2_bump)
+        .instruction();
+    let max_compute_units = 15_000 + canonical_bump_units(&[schema_v2_bump]);
+    process_and_measure(
+        &mut ctx,
+        "ChangeSchemaVersion",
+        &[ix],
+        &[&authority],
+        max_compute_units,
+    )
+    .await;
+
+    // Attestation
+
+    let nonce = Pubkey::new_unique();
+    let (attestation_pda, attestation_bump) = find_pda(&[
+        b"attestation",
+        &credential_pda.to_bytes(),
+        &schema_pda.to_bytes(),
+        &nonce.to_bytes(),
+    ]);
+    let ix = CreateAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(data.clone())
+        .expiry(0)
+        .nonce(nonce)
+        .bump(attestation_bump)
+        .instruction();
+    let max_compute_units = 15_000 + canonical_bump_units(&[attestation_bump]);
+    process_and_measure(
+        &mut ctx,
+        "CreateAttestation",
+        &[ix],
+        &[&authority],
+        max_compute_units,
+    )
+    .await;
+
+    let (event_auth_pda, _bump) = find_pda(&[b"__event_authority"]);
+    let ix = CloseAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .attestation(attestation_pda)
+        .event_authority(event_auth_pda)
+        .system_program(system_program::ID)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .instruction();
+    process_and_measure(&mut ctx, "CloseAttestation", &[ix], &[&authority], 15_000).await;
+
+    let nonce = Pubkey::new_unique();
+    let deadline = i64::MAX;
+    let message = attestation_message(&credential_pda, &schema_pda, &nonce, &data, 0, deadline);
+    let (attestation_pda, attestation_bump) = find_pda(&[
+        b"attestation",
+        &credential_pda.to_bytes(),
+        &schema_pda.to_bytes(),
+        &nonce.to_bytes(),
+    ]);
+    let (used_message_pda, used_message_bump) = find_used_message_pda(&message);
+    let ed25519_ix = ed25519_instruction(
+        &authority.pubkey(),
+        authority
+            .sign_message(&message)
+            .as_ref()
+            .try_into()
+            .unwrap(),
+        &message,
+    );
+    let ix = CreateAttestationWithSignatureBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .instructions_sysvar(sysvar::instructions::ID)
+        .used_message(used_message_pda)
+        .deadline(deadline)
+        .nonce(nonce)
+        .data(data.clone())
+        .expiry(0)
+        .bump(attestation_bump)
+        .used_message_bump(used_message_bump)
+        .instruction();
+    let max_compute_units = 30_000 + canonical_bump_units(&[attestation_bump, used_message_bump]);
+    process_and_measure(
+        &mut ctx,
+        "CreateAttestationWithSignature",
+        &[ed25519_ix, ix],
+        &[],
+        max_compute_units,
+    )
+    .await;
+
+    let nonce = Pubkey::new_unique();
+    let message = attestation_message(&credential_pda, &schema_pda, &nonce, &data, 0, deadline);
+    let (attestation_pda, attestation_bump) = find_pda(&[
+        b"attestation",
+        &credential_pda.to_bytes(),
+        &schema_pda.to_bytes(),
+        &nonce.to_bytes(),
+    ]);
+    let (used_message_pda, used_message_bump) = find_used_message_pda(&message);
+    // rpIdHash, flags with user present set, signCount
+    let authenticator_data = [[0; 32].as_slice(), [0x01].as_slice(), [0; 4].as_slice()].concat();
+    let client_data_json = format!(
+        r#"{{"type":"webauthn.get","challenge":"{}","origin":"https://example.com"}}"#,
+        webauthn_challenge(&message)
+    )
+    .into_bytes();
+    let signed_message = webauthn_signed_message(&authenticator_data, &client_data_json);
+    let secp256r1_ix = secp256r1_instruction(
+        &passkey.public_key,
+        &passkey.sign(&signed_message),
+        &signed_message,
+    );
+    let ix = CreateAttestationWithPasskeyBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(passkey_signer(&passkey.public_key))
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .instructions_sysvar(sysvar::instructions::ID)
+        .used_message(used_message_pda)
+        .deadline(deadline)
+        .authenticator_data(authenticator_data)
+        .client_data_json(client_data_json)
+        .nonce(nonce)
+        .data(data.clone())
+        .expiry(0)
+        .bump(attestation_bump)
+        .used_message_bump(used_message_bump)
+        .instruction();
+    let max_compute_units = 40_000 + canonical_bump_units(&[attestation_bump, used_message_bump]);
+    process_and_measure(
+        &mut ctx,
+        "CreateAttestationWithPasskey",
+        &[secp256r1_ix, ix],
+        &[],
+        max_compute_units,
+    )
+    .await;
+
+    // Attestation requests, with the payer as the subject.
+
+    let subject = ctx.payer.pubkey();
+    let request_pda = |nonce: &Pubkey| {
+        find_pda(&[
+            b"attestationRequest",
+            &credential_pda.to_bytes(),
+            &schema_pda.to_bytes(),
+            &nonce.to_bytes(),
+        ])
+    };
+    let create_request_ix = |nonce: Pubkey, request: Pubkey, bump: u8| {
+        CreateAttestationRequestBuilder::new()
+            .subject(subject)
+            .credential(credential_pda)
+            .schema(schema_pda)
+            .attestation_request(request)
+            .system_program(system_program::ID)
+            .nonce(nonce)
+            .data(data.clone())
+            .expiry(0)
+            .bump(bump)
+            .instruction()
+    };
+
+    let nonce = Pubkey::new_unique();
+    let (request, request_bump) = request_pda(&nonce);
+    let max_compute_units = 15_000 + canonical_bump_units(&[request_bump]);
+    process_and_measure(
+        &mut ctx,
+        "CreateAttestationRequest",
+        &[create_request_ix(nonce, request, request_bump)],
+        &[],
+        max_compute_units,
+    )
+    .await;
+
+    let (attestation_pda, attestation_bump) = find_pda(&[
+        b"attestation",
+        &credential_pda.to_bytes(),
+        &schema_pda.to_bytes(),
+        &nonce.to_bytes(),
+    ]);
+    let ix = ApproveAttestationRequestBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .attestation_request(request)
+        .subject(subject)
+        .bump(attestation_bump)
+        .instruction();
+    let max_compute_units = 20_000 + canonical_bump_units(&[attestation_bump]);
+    process_and_measure(
+        &mut ctx,
+        "ApproveAttestationRequest",
+        &[ix],
+        &[&authority],
+        max_compute_units,
+    )
+    .await;
+
+    let nonce = Pubkey::new_unique();
+    let (request, request_bump) = request_pda(&nonce);
+    let ix = create_request_ix(nonce, request, request_bump);
+    let max_compute_units = 15_000 + canonical_bump_units(&[request_bump]);
+    process_and_measure(
+        &mut ctx,
+        "CreateAttestationRequest",
+        &[ix],
+        &[],
+        max_compute_units,
+    )
+    .await;
+    let ix = RejectAttestationRequestBuilder::new()
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .attestation_request(request)
+        .subject(subject)
+        .instruction();
+    process_and_measure(
+        &mut ctx,
+        "RejectAttestationRequest",
+        &[ix],
+        &[&authority],
+        10_000,
+    )
+    .await;
+
+    // Migration of a Credential written before accounts carried a layout
+    // version.
+
+    let legacy_authority = Pubkey::new_unique();
+    let (legacy_credential_pda, legacy_credential_bump) = find_pda(&[
+        b"credential",
+        &legacy_authority.to_bytes(),
+        credential_name.as_bytes(),
+    ]);
+    let mut legacy_data = vec![0];
+    legacy_data.extend_from_slice(legacy_authority.as_ref());
+    legacy_data.extend((credential_name.len() as u32).to_le_bytes());
+    legacy_data.extend_from_slice(credential_name.as_bytes());
+    legacy_data.extend(1u32.to_le_bytes());
+    legacy_data.extend_from_slice(legacy_authority.as_ref());
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    ctx.set_account(
+        &legacy_credential_pda,
+        &AccountSharedData::from(Account {
+            lamports: rent.minimum_balance(legacy_data.len()),
+            data: legacy_data,
+            owner: SOLANA_ATTESTATION_SERVICE_ID,
+            executable: false,
+            rent_epoch: 0,
+        }),
+    );
+    let ix = MigrateAccountBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .account(legacy_credential_pda)
+        .system_program(system_program::ID)
+        .bump(legacy_credential_bump)
+        .instruction();
+    let max_compute_units = 15_000 + canonical_bump_units(&[legacy_credential_bump]);
+    process_and_measure(&mut ctx, "MigrateAccount", &[ix], &[], max_compute_units).await;
+
+    // Merkle attestation batch
+
+    let records: Vec<MerkleAttestationRecord> = (0..8)
+        .map(|i| MerkleAttestationRecord {
+            subject: Pubkey::new_unique(),
+            data: borsh::to_vec(&TestData {
+                name: format!("attest {}", i),
+                location: i,
+            })
+            .unwrap(),
+            expiry: 0,
+        })
+        .collect();
+    let tree = MerkleTree::new(&records);
+    let nonce = Pubkey::new_unique();
+    let (merkle_batch_pda, merkle_batch_bump) = find_pda(&[
+        b"merkleBatch",
+        &credential_pda.to_bytes(),
+        &schema_pda.to_bytes(),
+        &nonce.to_bytes(),
+    ]);
+    let ix = CreateMerkleAttestationBatchBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .merkle_batch(merkle_batch_pda)
+        .system_program(system_program::ID)
+        .nonce(nonce)
+        .root(tree.root().unwrap())
+        .bump(merkle_batch_bump)
+        .instruction();
+    let max_compute_units = 15_000 + canonical_bump_units(&[merkle_batch_bump]);
+    process_and_measure(
+        &mut ctx,
+        "CreateMerkleAttestationBatch",
+        &[ix],
+        &[&authority],
+        max_compute_units,
+    )
+    .await;
+
+    let ix = VerifyMerkleAttestationBuilder::new()
+        .merkle_batch(merkle_batch_pda)
+        .subject(records[0].subject)
+        .data(records[0].data.clone())
+        .expiry(records[0].expiry)
+        .proof(tree.proof(0).unwrap())
+        .instruction();
+    process_and_measure(&mut ctx, "VerifyMerkleAttestation", &[ix], &[], 15_000).await;
+
+    let ix = RevokeMerkleAttestationBatchBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .merkle_batch(merkle_batch_pda)
+        .instruction();
+    process_and_measure(
+        &mut ctx,
+        "RevokeMerkleAttestationBatch",
+        &[ix],
+        &[&authority],
+        10_000,
+    )
+    .await;
+
+    // Tokenization
+
+    let (sas_pda, _bump) = find_pda(&[b"sas"]);
+    let (schema_mint_pda, schema_mint_bump) = find_pda(&[b"schemaMint", &schema_pda.to_bytes()]);
+    let ix = TokenizeSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .mint(schema_mint_pda)
+        .sas_pda(sas_pda)
+        .max_size(100)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .mint_bump(schema_mint_bump)
+        .token_policy(
+            TOKEN_POLICY_NON_TRANSFERABLE
+                | TOKEN_POLICY_PERMANENT_DELEGATE
+                | TOKEN_POLICY_FREEZE_ON_EXPIRY,
+        )
+        .name("Test Schema".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .instruction();
+    let max_compute_units = 120_000 + canonical_bump_units(&[schema_mint_bump]);
+    process_and_measure(
+        &mut ctx,
+        "TokenizeSchema",
+        &[ix],
+        &[&authority],
+        max_compute_units,
+    )
+    .await;
+
+    let ix = ChangeSchemaTokenMetadataFieldsBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .field_names(vec!["location".into(), "name".into()])
+        .instruction();
+    process_and_measure(
+        &mut ctx,
+        "ChangeSchemaTokenMetadataFields",
+        &[ix],
+        &[&authority],
+        10_000,
+    )
+    .await;
+
+    let ix = UpdateSchemaTokenMetadataBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .mint(schema_mint_pda)
+        .sas_pda(sas_pda)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .name("Updated Schema".to_string())
+        .uri("https://example.com".to_string())
+        .symbol("UVAT".to_string())
+        .mint_bump(schema_mint_bump)
+        .instruction();
+    let max_compute_units = 60_000 + canonical_bump_units(&[schema_mint_bump]);
+    process_and_measure(
+        &mut ctx,
+        "UpdateSchemaTokenMetadata",
+        &[ix],
+        &[&authority],
+        max_compute_units,
+    )
+    .await;
+
+    let nonce = Pubkey::new_unique();
+    let (attestation_pda, attestation_bump) = find_pda(&[
+        b"attestation",
+        &credential_pda.to_bytes(),
+        &schema_pda.to_bytes(),
+        &nonce.to_bytes(),
+    ]);
+    let (attestation_mint_pda, attestation_mint_bump) =
+        find_pda(&[b"attestationMint", &attestation_pda.to_bytes()]);
+    let recipient = Pubkey::new_unique();
+    let recipient_token_account = get_associated_token_address_with_program_id(
+        &recipient,
+        &attestation_mint_pda,
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    let ix = CreateTokenizedAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .schema_mint(schema_mint_pda)
+        .attestation_mint(attestation_mint_pda)
+        .sas_pda(sas_pda)
+        .recipient_token_account(recipient_token_account)
+        .recipient(recipient)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .associated_token_program(ATA_PROGRAM_ID)
+        .data(data.clone())
+        .expiry(0)
+        .nonce(nonce)
+        .name("Test Asset".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .bump(attestation_bump)
+        .schema_mint_bump(schema_mint_bump)
+        .attestation_mint_bump(attestation_mint_bump)
+        .instruction();
+    let max_compute_units = 190_000
+        + canonical_bump_units(&[attestation_bump, schema_mint_bump, attestation_mint_bump]);
+    process_and_measure(
+        &mut ctx,
+        "CreateTokenizedAttestation",
+        &[ix],
+        &[&authority],
+        max_compute_units,
+    )
+    .await;
+
+    let ix = CloseTokenizedAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .attestation(attestation_pda)
+        .event_authority(event_auth_pda)
+        .system_program(system_program::ID)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .attestation_mint(attestation_mint_pda)
+        .sas_pda(sas_pda)
+        .attestation_token_account(recipient_token_account)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .attestation_mint_bump(attestation_mint_bump)
+        .instruction();
+    let max_compute_units = 60_000 + canonical_bump_units(&[attestation_mint_bump]);
+    process_and_measure(
+        &mut ctx,
+        "CloseTokenizedAttestation",
+        &[ix],
+        &[&authority],
+        max_compute_units,
+    )
+    .await;
+
+    // Tokenize an existing Attestation, then freeze its token once it expires.
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let nonce = Pubkey::new_unique();
+    let (attestation_pda, attestation_bump) = find_pda(&[
+        b"attestation",
+        &credential_pda.to_bytes(),
+        &schema_pda.to_bytes(),
+        &nonce.to_bytes(),
+    ]);
+    let ix = CreateAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(data.clone())
+        .expiry(clock.unix_timestamp + 60)
+        .nonce(nonce)
+        .bump(attestation_bump)
+        .instruction();
+    let max_compute_units = 15_000 + canonical_bump_units(&[attestation_bump]);
+    process_and_measure(
+        &mut ctx,
+        "CreateAttestation",
+        &[ix],
+        &[&authority],
+        max_compute_units,
+    )
+    .await;
+
+    let (attestation_mint_pda, attestation_mint_bump) =
+        find_pda(&[b"attestationMint", &attestation_pda.to_bytes()]);
+    let recipient_token_account = get_associated_token_address_with_program_id(
+        &recipient,
+        &attestation_mint_pda,
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    let ix = TokenizeAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .schema_mint(schema_mint_pda)
+        .attestation_mint(attestation_mint_pda)
+        .sas_pda(sas_pda)
+        .recipient_token_account(recipient_token_account)
+        .recipient(recipient)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .associated_token_program(ATA_PROGRAM_ID)
+        .name("Test Asset".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .schema_mint_bump(schema_mint_bump)
+        .attestation_mint_bump(attestation_mint_bump)
+        .instruction();
+    let max_compute_units =
+        190_000 + canonical_bump_units(&[schema_mint_bump, attestation_mint_bump]);
+    process_and_measure(
+        &mut ctx,
+        "TokenizeAttestation",
+        &[ix],
+        &[&authority],
+        max_compute_units,
+    )
+    .await;
+
+    clock.unix_timestamp += 120;
+    ctx.set_sysvar(&clock);
+    let ix = FreezeExpiredAttestationTokenBuilder::new()
+        .attestation(attestation_pda)
+        .schema(schema_pda)
+        .attestation_mint(attestation_mint_pda)
+        .sas_pda(sas_pda)
+        .attestation_token_account(recipient_token_account)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .attestation_mint_bump(attestation_mint_bump)
+        .instruction();
+    let max_compute_units = 30_000 + canonical_bump_units(&[attestation_mint_bump]);
+    process_and_measure(
+        &mut ctx,
+        "FreezeExpiredAttestationToken",
+        &[ix],
+        &[],
+        max_compute_units,
+    )
+    .await;
+
+    // Fees
+
+    let (treasury, treasury_bump) = find_pda(&[b"treasury", &credential_pda.to_bytes()]);
+    let ix = ChangeSchemaFeeBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .treasury(treasury)
+        .system_program(system_program::ID)
+        .fee(1_000_000)
+        .fee_mint(Pubkey::default())
+        .treasury_bump(treasury_bump)
+        .instruction();
+    let max_compute_units = 15_000 + canonical_bump_units(&[treasury_bump]);
+    process_and_measure(
+        &mut ctx,
+        "ChangeSchemaFee",
+        &[ix],
+        &[&authority],
+        max_compute_units,
+    )
+    .await;
+
+    let nonce = Pubkey::new_unique();
+    let (attestation_pda, attestation_bump) = find_pda(&[
+        b"attestation",
+        &credential_pda.to_bytes(),
+        &schema_pda.to_bytes(),
+        &nonce.to_bytes(),
+    ]);
+    let ix = CreateAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(data.clone())
+        .expiry(0)
+        .nonce(nonce)
+        .bump(attestation_bump)
+        .add_remaining_account(AccountMeta::new(treasury, false))
+        .instruction();
+    let max_compute_units = 20_000 + canonical_bump_units(&[attestation_bump]);
+    process_and_measure(
+        &mut ctx,
+        "CreateAttestation with fee",
+        &[ix],
+        &[&authority],
+        max_compute_units,
+    )
+    .await;
+
+    let ix = WithdrawTreasuryBuilder::new()
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .treasury(treasury)
+        .destination(ctx.payer.pubkey())
+        .system_program(system_program::ID)
+        .treasury_bump(treasury_bump)
+        .instruction();
+    let max_compute_units = 10_000 + canonical_bump_units(&[treasury_bump]);
+    process_and_measure(
+        &mut ctx,
+        "WithdrawTreasury",
+        &[ix],
+        &[&authority],
+        max_compute_units,
+    )
+    .await;
+}
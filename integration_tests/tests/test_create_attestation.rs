@@ -1,5 +1,5 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use helpers::program_test_context;
+use helpers::{non_canonical_program_address, program_test_context};
 use solana_attestation_service_client::{
     accounts::Attestation,
     instructions::{
@@ -10,7 +10,13 @@ use solana_attestation_service_client::{
 use solana_attestation_service_macros::SchemaStructSerialize;
 use solana_program_test::ProgramTestContext;
 use solana_sdk::{
-    clock::Clock, instruction::InstructionError, pubkey::Pubkey, signature::Keypair, signer::Signer, system_program, transaction::{Transaction, TransactionError}
+    clock::Clock,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
 };
 
 mod helpers;
@@ -33,7 +39,7 @@ async fn setup() -> TestFixtures {
 
     let authority = Keypair::new();
     let credential_name = "test";
-    let (credential_pda, _bump) = Pubkey::find_program_address(
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
         &[
             b"credential",
             &authority.pubkey().to_bytes(),
@@ -49,6 +55,7 @@ async fn setup() -> TestFixtures {
         .system_program(system_program::ID)
         .name(credential_name.to_string())
         .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
         .instruction();
 
     // Create Schema
@@ -56,7 +63,7 @@ async fn setup() -> TestFixtures {
     let description = "schema for test data";
     let schema_data = TestData::get_serialized_representation();
     let field_names = vec!["name".into(), "location".into()];
-    let (schema_pda, _bump) = Pubkey::find_program_address(
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
         &[
             b"schema",
             &credential_pda.to_bytes(),
@@ -75,6 +82,7 @@ async fn setup() -> TestFixtures {
         .name(schema_name.to_string())
         .layout(schema_data.clone())
         .field_names(field_names)
+        .bump(schema_bump)
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -116,7 +124,7 @@ async fn create_attestation_success() {
         .serialize(&mut serialized_attestation_data)
         .unwrap();
     let nonce = Pubkey::new_unique();
-    let attestation_pda = Pubkey::find_program_address(
+    let (attestation_pda, attestation_bump) = Pubkey::find_program_address(
         &[
             b"attestation",
             &credential.to_bytes(),
@@ -124,8 +132,7 @@ async fn create_attestation_success() {
             &nonce.to_bytes(),
         ],
         &solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID,
-    )
-    .0;
+    );
     let create_attestation_ix = CreateAttestationBuilder::new()
         .payer(ctx.payer.pubkey())
         .authority(authority.pubkey())
@@ -136,6 +143,7 @@ async fn create_attestation_success() {
         .data(serialized_attestation_data.clone())
         .expiry(expiry)
         .nonce(nonce)
+        .bump(attestation_bump)
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -164,6 +172,7 @@ async fn create_attestation_success() {
     assert_eq!(attestation.signer, authority.pubkey());
     assert_eq!(attestation.nonce, nonce);
     assert_eq!(attestation.token_account, Pubkey::default());
+    assert_eq!(attestation.bump, attestation_bump);
 }
 
 #[tokio::test]
@@ -206,6 +215,7 @@ async fn create_attestation_fail_bad_data() {
         .data(serialized_attestation_data.clone())
         .expiry(expiry)
         .nonce(nonce)
+        .bump(attestation_bump)
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -284,6 +294,7 @@ async fn create_attestation_fail_schema_paused() {
         .data(serialized_attestation_data.clone())
         .expiry(expiry)
         .nonce(nonce)
+        .bump(attestation_bump)
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -304,3 +315,59 @@ async fn create_attestation_fail_schema_paused() {
         TransactionError::InstructionError(0, InstructionError::Custom(11))
     )
 }
+
+#[tokio::test]
+async fn create_attestation_fail_non_canonical_bump() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        authority,
+    } = setup().await;
+
+    let mut serialized_attestation_data = Vec::new();
+    TestData {
+        name: "attest".to_string(),
+        location: 11,
+    }
+    .serialize(&mut serialized_attestation_data)
+    .unwrap();
+    // A valid program address, but not the canonical one for the seeds.
+    let nonce = Pubkey::new_unique();
+    let (attestation_pda, attestation_bump) = non_canonical_program_address(&[
+        b"attestation",
+        &credential.to_bytes(),
+        &schema.to_bytes(),
+        &nonce.to_bytes(),
+    ]);
+    let create_attestation_ix = CreateAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(serialized_attestation_data)
+        .expiry(0)
+        .nonce(nonce)
+        .bump(attestation_bump)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(2))
+    );
+}
@@ -0,0 +1,538 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::Attestation,
+    instructions::{
+        ChangeAuthorizedSignersBuilder, CloseAttestationBuilder,
+        CreateAttestationWithSignatureBuilder, CreateCredentialBuilder, CreateSchemaBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+    signature::{
+        attestation_message, ed25519_instruction, eip191_message, ethereum_signer,
+        find_used_message_pda, secp256k1_instruction, SignerKind,
+    },
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    keccak,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program, sysvar,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    authority: Keypair,
+    ethereum_key: libsecp256k1::SecretKey,
+}
+
+fn ethereum_address(key: &libsecp256k1::SecretKey) -> [u8; 20] {
+    let public_key = libsecp256k1::PublicKey::from_secret_key(key).serialize();
+    keccak::hash(&public_key[1..]).to_bytes()[12..]
+        .try_into()
+        .unwrap()
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let ethereum_key = libsecp256k1::SecretKey::parse(&[7; 32]).unwrap();
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![
+            authority.pubkey(),
+            ethereum_signer(&ethereum_address(&ethereum_key)),
+        ])
+        .bump(credential_bump)
+        .signer_kinds(vec![SignerKind::Ed25519 as u8, SignerKind::Secp256k1 as u8])
+        .instruction();
+
+    let schema_name = "test_data";
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("schema for test data".to_string())
+        .name(schema_name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into()])
+        .bump(schema_bump)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        authority,
+        ethereum_key,
+    }
+}
+
+struct SignedAttestation {
+    nonce: Pubkey,
+    data: Vec<u8>,
+    expiry: i64,
+    deadline: i64,
+}
+
+impl SignedAttestation {
+    fn new(deadline: i64) -> Self {
+        Self {
+            nonce: Pubkey::new_unique(),
+            data: borsh::to_vec(&TestData {
+                name: "attest".to_string(),
+                location: 11,
+            })
+            .unwrap(),
+            expiry: 0,
+            deadline,
+        }
+    }
+
+    fn attestation_pda(&self, fixtures: &TestFixtures) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                b"attestation",
+                &fixtures.credential.to_bytes(),
+                &fixtures.schema.to_bytes(),
+                &self.nonce.to_bytes(),
+            ],
+            &SOLANA_ATTESTATION_SERVICE_ID,
+        )
+    }
+
+    fn message(&self, fixtures: &TestFixtures) -> Vec<u8> {
+        attestation_message(
+            &fixtures.credential,
+            &fixtures.schema,
+            &self.nonce,
+            &self.data,
+            self.expiry,
+            self.deadline,
+        )
+    }
+
+    fn sign(&self, fixtures: &TestFixtures, signer: &Keypair) -> Instruction {
+        let message = self.message(fixtures);
+        let signature = signer.sign_message(&message);
+        ed25519_instruction(
+            &signer.pubkey(),
+            signature.as_ref().try_into().unwrap(),
+            &message,
+        )
+    }
+
+    fn sign_ethereum(&self, fixtures: &TestFixtures, key: &libsecp256k1::SecretKey) -> Instruction {
+        let message = self.message(fixtures);
+        let digest = keccak::hash(&eip191_message(&message)).to_bytes();
+        let (signature, recovery_id) =
+            libsecp256k1::sign(&libsecp256k1::Message::parse(&digest), key);
+        secp256k1_instruction(
+            &ethereum_address(key),
+            &signature.serialize(),
+            recovery_id.serialize(),
+            &message,
+            0,
+        )
+    }
+
+    fn instruction(&self, fixtures: &TestFixtures, authority: &Pubkey) -> Instruction {
+        let (attestation_pda, attestation_bump) = self.attestation_pda(fixtures);
+        let (used_message_pda, used_message_bump) = find_used_message_pda(&self.message(fixtures));
+        CreateAttestationWithSignatureBuilder::new()
+            .payer(fixtures.ctx.payer.pubkey())
+            .authority(*authority)
+            .credential(fixtures.credential)
+            .schema(fixtures.schema)
+            .attestation(attestation_pda)
+            .system_program(system_program::ID)
+            .instructions_sysvar(sysvar::instructions::ID)
+            .used_message(used_message_pda)
+            .deadline(self.deadline)
+            .nonce(self.nonce)
+            .data(self.data.clone())
+            .expiry(self.expiry)
+            .bump(attestation_bump)
+            .used_message_bump(used_message_bump)
+            .instruction()
+    }
+}
+
+async fn process(
+    fixtures: &TestFixtures,
+    instructions: &[Instruction],
+) -> Result<(), TransactionError> {
+    // Only the relayer signs the transaction.
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+#[tokio::test]
+async fn create_attestation_with_signature_success() {
+    let fixtures = setup().await;
+    let signed = SignedAttestation::new(i64::MAX);
+
+    process(
+        &fixtures,
+        &[
+            signed.sign(&fixtures, &fixtures.authority),
+            signed.instruction(&fixtures, &fixtures.authority.pubkey()),
+        ],
+    )
+    .await
+    .unwrap();
+
+    let (attestation_pda, attestation_bump) = signed.attestation_pda(&fixtures);
+    let attestation_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let attestation = Attestation::try_from_slice(&attestation_account.data).unwrap();
+    assert_eq!(attestation.nonce, signed.nonce);
+    assert_eq!(attestation.credential, fixtures.credential);
+    assert_eq!(attestation.schema, fixtures.schema);
+    assert_eq!(attestation.data, signed.data);
+    assert_eq!(attestation.signer, fixtures.authority.pubkey());
+    assert_eq!(attestation.bump, attestation_bump);
+    assert_eq!(attestation.signer_kind, SignerKind::Ed25519 as u8);
+
+    // The message is marked as used.
+    let (used_message_pda, _) = find_used_message_pda(&signed.message(&fixtures));
+    let used_message_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(used_message_pda)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    assert_eq!(used_message_account.owner, SOLANA_ATTESTATION_SERVICE_ID);
+}
+
+#[tokio::test]
+async fn create_attestation_with_ethereum_signature_success() {
+    let fixtures = setup().await;
+    let signed = SignedAttestation::new(i64::MAX);
+    let signer = ethereum_signer(&ethereum_address(&fixtures.ethereum_key));
+
+    process(
+        &fixtures,
+        &[
+            signed.sign_ethereum(&fixtures, &fixtures.ethereum_key),
+            signed.instruction(&fixtures, &signer),
+        ],
+    )
+    .await
+    .unwrap();
+
+    let (attestation_pda, _) = signed.attestation_pda(&fixtures);
+    let attestation_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let attestation = Attestation::try_from_slice(&attestation_account.data).unwrap();
+    assert_eq!(attestation.data, signed.data);
+    assert_eq!(attestation.signer, signer);
+    assert_eq!(attestation.signer_kind, SignerKind::Secp256k1 as u8);
+}
+
+#[tokio::test]
+async fn create_attestation_with_ethereum_signature_fail_wrong_signer() {
+    let fixtures = setup().await;
+    let signed = SignedAttestation::new(i64::MAX);
+    let other_key = libsecp256k1::SecretKey::parse(&[9; 32]).unwrap();
+
+    // Signature by another address than the authority.
+    let tx_err = process(
+        &fixtures,
+        &[
+            signed.sign_ethereum(&fixtures, &other_key),
+            signed.instruction(
+                &fixtures,
+                &ethereum_signer(&ethereum_address(&fixtures.ethereum_key)),
+            ),
+        ],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(1, InstructionError::Custom(25))
+    );
+
+    // Address that is not an authorized signer of the Credential.
+    let tx_err = process(
+        &fixtures,
+        &[
+            signed.sign_ethereum(&fixtures, &other_key),
+            signed.instruction(&fixtures, &ethereum_signer(&ethereum_address(&other_key))),
+        ],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(1, InstructionError::Custom(5))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_ethereum_signature_fail_ed25519_kind() {
+    let fixtures = setup().await;
+    let signed = SignedAttestation::new(i64::MAX);
+    let signer = ethereum_signer(&ethereum_address(&fixtures.ethereum_key));
+
+    // Register the padded address as an ed25519 key.
+    let ctx = &fixtures.ctx;
+    let ix = ChangeAuthorizedSignersBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .system_program(system_program::ID)
+        .signers(vec![fixtures.authority.pubkey(), signer])
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let tx_err = process(
+        &fixtures,
+        &[
+            signed.sign_ethereum(&fixtures, &fixtures.ethereum_key),
+            signed.instruction(&fixtures, &signer),
+        ],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(1, InstructionError::Custom(5))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_signature_fail_mismatched_message() {
+    let fixtures = setup().await;
+    let signed = SignedAttestation::new(i64::MAX);
+    let signature_ix = signed.sign(&fixtures, &fixtures.authority);
+
+    let tampered = SignedAttestation {
+        data: borsh::to_vec(&TestData {
+            name: "tampered".to_string(),
+            location: 11,
+        })
+        .unwrap(),
+        ..signed
+    };
+    let tx_err = process(
+        &fixtures,
+        &[
+            signature_ix,
+            tampered.instruction(&fixtures, &fixtures.authority.pubkey()),
+        ],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(1, InstructionError::Custom(25))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_signature_fail_missing_signature() {
+    let fixtures = setup().await;
+    let signed = SignedAttestation::new(i64::MAX);
+
+    let tx_err = process(
+        &fixtures,
+        &[signed.instruction(&fixtures, &fixtures.authority.pubkey())],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(25))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_signature_fail_unauthorized_signer() {
+    let fixtures = setup().await;
+    let signed = SignedAttestation::new(i64::MAX);
+    let other_authority = Keypair::new();
+
+    let tx_err = process(
+        &fixtures,
+        &[
+            signed.sign(&fixtures, &other_authority),
+            signed.instruction(&fixtures, &other_authority.pubkey()),
+        ],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(1, InstructionError::Custom(5))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_signature_fail_deadline_passed() {
+    let fixtures = setup().await;
+    let signed = SignedAttestation::new(1);
+
+    let tx_err = process(
+        &fixtures,
+        &[
+            signed.sign(&fixtures, &fixtures.authority),
+            signed.instruction(&fixtures, &fixtures.authority.pubkey()),
+        ],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(1, InstructionError::Custom(26))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_signature_fail_replay_after_close() {
+    let mut fixtures = setup().await;
+    let signed = SignedAttestation::new(i64::MAX);
+    let instructions = [
+        signed.sign(&fixtures, &fixtures.authority),
+        signed.instruction(&fixtures, &fixtures.authority.pubkey()),
+    ];
+    process(&fixtures, &instructions).await.unwrap();
+
+    // The issuer closes the Attestation.
+    let (attestation_pda, _) = signed.attestation_pda(&fixtures);
+    let (event_authority, _) =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID);
+    let close_ix = CloseAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .attestation(attestation_pda)
+        .event_authority(event_authority)
+        .system_program(system_program::ID)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .instruction();
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+    assert!(ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Resubmitting the signed message does not recreate the Attestation.
+    fixtures.ctx.last_blockhash = fixtures.ctx.get_new_latest_blockhash().await.unwrap();
+    let tx_err = process(&fixtures, &instructions).await.unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(1, InstructionError::Custom(40))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_signature_fail_incorrect_used_message() {
+    let fixtures = setup().await;
+    let signed = SignedAttestation::new(i64::MAX);
+    let mut create_ix = signed.instruction(&fixtures, &fixtures.authority.pubkey());
+    create_ix.accounts[7].pubkey = Pubkey::new_unique();
+
+    let tx_err = process(
+        &fixtures,
+        &[signed.sign(&fixtures, &fixtures.authority), create_ix],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(1, InstructionError::Custom(39))
+    );
+}
@@ -1,10 +1,17 @@
 use borsh::BorshDeserialize;
-use helpers::program_test_context;
+use helpers::{non_canonical_program_address, program_test_context};
 use solana_attestation_service_client::{
-    accounts::Credential, instructions::CreateCredentialBuilder,
+    accounts::Credential,
+    instructions::CreateCredentialBuilder,
+    signature::{ethereum_signer, SignerKind},
 };
 use solana_sdk::{
-    pubkey::Pubkey, signature::Keypair, signer::Signer, system_program, transaction::Transaction,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
 };
 
 mod helpers;
@@ -16,7 +23,7 @@ async fn create_credential_success() {
     let authority = Keypair::new();
     let name = "test";
 
-    let (credential_pda, _bump) = Pubkey::find_program_address(
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
         &[
             b"credential",
             &authority.pubkey().to_bytes(),
@@ -32,6 +39,7 @@ async fn create_credential_success() {
         .system_program(system_program::ID)
         .name(name.to_string())
         .signers(vec![authority.pubkey(), ctx.payer.pubkey()])
+        .bump(credential_bump)
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -58,4 +66,202 @@ async fn create_credential_success() {
     assert_eq!(credential.name, name.as_bytes());
     assert_eq!(credential.authorized_signers[0], authority.pubkey());
     assert_eq!(credential.authorized_signers[1], ctx.payer.pubkey());
+    // Signers without explicit kinds are ed25519 keys.
+    assert_eq!(credential.authorized_signer_kinds, vec![0, 0]);
+    assert_eq!(credential.bump, credential_bump);
+}
+
+#[tokio::test]
+async fn create_credential_fail_wrong_bump() {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let name = "test";
+
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            name.as_bytes(),
+        ],
+        &Pubkey::from(solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID),
+    );
+
+    let ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump.wrapping_sub(1))
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(0))
+    );
+}
+
+#[tokio::test]
+async fn create_credential_fail_non_canonical_bump() {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let name = "test";
+
+    // A valid program address, but not the canonical one for the seeds.
+    let (credential_pda, credential_bump) = non_canonical_program_address(&[
+        b"credential",
+        &authority.pubkey().to_bytes(),
+        name.as_bytes(),
+    ]);
+
+    let ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(0))
+    );
+}
+
+#[tokio::test]
+async fn create_credential_fail_empty_name() {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let name = "";
+
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            name.as_bytes(),
+        ],
+        &Pubkey::from(solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID),
+    );
+
+    let ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(15))
+    );
+}
+
+#[tokio::test]
+async fn create_credential_fail_invalid_signer_kinds() {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let name = "test";
+
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            name.as_bytes(),
+        ],
+        &Pubkey::from(solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID),
+    );
+
+    let ethereum = ethereum_signer(&[7; 20]);
+    let cases = [
+        // Fewer kinds than signers.
+        (
+            vec![authority.pubkey(), ethereum],
+            vec![SignerKind::Secp256k1 as u8],
+        ),
+        // A secp256k1 signer that is not a zero-padded Ethereum address.
+        (vec![authority.pubkey()], vec![SignerKind::Secp256k1 as u8]),
+        // Passkeys are set with ChangePasskeySigners.
+        (vec![authority.pubkey()], vec![SignerKind::Secp256r1 as u8]),
+        // Unknown kind.
+        (vec![authority.pubkey()], vec![3]),
+    ];
+    for (signers, signer_kinds) in cases {
+        let ix = CreateCredentialBuilder::new()
+            .payer(ctx.payer.pubkey())
+            .credential(credential_pda)
+            .authority(authority.pubkey())
+            .system_program(system_program::ID)
+            .name(name.to_string())
+            .signers(signers)
+            .bump(credential_bump)
+            .signer_kinds(signer_kinds)
+            .instruction();
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &authority],
+            ctx.last_blockhash,
+        );
+        let tx_err = ctx
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .err()
+            .expect("should error")
+            .unwrap();
+        assert_eq!(
+            tx_err,
+            TransactionError::InstructionError(0, InstructionError::Custom(41))
+        );
+    }
 }
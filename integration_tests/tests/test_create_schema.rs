@@ -1,12 +1,18 @@
 use borsh::BorshDeserialize;
-use helpers::program_test_context;
+use helpers::{non_canonical_program_address, program_test_context};
 use solana_attestation_service_client::{
     accounts::Schema,
     instructions::{CreateCredentialBuilder, CreateSchemaBuilder},
 };
 use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
 use solana_sdk::{
-    pubkey::Pubkey, signature::Keypair, signer::Signer, system_program, transaction::Transaction,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
 };
 
 mod helpers;
@@ -23,7 +29,7 @@ async fn create_schema_success() {
 
     let authority = Keypair::new();
     let credential_name = "test";
-    let (credential_pda, _bump) = Pubkey::find_program_address(
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
         &[
             b"credential",
             &authority.pubkey().to_bytes(),
@@ -39,6 +45,7 @@ async fn create_schema_success() {
         .system_program(system_program::ID)
         .name(credential_name.to_string())
         .signers(vec![authority.pubkey(), ctx.payer.pubkey()])
+        .bump(credential_bump)
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -57,7 +64,7 @@ async fn create_schema_success() {
     let description = "schema for test data";
     let schema_layout = TestData::get_serialized_representation();
     let field_names = vec!["name".into(), "location".into()];
-    let (schema_pda, _bump) = Pubkey::find_program_address(
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
         &[
             b"schema",
             &credential_pda.to_bytes(),
@@ -76,6 +83,7 @@ async fn create_schema_success() {
         .name(schema_name.to_string())
         .layout(schema_layout.clone())
         .field_names(field_names.clone())
+        .bump(schema_bump)
         .instruction();
     let transaction = Transaction::new_signed_with_payer(
         &[create_schema_ix],
@@ -109,5 +117,191 @@ async fn create_schema_success() {
     assert_eq!(schema.description, description.as_bytes());
     assert_eq!(schema.is_paused, false);
     assert_eq!(schema.version, 1);
+    assert_eq!(schema.bump, schema_bump);
     assert_eq!(schema.name, schema_name.as_bytes());
 }
+
+async fn create_credential(ctx: &ProgramTestContext, authority: &Keypair) -> Pubkey {
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &Pubkey::from(solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID),
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    credential_pda
+}
+
+async fn create_schema_error(
+    ctx: &ProgramTestContext,
+    authority: &Keypair,
+    credential: Pubkey,
+    schema: Pubkey,
+    schema_name: &str,
+    field_names: Vec<String>,
+) -> TransactionError {
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .system_program(system_program::ID)
+        .description("schema for test data".to_string())
+        .name(schema_name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(field_names)
+        // Schema validation fails before the PDA is checked.
+        .bump(0)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap()
+}
+
+#[tokio::test]
+async fn create_schema_fail_duplicate_field_names() {
+    let ctx = program_test_context().await;
+    let authority = Keypair::new();
+    let credential_pda = create_credential(&ctx, &authority).await;
+
+    let schema_name = "test_data";
+    let (schema_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &Pubkey::from(solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID),
+    );
+
+    let tx_err = create_schema_error(
+        &ctx,
+        &authority,
+        credential_pda,
+        schema_pda,
+        schema_name,
+        vec!["name".into(), "name".into()],
+    )
+    .await;
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(16))
+    );
+
+    // Empty field name
+    let tx_err = create_schema_error(
+        &ctx,
+        &authority,
+        credential_pda,
+        schema_pda,
+        schema_name,
+        vec!["name".into(), "".into()],
+    )
+    .await;
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(15))
+    );
+}
+
+#[tokio::test]
+async fn create_schema_fail_name_too_long() {
+    let ctx = program_test_context().await;
+    let authority = Keypair::new();
+    let credential_pda = create_credential(&ctx, &authority).await;
+
+    // Names longer than a PDA seed are rejected before the PDA is derived.
+    let tx_err = create_schema_error(
+        &ctx,
+        &authority,
+        credential_pda,
+        Pubkey::new_unique(),
+        &"a".repeat(33),
+        vec!["name".into(), "location".into()],
+    )
+    .await;
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(17))
+    );
+}
+
+#[tokio::test]
+async fn create_schema_fail_non_canonical_bump() {
+    let ctx = program_test_context().await;
+    let authority = Keypair::new();
+    let credential_pda = create_credential(&ctx, &authority).await;
+
+    // A valid program address, but not the canonical one for the seeds.
+    let schema_name = "test_data";
+    let (schema_pda, schema_bump) = non_canonical_program_address(&[
+        b"schema",
+        &credential_pda.to_bytes(),
+        schema_name.as_bytes(),
+        &[1],
+    ]);
+
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("schema for test data".to_string())
+        .name(schema_name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into()])
+        .bump(schema_bump)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+}
@@ -0,0 +1,511 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::{non_canonical_program_address, program_test_context};
+use solana_attestation_service_client::{
+    accounts::Schema,
+    instructions::{
+        ChangeSchemaFeeBuilder, CreateAttestationBuilder, CreateCredentialBuilder,
+        CreateSchemaBuilder, WithdrawTreasuryBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::Keypair,
+    signer::Signer,
+    system_instruction, system_program,
+    transaction::{Transaction, TransactionError},
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id, instruction::create_associated_token_account,
+};
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    instruction::{initialize_mint2, mint_to},
+    state::{Account, Mint},
+    ID as TOKEN_2022_PROGRAM_ID,
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+const FEE: u64 = 1_000_000;
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    authority: Keypair,
+    treasury: Pubkey,
+    treasury_bump: u8,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
+        .instruction();
+
+    let schema_name = "test_data";
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("schema for test data".to_string())
+        .name(schema_name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into()])
+        .bump(schema_bump)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let (treasury, treasury_bump) = Pubkey::find_program_address(
+        &[b"treasury", &credential_pda.to_bytes()],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        authority,
+        treasury,
+        treasury_bump,
+    }
+}
+
+async fn process(
+    fixtures: &TestFixtures,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&[&ctx.payer][..], signers].concat(),
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+fn change_schema_fee_ix(
+    fixtures: &TestFixtures,
+    authority: &Pubkey,
+    treasury: &Pubkey,
+    fee_mint: Pubkey,
+) -> Instruction {
+    ChangeSchemaFeeBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(*authority)
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .treasury(*treasury)
+        .system_program(system_program::ID)
+        .fee(FEE)
+        .fee_mint(fee_mint)
+        .treasury_bump(fixtures.treasury_bump)
+        .instruction()
+}
+
+/// CreateAttestation instruction with `fee_accounts` after the other accounts.
+fn create_attestation_ix(fixtures: &TestFixtures, fee_accounts: &[AccountMeta]) -> Instruction {
+    let nonce = Pubkey::new_unique();
+    let (attestation_pda, attestation_bump) = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &fixtures.credential.to_bytes(),
+            &fixtures.schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    CreateAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .nonce(nonce)
+        .data(
+            borsh::to_vec(&TestData {
+                name: "attest".to_string(),
+                location: 11,
+            })
+            .unwrap(),
+        )
+        .expiry(0)
+        .bump(attestation_bump)
+        .add_remaining_accounts(fee_accounts)
+        .instruction()
+}
+
+fn withdraw_treasury_ix(
+    fixtures: &TestFixtures,
+    destination: &Pubkey,
+    token_accounts: &[AccountMeta],
+) -> Instruction {
+    WithdrawTreasuryBuilder::new()
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .treasury(fixtures.treasury)
+        .destination(*destination)
+        .system_program(system_program::ID)
+        .treasury_bump(fixtures.treasury_bump)
+        .add_remaining_accounts(token_accounts)
+        .instruction()
+}
+
+async fn get_lamports(fixtures: &TestFixtures, address: &Pubkey) -> u64 {
+    fixtures
+        .ctx
+        .banks_client
+        .get_account(*address)
+        .await
+        .expect("get_account")
+        .map(|account| account.lamports)
+        .unwrap_or_default()
+}
+
+async fn get_token_amount(fixtures: &TestFixtures, address: &Pubkey) -> u64 {
+    let account = fixtures
+        .ctx
+        .banks_client
+        .get_account(*address)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    StateWithExtensions::<Account>::unpack(&account.data)
+        .unwrap()
+        .base
+        .amount
+}
+
+#[tokio::test]
+async fn lamport_fee_success() {
+    let fixtures = setup().await;
+
+    process(
+        &fixtures,
+        &[change_schema_fee_ix(
+            &fixtures,
+            &fixtures.authority.pubkey(),
+            &fixtures.treasury,
+            Pubkey::default(),
+        )],
+        &[&fixtures.authority],
+    )
+    .await
+    .unwrap();
+
+    let schema_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(fixtures.schema)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let schema = Schema::try_from_slice(&schema_account.data).unwrap();
+    assert_eq!(schema.fee, FEE);
+    assert_eq!(schema.fee_mint, Pubkey::default());
+    assert_eq!(schema.fee_treasury, fixtures.treasury);
+
+    // The treasury is funded up to rent exemption.
+    let rent_exempt = Rent::default().minimum_balance(0);
+    assert_eq!(
+        get_lamports(&fixtures, &fixtures.treasury).await,
+        rent_exempt
+    );
+
+    process(
+        &fixtures,
+        &[create_attestation_ix(
+            &fixtures,
+            &[AccountMeta::new(fixtures.treasury, false)],
+        )],
+        &[&fixtures.authority],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        get_lamports(&fixtures, &fixtures.treasury).await,
+        rent_exempt + FEE
+    );
+
+    let destination = Pubkey::new_unique();
+    process(
+        &fixtures,
+        &[withdraw_treasury_ix(&fixtures, &destination, &[])],
+        &[&fixtures.authority],
+    )
+    .await
+    .unwrap();
+    assert_eq!(get_lamports(&fixtures, &destination).await, FEE);
+    assert_eq!(
+        get_lamports(&fixtures, &fixtures.treasury).await,
+        rent_exempt
+    );
+}
+
+#[tokio::test]
+async fn lamport_fee_fail_missing_or_wrong_treasury() {
+    let fixtures = setup().await;
+    process(
+        &fixtures,
+        &[change_schema_fee_ix(
+            &fixtures,
+            &fixtures.authority.pubkey(),
+            &fixtures.treasury,
+            Pubkey::default(),
+        )],
+        &[&fixtures.authority],
+    )
+    .await
+    .unwrap();
+
+    let tx_err = process(
+        &fixtures,
+        &[create_attestation_ix(&fixtures, &[])],
+        &[&fixtures.authority],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+
+    let tx_err = process(
+        &fixtures,
+        &[create_attestation_ix(
+            &fixtures,
+            &[AccountMeta::new(Pubkey::new_unique(), false)],
+        )],
+        &[&fixtures.authority],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(33))
+    );
+}
+
+#[tokio::test]
+async fn change_schema_fee_fail() {
+    let fixtures = setup().await;
+
+    // Only the Credential authority sets fees.
+    let other_authority = Keypair::new();
+    let tx_err = process(
+        &fixtures,
+        &[change_schema_fee_ix(
+            &fixtures,
+            &other_authority.pubkey(),
+            &fixtures.treasury,
+            Pubkey::default(),
+        )],
+        &[&other_authority],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+
+    // Lamport fees are paid to the Credential's treasury PDA.
+    let tx_err = process(
+        &fixtures,
+        &[change_schema_fee_ix(
+            &fixtures,
+            &fixtures.authority.pubkey(),
+            &Pubkey::new_unique(),
+            Pubkey::default(),
+        )],
+        &[&fixtures.authority],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(33))
+    );
+
+    // The treasury PDA must use the canonical bump, otherwise WithdrawTreasury
+    // could never sign for the fees paid to it.
+    let (treasury, treasury_bump) =
+        non_canonical_program_address(&[b"treasury", &fixtures.credential.to_bytes()]);
+    let ix = ChangeSchemaFeeBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .treasury(treasury)
+        .system_program(system_program::ID)
+        .fee(FEE)
+        .fee_mint(Pubkey::default())
+        .treasury_bump(treasury_bump)
+        .instruction();
+    let tx_err = process(&fixtures, &[ix], &[&fixtures.authority])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(33))
+    );
+}
+
+#[tokio::test]
+async fn token_fee_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let payer = ctx.payer.pubkey();
+
+    // Mint tokens to the payer, and create the treasury's token account.
+    let mint = Keypair::new();
+    let payer_token_account = get_associated_token_address_with_program_id(
+        &payer,
+        &mint.pubkey(),
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    let treasury_token_account = get_associated_token_address_with_program_id(
+        &fixtures.treasury,
+        &mint.pubkey(),
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    process(
+        &fixtures,
+        &[
+            system_instruction::create_account(
+                &payer,
+                &mint.pubkey(),
+                Rent::default().minimum_balance(Mint::LEN),
+                Mint::LEN as u64,
+                &TOKEN_2022_PROGRAM_ID,
+            ),
+            initialize_mint2(&TOKEN_2022_PROGRAM_ID, &mint.pubkey(), &payer, None, 6).unwrap(),
+            create_associated_token_account(&payer, &payer, &mint.pubkey(), &TOKEN_2022_PROGRAM_ID),
+            create_associated_token_account(
+                &payer,
+                &fixtures.treasury,
+                &mint.pubkey(),
+                &TOKEN_2022_PROGRAM_ID,
+            ),
+            mint_to(
+                &TOKEN_2022_PROGRAM_ID,
+                &mint.pubkey(),
+                &payer_token_account,
+                &payer,
+                &[],
+                FEE * 2,
+            )
+            .unwrap(),
+            change_schema_fee_ix(
+                &fixtures,
+                &fixtures.authority.pubkey(),
+                &treasury_token_account,
+                mint.pubkey(),
+            ),
+        ],
+        &[&mint, &fixtures.authority],
+    )
+    .await
+    .unwrap();
+
+    let fee_accounts = [
+        AccountMeta::new(payer_token_account, false),
+        AccountMeta::new(treasury_token_account, false),
+        AccountMeta::new_readonly(mint.pubkey(), false),
+        AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+    ];
+    process(
+        &fixtures,
+        &[create_attestation_ix(&fixtures, &fee_accounts)],
+        &[&fixtures.authority],
+    )
+    .await
+    .unwrap();
+    assert_eq!(get_token_amount(&fixtures, &payer_token_account).await, FEE);
+    assert_eq!(
+        get_token_amount(&fixtures, &treasury_token_account).await,
+        FEE
+    );
+
+    // The treasury token account is drained to the destination token account.
+    process(
+        &fixtures,
+        &[withdraw_treasury_ix(
+            &fixtures,
+            &payer,
+            &[
+                AccountMeta::new(treasury_token_account, false),
+                AccountMeta::new(payer_token_account, false),
+                AccountMeta::new_readonly(mint.pubkey(), false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+            ],
+        )],
+        &[&fixtures.authority],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        get_token_amount(&fixtures, &payer_token_account).await,
+        FEE * 2
+    );
+    assert_eq!(
+        get_token_amount(&fixtures, &treasury_token_account).await,
+        0
+    );
+}
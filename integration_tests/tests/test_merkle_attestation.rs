@@ -0,0 +1,425 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::MerkleAttestationBatch,
+    instructions::{
+        ChangeSchemaFeeBuilder, CreateCredentialBuilder, CreateMerkleAttestationBatchBuilder,
+        CreateSchemaBuilder, RevokeMerkleAttestationBatchBuilder, VerifyMerkleAttestationBuilder,
+    },
+    merkle::{verify_merkle_proof, MerkleAttestationRecord, MerkleTree},
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    authority: Keypair,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
+        .instruction();
+
+    let schema_name = "test_data";
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("schema for test data".to_string())
+        .name(schema_name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into()])
+        .bump(schema_bump)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        authority,
+    }
+}
+
+fn records(expiry: i64) -> Vec<MerkleAttestationRecord> {
+    (0..5)
+        .map(|i| MerkleAttestationRecord {
+            subject: Pubkey::new_unique(),
+            data: borsh::to_vec(&TestData {
+                name: format!("attest {}", i),
+                location: i,
+            })
+            .unwrap(),
+            expiry,
+        })
+        .collect()
+}
+
+async fn try_create_batch(
+    fixtures: &TestFixtures,
+    schema: &Pubkey,
+    tree: &MerkleTree,
+) -> Result<Pubkey, TransactionError> {
+    let ctx = &fixtures.ctx;
+    let nonce = Pubkey::new_unique();
+    let (merkle_batch_pda, merkle_batch_bump) = Pubkey::find_program_address(
+        &[
+            b"merkleBatch",
+            &fixtures.credential.to_bytes(),
+            &schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let ix = CreateMerkleAttestationBatchBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(*schema)
+        .merkle_batch(merkle_batch_pda)
+        .system_program(system_program::ID)
+        .nonce(nonce)
+        .root(tree.root().unwrap())
+        .bump(merkle_batch_bump)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())?;
+    Ok(merkle_batch_pda)
+}
+
+async fn create_batch(fixtures: &TestFixtures, tree: &MerkleTree) -> Pubkey {
+    try_create_batch(fixtures, &fixtures.schema, tree)
+        .await
+        .unwrap()
+}
+
+/// Create a Schema of the test Credential that requires an Attestation of
+/// `ref_schema` and invokes `resolver_program`.
+async fn create_schema(
+    fixtures: &TestFixtures,
+    name: &str,
+    ref_schema: Pubkey,
+    resolver_program: Pubkey,
+) -> Pubkey {
+    let ctx = &fixtures.ctx;
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &fixtures.credential.to_bytes(),
+            name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("schema for test data".to_string())
+        .name(name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into()])
+        .bump(schema_bump)
+        .ref_schema(ref_schema)
+        .resolver_program(resolver_program)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+    schema_pda
+}
+
+fn verify_ix(
+    merkle_batch: Pubkey,
+    record: &MerkleAttestationRecord,
+    proof: Vec<[u8; 32]>,
+) -> Instruction {
+    VerifyMerkleAttestationBuilder::new()
+        .merkle_batch(merkle_batch)
+        .subject(record.subject)
+        .data(record.data.clone())
+        .expiry(record.expiry)
+        .proof(proof)
+        .instruction()
+}
+
+async fn process(fixtures: &TestFixtures, ix: Instruction) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+#[tokio::test]
+async fn verify_merkle_attestation_success() {
+    let fixtures = setup().await;
+    let records = records(0);
+    let tree = MerkleTree::new(&records);
+    let merkle_batch = create_batch(&fixtures, &tree).await;
+
+    let merkle_batch_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(merkle_batch)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let batch = MerkleAttestationBatch::try_from_slice(&merkle_batch_account.data).unwrap();
+    assert_eq!(batch.credential, fixtures.credential);
+    assert_eq!(batch.schema, fixtures.schema);
+    assert_eq!(batch.root, tree.root().unwrap());
+    assert_eq!(batch.signer, fixtures.authority.pubkey());
+
+    // Every record, including the odd one out at the end, has a valid proof.
+    for (index, record) in records.iter().enumerate() {
+        let proof = tree.proof(index).unwrap();
+        assert!(verify_merkle_proof(&batch.root, record, &proof));
+        process(&fixtures, verify_ix(merkle_batch, record, proof))
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn create_merkle_attestation_batch_fail_schema_rules() {
+    let fixtures = setup().await;
+    let tree = MerkleTree::new(&records(0));
+
+    // Schemas requiring a referenced Attestation or invoking a resolver.
+    let ref_schema = create_schema(&fixtures, "ref_data", fixtures.schema, Pubkey::default()).await;
+    let resolver_schema = create_schema(
+        &fixtures,
+        "resolver_data",
+        Pubkey::default(),
+        Pubkey::new_unique(),
+    )
+    .await;
+    for schema in [ref_schema, resolver_schema] {
+        assert_eq!(
+            try_create_batch(&fixtures, &schema, &tree)
+                .await
+                .unwrap_err(),
+            TransactionError::InstructionError(0, InstructionError::Custom(1))
+        );
+    }
+
+    // Schema charging an issuance fee.
+    let ctx = &fixtures.ctx;
+    let (treasury, treasury_bump) = Pubkey::find_program_address(
+        &[b"treasury", &fixtures.credential.to_bytes()],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let ix = ChangeSchemaFeeBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .treasury(treasury)
+        .system_program(system_program::ID)
+        .fee(1_000_000)
+        .fee_mint(Pubkey::default())
+        .treasury_bump(treasury_bump)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+    assert_eq!(
+        try_create_batch(&fixtures, &fixtures.schema, &tree)
+            .await
+            .unwrap_err(),
+        TransactionError::InstructionError(0, InstructionError::Custom(1))
+    );
+}
+
+#[tokio::test]
+async fn verify_merkle_attestation_fail_invalid_proof() {
+    let fixtures = setup().await;
+    let records = records(0);
+    let tree = MerkleTree::new(&records);
+    let merkle_batch = create_batch(&fixtures, &tree).await;
+
+    // Proof for another record.
+    let ix = verify_ix(merkle_batch, &records[0], tree.proof(1).unwrap());
+    assert_eq!(
+        process(&fixtures, ix).await.unwrap_err(),
+        TransactionError::InstructionError(0, InstructionError::Custom(23))
+    );
+
+    // Record not in the batch.
+    let mut record = records[0].clone();
+    record.subject = Pubkey::new_unique();
+    let ix = verify_ix(merkle_batch, &record, tree.proof(0).unwrap());
+    assert_eq!(
+        process(&fixtures, ix).await.unwrap_err(),
+        TransactionError::InstructionError(0, InstructionError::Custom(23))
+    );
+}
+
+#[tokio::test]
+async fn verify_merkle_attestation_fail_expired() {
+    let fixtures = setup().await;
+    let records = records(1);
+    let tree = MerkleTree::new(&records);
+    let merkle_batch = create_batch(&fixtures, &tree).await;
+
+    let ix = verify_ix(merkle_batch, &records[0], tree.proof(0).unwrap());
+    assert_eq!(
+        process(&fixtures, ix).await.unwrap_err(),
+        TransactionError::InstructionError(0, InstructionError::Custom(24))
+    );
+}
+
+#[tokio::test]
+async fn revoke_merkle_attestation_batch_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let records = records(0);
+    let tree = MerkleTree::new(&records);
+    let merkle_batch = create_batch(&fixtures, &tree).await;
+
+    // Signer that is not authorized cannot revoke the batch.
+    let other_authority = Keypair::new();
+    let ix = RevokeMerkleAttestationBatchBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(other_authority.pubkey())
+        .credential(fixtures.credential)
+        .merkle_batch(merkle_batch)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &other_authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(5))
+    );
+
+    let ix = RevokeMerkleAttestationBatchBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .merkle_batch(merkle_batch)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+    assert!(ctx
+        .banks_client
+        .get_account(merkle_batch)
+        .await
+        .unwrap()
+        .is_none());
+
+    // Proofs against a revoked batch no longer verify.
+    let ix = verify_ix(merkle_batch, &records[0], tree.proof(0).unwrap());
+    assert_eq!(
+        process(&fixtures, ix).await.unwrap_err(),
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountOwner)
+    );
+}
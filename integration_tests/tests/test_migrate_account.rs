@@ -0,0 +1,447 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::{Attestation, Credential, Schema},
+    instructions::{
+        ChangeAuthorizedSignersBuilder, CreateAttestationBuilder, MigrateAccountBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+    signature::{ethereum_signer, SignerKind},
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+fn serialized_vec(data: &[u8]) -> Vec<u8> {
+    let mut out = (data.len() as u32).to_le_bytes().to_vec();
+    out.extend_from_slice(data);
+    out
+}
+
+/// Credential data as written before accounts carried a layout version.
+fn legacy_credential_data(authority: &Pubkey, name: &str, signers: &[Pubkey]) -> Vec<u8> {
+    let mut data = vec![0];
+    data.extend_from_slice(authority.as_ref());
+    data.extend(serialized_vec(name.as_bytes()));
+    data.extend((signers.len() as u32).to_le_bytes());
+    for signer in signers {
+        data.extend_from_slice(signer.as_ref());
+    }
+    data
+}
+
+/// Schema data as written before accounts carried a layout version.
+fn legacy_schema_data(credential: &Pubkey, name: &str, field_names: &[&str]) -> Vec<u8> {
+    let mut data = vec![1];
+    data.extend_from_slice(credential.as_ref());
+    data.extend(serialized_vec(name.as_bytes()));
+    data.extend(serialized_vec(b"schema for test data"));
+    data.extend(serialized_vec(&TestData::get_serialized_representation()));
+    let field_names: Vec<u8> = field_names
+        .iter()
+        .flat_map(|name| serialized_vec(name.as_bytes()))
+        .collect();
+    data.extend(serialized_vec(&field_names));
+    data.push(0); // is_paused
+    data.push(1); // version
+    data
+}
+
+/// Attestation data as written before accounts carried a layout version.
+fn legacy_attestation_data(
+    nonce: &Pubkey,
+    credential: &Pubkey,
+    schema: &Pubkey,
+    attestation_data: &[u8],
+    signer: &Pubkey,
+) -> Vec<u8> {
+    let mut data = vec![2];
+    data.extend_from_slice(nonce.as_ref());
+    data.extend_from_slice(credential.as_ref());
+    data.extend_from_slice(schema.as_ref());
+    data.extend(serialized_vec(attestation_data));
+    data.extend_from_slice(signer.as_ref());
+    data.extend(0i64.to_le_bytes());
+    data.extend_from_slice(Pubkey::default().as_ref());
+    data
+}
+
+async fn set_program_account(ctx: &mut ProgramTestContext, address: &Pubkey, data: Vec<u8>) {
+    let rent = ctx.banks_client.get_rent().await.unwrap();
+    let account = Account {
+        lamports: rent.minimum_balance(data.len()),
+        data,
+        owner: SOLANA_ATTESTATION_SERVICE_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    ctx.set_account(address, &AccountSharedData::from(account));
+}
+
+async fn process(
+    ctx: &ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), TransactionError> {
+    let last_blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        signers,
+        last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+fn migrate_account_ix(ctx: &ProgramTestContext, account: Pubkey, bump: u8) -> Instruction {
+    MigrateAccountBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .account(account)
+        .system_program(system_program::ID)
+        .bump(bump)
+        .instruction()
+}
+
+#[tokio::test]
+async fn migrate_legacy_credential_success() {
+    let mut ctx = program_test_context().await;
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    set_program_account(
+        &mut ctx,
+        &credential_pda,
+        legacy_credential_data(&authority.pubkey(), credential_name, &[authority.pubkey()]),
+    )
+    .await;
+
+    // Legacy accounts must be migrated before they are modified.
+    let change_signers_ix = |signers: Vec<Pubkey>| {
+        ChangeAuthorizedSignersBuilder::new()
+            .payer(ctx.payer.pubkey())
+            .authority(authority.pubkey())
+            .credential(credential_pda)
+            .system_program(system_program::ID)
+            .signers(signers)
+            .instruction()
+    };
+    let ix = change_signers_ix(vec![authority.pubkey()]);
+    let tx_err = process(&ctx, ix, &[&ctx.payer, &authority])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(20))
+    );
+
+    let ix = migrate_account_ix(&ctx, credential_pda, credential_bump);
+    process(&ctx, ix, &[&ctx.payer]).await.unwrap();
+
+    let credential_account = ctx
+        .banks_client
+        .get_account(credential_pda)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let credential = Credential::try_from_slice(&credential_account.data).unwrap();
+    assert_eq!(credential.discriminator, 3);
+    assert_eq!(credential.layout_version, 5);
+    assert_eq!(credential.authority, authority.pubkey());
+    assert_eq!(credential.name, credential_name.as_bytes());
+    assert_eq!(credential.authorized_signers, vec![authority.pubkey()]);
+    assert_eq!(credential.authorized_signer_kinds, vec![0]);
+    assert_eq!(credential.bump, credential_bump);
+    assert!(credential.display_name.is_empty());
+    assert!(!credential.is_paused);
+    assert!(credential.website.is_empty());
+    assert!(credential.passkey_signers.is_empty());
+
+    // Migrating an account with the current layout is a no-op and does not
+    // check the bump.
+    let ix = migrate_account_ix(&ctx, credential_pda, credential_bump.wrapping_sub(1));
+    process(&ctx, ix, &[&ctx.payer]).await.unwrap();
+
+    let new_signers = vec![authority.pubkey(), ctx.payer.pubkey()];
+    let ix = change_signers_ix(new_signers.clone());
+    process(&ctx, ix, &[&ctx.payer, &authority]).await.unwrap();
+    let credential_account = ctx
+        .banks_client
+        .get_account(credential_pda)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let credential = Credential::try_from_slice(&credential_account.data).unwrap();
+    assert_eq!(credential.authorized_signers, new_signers);
+}
+
+#[tokio::test]
+async fn migrate_legacy_schema_and_attestation_success() {
+    let mut ctx = program_test_context().await;
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    set_program_account(
+        &mut ctx,
+        &credential_pda,
+        legacy_credential_data(&authority.pubkey(), credential_name, &[authority.pubkey()]),
+    )
+    .await;
+
+    let schema_name = "test_data";
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    set_program_account(
+        &mut ctx,
+        &schema_pda,
+        legacy_schema_data(&credential_pda, schema_name, &["name", "location"]),
+    )
+    .await;
+
+    let mut serialized_attestation_data = Vec::new();
+    TestData {
+        name: "attest".to_string(),
+        location: 11,
+    }
+    .serialize(&mut serialized_attestation_data)
+    .unwrap();
+
+    // Legacy Credentials and Schemas can still be read.
+    let nonce = Pubkey::new_unique();
+    let (attestation_pda, attestation_bump) = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &credential_pda.to_bytes(),
+            &schema_pda.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let ix = CreateAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(serialized_attestation_data.clone())
+        .expiry(0)
+        .nonce(nonce)
+        .bump(attestation_bump)
+        .instruction();
+    process(&ctx, ix, &[&ctx.payer, &authority]).await.unwrap();
+
+    let ix = migrate_account_ix(&ctx, schema_pda, schema_bump);
+    process(&ctx, ix, &[&ctx.payer]).await.unwrap();
+    let schema_account = ctx
+        .banks_client
+        .get_account(schema_pda)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let schema = Schema::try_from_slice(&schema_account.data).unwrap();
+    assert_eq!(schema.discriminator, 4);
+    assert_eq!(schema.layout_version, 7);
+    assert_eq!(schema.credential, credential_pda);
+    assert_eq!(schema.name, schema_name.as_bytes());
+    assert_eq!(schema.layout, TestData::get_serialized_representation());
+    assert_eq!(schema.version, 1);
+    assert!(schema.field_constraints.is_empty());
+    assert_eq!(schema.bump, schema_bump);
+    assert_eq!(schema.commitment_scheme, 0);
+    assert_eq!(schema.ref_schema, Pubkey::default());
+    assert_eq!(schema.ref_close_policy, 1);
+    assert_eq!(schema.fee, 0);
+    assert_eq!(schema.fee_mint, Pubkey::default());
+    assert_eq!(schema.fee_treasury, Pubkey::default());
+    assert_eq!(schema.resolver_program, Pubkey::default());
+    assert!(schema.token_metadata_fields.is_empty());
+    // Migrated Schemas keep issuing non-transferable tokens the program can burn.
+    assert_eq!(schema.token_policy, 3);
+
+    let legacy_nonce = Pubkey::new_unique();
+    let (legacy_attestation_pda, legacy_attestation_bump) = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &credential_pda.to_bytes(),
+            &schema_pda.to_bytes(),
+            &legacy_nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    set_program_account(
+        &mut ctx,
+        &legacy_attestation_pda,
+        legacy_attestation_data(
+            &legacy_nonce,
+            &credential_pda,
+            &schema_pda,
+            &serialized_attestation_data,
+            &authority.pubkey(),
+        ),
+    )
+    .await;
+
+    let ix = migrate_account_ix(&ctx, legacy_attestation_pda, legacy_attestation_bump);
+    process(&ctx, ix, &[&ctx.payer]).await.unwrap();
+    let attestation_account = ctx
+        .banks_client
+        .get_account(legacy_attestation_pda)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let attestation = Attestation::try_from_slice(&attestation_account.data).unwrap();
+    assert_eq!(attestation.discriminator, 5);
+    assert_eq!(attestation.layout_version, 4);
+    assert_eq!(attestation.nonce, legacy_nonce);
+    assert_eq!(attestation.data, serialized_attestation_data);
+    assert_eq!(attestation.signer, authority.pubkey());
+    assert_eq!(attestation.bump, legacy_attestation_bump);
+    assert_eq!(attestation.signer_kind, 0);
+    assert_eq!(attestation.ref_attestation, Pubkey::default());
+    assert_eq!(attestation.ref_count, 0);
+    assert_eq!(attestation.resolver_program, Pubkey::default());
+}
+
+#[tokio::test]
+async fn migrate_account_fail_wrong_bump() {
+    let mut ctx = program_test_context().await;
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    set_program_account(
+        &mut ctx,
+        &credential_pda,
+        legacy_credential_data(&authority.pubkey(), credential_name, &[authority.pubkey()]),
+    )
+    .await;
+
+    let ix = migrate_account_ix(&ctx, credential_pda, credential_bump.wrapping_sub(1));
+    let tx_err = process(&ctx, ix, &[&ctx.payer]).await.unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(0))
+    );
+}
+
+#[tokio::test]
+async fn migrate_credential_layout_version_1_success() {
+    let mut ctx = program_test_context().await;
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    // Layout version 1 adds the bump but has no metadata.
+    let mut data =
+        legacy_credential_data(&authority.pubkey(), credential_name, &[authority.pubkey()]);
+    data[0] = 3;
+    data.insert(1, 1);
+    data.push(credential_bump);
+    set_program_account(&mut ctx, &credential_pda, data).await;
+
+    // The stored bump is kept, the bump argument is not used.
+    let ix = migrate_account_ix(&ctx, credential_pda, 0);
+    process(&ctx, ix, &[&ctx.payer]).await.unwrap();
+
+    let credential_account = ctx
+        .banks_client
+        .get_account(credential_pda)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let credential = Credential::try_from_slice(&credential_account.data).unwrap();
+    assert_eq!(credential.layout_version, 5);
+    assert_eq!(credential.authorized_signers, vec![authority.pubkey()]);
+    assert_eq!(credential.authorized_signer_kinds, vec![0]);
+    assert_eq!(credential.bump, credential_bump);
+    assert!(credential.display_name.is_empty());
+}
+
+#[tokio::test]
+async fn migrate_credential_infers_signer_kinds_success() {
+    let mut ctx = program_test_context().await;
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    // Before layout version 5 Ethereum addresses were only told apart from
+    // ed25519 keys by their zero padding.
+    let signers = [authority.pubkey(), ethereum_signer(&[7; 20])];
+    let data = legacy_credential_data(&authority.pubkey(), credential_name, &signers);
+    set_program_account(&mut ctx, &credential_pda, data).await;
+
+    let ix = migrate_account_ix(&ctx, credential_pda, credential_bump);
+    process(&ctx, ix, &[&ctx.payer]).await.unwrap();
+
+    let credential_account = ctx
+        .banks_client
+        .get_account(credential_pda)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let credential = Credential::try_from_slice(&credential_account.data).unwrap();
+    assert_eq!(credential.layout_version, 5);
+    assert_eq!(credential.authorized_signers, signers.to_vec());
+    assert_eq!(
+        credential.authorized_signer_kinds,
+        vec![SignerKind::Ed25519 as u8, SignerKind::Secp256k1 as u8]
+    );
+}
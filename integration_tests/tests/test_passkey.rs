@@ -0,0 +1,438 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::{program_test_context, Passkey};
+use solana_attestation_service_client::{
+    accounts::{Attestation, Credential},
+    instructions::{
+        ChangePasskeySignersBuilder, CreateAttestationWithPasskeyBuilder,
+        CreateAttestationWithSignatureBuilder, CreateCredentialBuilder, CreateSchemaBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+    signature::{
+        attestation_message, find_used_message_pda, passkey_signer, secp256r1_instruction,
+        webauthn_challenge, webauthn_signed_message, SignerKind, PASSKEY_LEN,
+    },
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program, sysvar,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    authority: Keypair,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
+        .instruction();
+
+    let schema_name = "test_data";
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential_pda.to_bytes(),
+            schema_name.as_bytes(),
+            &[1],
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("schema for test data".to_string())
+        .name(schema_name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into()])
+        .bump(schema_bump)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        authority,
+    }
+}
+
+async fn change_passkey_signers(
+    fixtures: &TestFixtures,
+    passkeys: Vec<[u8; PASSKEY_LEN]>,
+) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let ix = ChangePasskeySignersBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .system_program(system_program::ID)
+        .passkeys(passkeys)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+struct PasskeyAttestation {
+    nonce: Pubkey,
+    data: Vec<u8>,
+    expiry: i64,
+    deadline: i64,
+    authenticator_data: Vec<u8>,
+    client_data_json: Vec<u8>,
+}
+
+impl PasskeyAttestation {
+    fn new(fixtures: &TestFixtures) -> Self {
+        let nonce = Pubkey::new_unique();
+        let data = borsh::to_vec(&TestData {
+            name: "attest".to_string(),
+            location: 11,
+        })
+        .unwrap();
+        let deadline = i64::MAX;
+        let message = attestation_message(
+            &fixtures.credential,
+            &fixtures.schema,
+            &nonce,
+            &data,
+            0,
+            deadline,
+        );
+        Self {
+            nonce,
+            data,
+            expiry: 0,
+            deadline,
+            // rpIdHash, flags with user present set, signCount
+            authenticator_data: [[0; 32].as_slice(), [0x01].as_slice(), [0; 4].as_slice()].concat(),
+            client_data_json: client_data_json(&webauthn_challenge(&message)),
+        }
+    }
+
+    fn attestation_pda(&self, fixtures: &TestFixtures) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                b"attestation",
+                &fixtures.credential.to_bytes(),
+                &fixtures.schema.to_bytes(),
+                &self.nonce.to_bytes(),
+            ],
+            &SOLANA_ATTESTATION_SERVICE_ID,
+        )
+    }
+
+    fn message(&self, fixtures: &TestFixtures) -> Vec<u8> {
+        attestation_message(
+            &fixtures.credential,
+            &fixtures.schema,
+            &self.nonce,
+            &self.data,
+            self.expiry,
+            self.deadline,
+        )
+    }
+
+    fn sign(&self, passkey: &Passkey) -> Instruction {
+        let message = webauthn_signed_message(&self.authenticator_data, &self.client_data_json);
+        secp256r1_instruction(&passkey.public_key, &passkey.sign(&message), &message)
+    }
+
+    fn instruction(&self, fixtures: &TestFixtures, passkey: &Passkey) -> Instruction {
+        let (attestation_pda, attestation_bump) = self.attestation_pda(fixtures);
+        let (used_message_pda, used_message_bump) = find_used_message_pda(&self.message(fixtures));
+        CreateAttestationWithPasskeyBuilder::new()
+            .payer(fixtures.ctx.payer.pubkey())
+            .authority(passkey_signer(&passkey.public_key))
+            .credential(fixtures.credential)
+            .schema(fixtures.schema)
+            .attestation(attestation_pda)
+            .system_program(system_program::ID)
+            .instructions_sysvar(sysvar::instructions::ID)
+            .used_message(used_message_pda)
+            .deadline(self.deadline)
+            .authenticator_data(self.authenticator_data.clone())
+            .client_data_json(self.client_data_json.clone())
+            .nonce(self.nonce)
+            .data(self.data.clone())
+            .expiry(self.expiry)
+            .bump(attestation_bump)
+            .used_message_bump(used_message_bump)
+            .instruction()
+    }
+}
+
+fn client_data_json(challenge: &str) -> Vec<u8> {
+    format!(
+        r#"{{"type":"webauthn.get","challenge":"{}","origin":"https://example.com"}}"#,
+        challenge
+    )
+    .into_bytes()
+}
+
+async fn process(
+    fixtures: &TestFixtures,
+    instructions: &[Instruction],
+) -> Result<(), TransactionError> {
+    // Only the relayer signs the transaction.
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+#[tokio::test]
+async fn change_passkey_signers_success() {
+    let fixtures = setup().await;
+    let passkeys = vec![Passkey::new().public_key, Passkey::new().public_key];
+
+    change_passkey_signers(&fixtures, passkeys.clone())
+        .await
+        .unwrap();
+
+    let credential_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(fixtures.credential)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let credential = Credential::try_from_slice(&credential_account.data).unwrap();
+    assert_eq!(credential.passkey_signers, passkeys);
+    // Authorized signers are unchanged.
+    assert_eq!(
+        credential.authorized_signers,
+        vec![fixtures.authority.pubkey()]
+    );
+
+    // Removing every passkey shrinks the Credential back.
+    change_passkey_signers(&fixtures, vec![]).await.unwrap();
+    let credential_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(fixtures.credential)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let credential = Credential::try_from_slice(&credential_account.data).unwrap();
+    assert!(credential.passkey_signers.is_empty());
+}
+
+#[tokio::test]
+async fn change_passkey_signers_fail_invalid_passkey() {
+    let fixtures = setup().await;
+
+    // Uncompressed key prefix.
+    let mut passkey = Passkey::new().public_key;
+    passkey[0] = 0x04;
+    let tx_err = change_passkey_signers(&fixtures, vec![passkey])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(27))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_passkey_success() {
+    let fixtures = setup().await;
+    let passkey = Passkey::new();
+    change_passkey_signers(&fixtures, vec![passkey.public_key])
+        .await
+        .unwrap();
+    let attestation = PasskeyAttestation::new(&fixtures);
+
+    process(
+        &fixtures,
+        &[
+            attestation.sign(&passkey),
+            attestation.instruction(&fixtures, &passkey),
+        ],
+    )
+    .await
+    .unwrap();
+
+    let (attestation_pda, attestation_bump) = attestation.attestation_pda(&fixtures);
+    let attestation_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(attestation_pda)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let created = Attestation::try_from_slice(&attestation_account.data).unwrap();
+    assert_eq!(created.nonce, attestation.nonce);
+    assert_eq!(created.credential, fixtures.credential);
+    assert_eq!(created.schema, fixtures.schema);
+    assert_eq!(created.data, attestation.data);
+    assert_eq!(created.signer, passkey_signer(&passkey.public_key));
+    assert_eq!(created.bump, attestation_bump);
+    assert_eq!(created.signer_kind, SignerKind::Secp256r1 as u8);
+
+    // The message is marked as used, like a signed message.
+    let (used_message_pda, _) = find_used_message_pda(&attestation.message(&fixtures));
+    let used_message_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(used_message_pda)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    assert_eq!(used_message_account.owner, SOLANA_ATTESTATION_SERVICE_ID);
+}
+
+#[tokio::test]
+async fn create_attestation_with_passkey_fail_wrong_challenge() {
+    let fixtures = setup().await;
+    let passkey = Passkey::new();
+    change_passkey_signers(&fixtures, vec![passkey.public_key])
+        .await
+        .unwrap();
+    let attestation = PasskeyAttestation {
+        client_data_json: client_data_json(&webauthn_challenge(b"another message")),
+        ..PasskeyAttestation::new(&fixtures)
+    };
+
+    let tx_err = process(
+        &fixtures,
+        &[
+            attestation.sign(&passkey),
+            attestation.instruction(&fixtures, &passkey),
+        ],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(1, InstructionError::Custom(28))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_passkey_fail_unregistered_passkey() {
+    let fixtures = setup().await;
+    let passkey = Passkey::new();
+    let attestation = PasskeyAttestation::new(&fixtures);
+
+    let tx_err = process(
+        &fixtures,
+        &[
+            attestation.sign(&passkey),
+            attestation.instruction(&fixtures, &passkey),
+        ],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(1, InstructionError::Custom(5))
+    );
+}
+
+#[tokio::test]
+async fn create_attestation_with_signature_fail_passkey_signer() {
+    let fixtures = setup().await;
+    let passkey = Passkey::new();
+    change_passkey_signers(&fixtures, vec![passkey.public_key])
+        .await
+        .unwrap();
+    let attestation = PasskeyAttestation::new(&fixtures);
+
+    // The passkey signs the attestation message itself instead of a WebAuthn
+    // assertion of it.
+    let message = attestation.message(&fixtures);
+    let (attestation_pda, attestation_bump) = attestation.attestation_pda(&fixtures);
+    let (used_message_pda, used_message_bump) = find_used_message_pda(&message);
+    let ix = CreateAttestationWithSignatureBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(passkey_signer(&passkey.public_key))
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .instructions_sysvar(sysvar::instructions::ID)
+        .used_message(used_message_pda)
+        .deadline(attestation.deadline)
+        .nonce(attestation.nonce)
+        .data(attestation.data.clone())
+        .expiry(attestation.expiry)
+        .bump(attestation_bump)
+        .used_message_bump(used_message_bump)
+        .instruction();
+
+    let tx_err = process(
+        &fixtures,
+        &[
+            secp256r1_instruction(&passkey.public_key, &passkey.sign(&message), &message),
+            ix,
+        ],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(1, InstructionError::Custom(25))
+    );
+}
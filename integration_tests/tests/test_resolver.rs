@@ -0,0 +1,485 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_attestation_service_client::{
+    accounts::{Attestation, Schema},
+    instructions::{
+        CloseAttestationBuilder, CreateAttestationBuilder, CreateCredentialBuilder,
+        CreateSchemaBuilder, TokenizeAttestationBuilder, TokenizeSchemaBuilder,
+    },
+    programs::SOLANA_ATTESTATION_SERVICE_ID,
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id, ID as ATA_PROGRAM_ID,
+};
+use spl_token_2022::ID as TOKEN_2022_PROGRAM_ID;
+
+/// Resolver that requires the account forwarded after the Attestation, its
+/// subject, to sign when Attestations are created and closed.
+mod sample_resolver {
+    use solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID;
+    use solana_sdk::{
+        account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+        pubkey::Pubkey,
+    };
+
+    pub const ID: Pubkey = Pubkey::new_from_array([7; 32]);
+    /// Error returned when the subject did not sign.
+    pub const REJECTED: u32 = 100;
+
+    pub fn process_instruction(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        data: &[u8],
+    ) -> ProgramResult {
+        let [resolver_authority, attestation, subject, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Only the attestation service can sign as the resolver authority of
+        // this resolver program.
+        let (expected_authority, _) = Pubkey::find_program_address(
+            &[b"resolverAuthority", program_id.as_ref()],
+            &SOLANA_ATTESTATION_SERVICE_ID,
+        );
+        if resolver_authority.key.ne(&expected_authority) || !resolver_authority.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Create, Close and Update hooks carry the Attestation account data.
+        let Some((0..=2, attestation_data)) = data.split_first() else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+        if attestation_data != &attestation.try_borrow_data()?[..] {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if !subject.is_signer {
+            return Err(ProgramError::Custom(REJECTED));
+        }
+        Ok(())
+    }
+}
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    authority: Keypair,
+    schema: Pubkey,
+    subject: Keypair,
+}
+
+fn resolver_authority(resolver_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"resolverAuthority", resolver_program.as_ref()],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    )
+    .0
+}
+
+fn create_schema_ix(
+    ctx: &ProgramTestContext,
+    authority: &Keypair,
+    credential: &Pubkey,
+    name: &str,
+    resolver_program: Pubkey,
+) -> (Pubkey, Instruction) {
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
+        &[b"schema", &credential.to_bytes(), name.as_bytes(), &[1]],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(*credential)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description("schema for test data".to_string())
+        .name(name.to_string())
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into()])
+        .bump(schema_bump)
+        .resolver_program(resolver_program)
+        .instruction();
+    (schema_pda, ix)
+}
+
+async fn setup() -> TestFixtures {
+    let mut program_test = ProgramTest::default();
+    program_test.add_program(
+        "solana_attestation_service",
+        SOLANA_ATTESTATION_SERVICE_ID,
+        None,
+    );
+    program_test.add_program(
+        "sample_resolver",
+        sample_resolver::ID,
+        processor!(sample_resolver::process_instruction),
+    );
+    let ctx = program_test.start_with_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
+        .instruction();
+    let (schema, create_schema_ix) = create_schema_ix(
+        &ctx,
+        &authority,
+        &credential_pda,
+        "resolved",
+        sample_resolver::ID,
+    );
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        authority,
+        schema,
+        subject: Keypair::new(),
+    }
+}
+
+async fn process(
+    fixtures: &TestFixtures,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), TransactionError> {
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&[&ctx.payer, &fixtures.authority][..], signers].concat(),
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+/// Resolver accounts forwarding the subject, signing when `subject_signs`.
+fn resolver_accounts(fixtures: &TestFixtures, subject_signs: bool) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new_readonly(sample_resolver::ID, false),
+        AccountMeta::new_readonly(resolver_authority(&sample_resolver::ID), false),
+        AccountMeta::new_readonly(fixtures.subject.pubkey(), subject_signs),
+    ]
+}
+
+/// CreateAttestation instruction with `remaining_accounts`, with the address
+/// of the Attestation it creates.
+fn create_attestation_ix(
+    fixtures: &TestFixtures,
+    remaining_accounts: &[AccountMeta],
+) -> (Pubkey, Instruction) {
+    let nonce = Pubkey::new_unique();
+    let (attestation_pda, attestation_bump) = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &fixtures.credential.to_bytes(),
+            &fixtures.schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let ix = CreateAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .nonce(nonce)
+        .data(
+            borsh::to_vec(&TestData {
+                name: "attest".to_string(),
+                location: 11,
+            })
+            .unwrap(),
+        )
+        .expiry(0)
+        .bump(attestation_bump)
+        .add_remaining_accounts(remaining_accounts)
+        .instruction();
+    (attestation_pda, ix)
+}
+
+/// CloseAttestation instruction with `remaining_accounts`.
+fn close_attestation_ix(
+    fixtures: &TestFixtures,
+    attestation: &Pubkey,
+    remaining_accounts: &[AccountMeta],
+) -> Instruction {
+    let (event_auth_pda, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID);
+    CloseAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .attestation(*attestation)
+        .event_authority(event_auth_pda)
+        .system_program(system_program::ID)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .add_remaining_accounts(remaining_accounts)
+        .instruction()
+}
+
+async fn create_attestation(fixtures: &TestFixtures) -> Pubkey {
+    let (attestation_pda, ix) = create_attestation_ix(fixtures, &resolver_accounts(fixtures, true));
+    process(fixtures, ix, &[&fixtures.subject]).await.unwrap();
+    attestation_pda
+}
+
+async fn get_attestation(fixtures: &TestFixtures, attestation: &Pubkey) -> Option<Attestation> {
+    fixtures
+        .ctx
+        .banks_client
+        .get_account(*attestation)
+        .await
+        .expect("get_account")
+        .map(|account| Attestation::try_from_slice(&account.data).unwrap())
+}
+
+#[tokio::test]
+async fn create_attestation_with_resolver_success() {
+    let fixtures = setup().await;
+
+    let schema_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(fixtures.schema)
+        .await
+        .unwrap()
+        .unwrap();
+    let schema = Schema::try_from_slice(&schema_account.data).unwrap();
+    assert_eq!(schema.resolver_program, sample_resolver::ID);
+
+    let attestation = create_attestation(&fixtures).await;
+    let attestation = get_attestation(&fixtures, &attestation).await.unwrap();
+    assert_eq!(attestation.resolver_program, sample_resolver::ID);
+}
+
+#[tokio::test]
+async fn create_attestation_with_resolver_fail() {
+    let fixtures = setup().await;
+
+    // The resolver rejects Attestations its subject did not sign for.
+    let (_, ix) = create_attestation_ix(&fixtures, &resolver_accounts(&fixtures, false));
+    let tx_err = process(&fixtures, ix, &[]).await.unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(sample_resolver::REJECTED))
+    );
+
+    // The resolver cannot be skipped.
+    let (_, ix) = create_attestation_ix(&fixtures, &[]);
+    let tx_err = process(&fixtures, ix, &[]).await.unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(34))
+    );
+
+    // The resolver authority must be the attestation service's PDA for the
+    // Schema's resolver program, not for another resolver program.
+    for authority in [
+        Pubkey::new_unique(),
+        resolver_authority(&Pubkey::new_unique()),
+    ] {
+        let mut accounts = resolver_accounts(&fixtures, true);
+        accounts[1] = AccountMeta::new_readonly(authority, false);
+        let (_, ix) = create_attestation_ix(&fixtures, &accounts);
+        let tx_err = process(&fixtures, ix, &[&fixtures.subject])
+            .await
+            .unwrap_err();
+        assert_eq!(
+            tx_err,
+            TransactionError::InstructionError(0, InstructionError::Custom(34))
+        );
+    }
+}
+
+#[tokio::test]
+async fn close_attestation_with_resolver() {
+    let fixtures = setup().await;
+    let attestation = create_attestation(&fixtures).await;
+
+    // The resolver the Attestation was created with cannot be skipped.
+    let tx_err = process(
+        &fixtures,
+        close_attestation_ix(&fixtures, &attestation, &[]),
+        &[],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(34))
+    );
+
+    // The resolver rejects closing without the subject.
+    let tx_err = process(
+        &fixtures,
+        close_attestation_ix(
+            &fixtures,
+            &attestation,
+            &resolver_accounts(&fixtures, false),
+        ),
+        &[],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(sample_resolver::REJECTED))
+    );
+
+    process(
+        &fixtures,
+        close_attestation_ix(&fixtures, &attestation, &resolver_accounts(&fixtures, true)),
+        &[&fixtures.subject],
+    )
+    .await
+    .unwrap();
+    assert!(get_attestation(&fixtures, &attestation).await.is_none());
+}
+
+#[tokio::test]
+async fn create_schema_with_resolver_fail_self() {
+    let fixtures = setup().await;
+
+    // The attestation service cannot be its own resolver.
+    let (_, ix) = create_schema_ix(
+        &fixtures.ctx,
+        &fixtures.authority,
+        &fixtures.credential,
+        "recursive",
+        SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let tx_err = process(&fixtures, ix, &[]).await.unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(34))
+    );
+}
+
+#[tokio::test]
+async fn tokenize_attestation_with_resolver() {
+    let fixtures = setup().await;
+    let attestation = create_attestation(&fixtures).await;
+
+    let (sas_pda, _) = Pubkey::find_program_address(&[b"sas"], &SOLANA_ATTESTATION_SERVICE_ID);
+    let (schema_mint, schema_mint_bump) = Pubkey::find_program_address(
+        &[b"schemaMint", &fixtures.schema.to_bytes()],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let (attestation_mint, attestation_mint_bump) = Pubkey::find_program_address(
+        &[b"attestationMint", &attestation.to_bytes()],
+        &SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    let recipient = Pubkey::new_unique();
+    let recipient_token_account = get_associated_token_address_with_program_id(
+        &recipient,
+        &attestation_mint,
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    let tokenize_schema_ix = TokenizeSchemaBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .mint(schema_mint)
+        .sas_pda(sas_pda)
+        .max_size(100)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .mint_bump(schema_mint_bump)
+        .name("Test Schema".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .instruction();
+    process(&fixtures, tokenize_schema_ix, &[]).await.unwrap();
+
+    let tokenize_attestation_ix = |subject_signs| {
+        TokenizeAttestationBuilder::new()
+            .payer(fixtures.ctx.payer.pubkey())
+            .authority(fixtures.authority.pubkey())
+            .credential(fixtures.credential)
+            .schema(fixtures.schema)
+            .attestation(attestation)
+            .system_program(system_program::ID)
+            .schema_mint(schema_mint)
+            .attestation_mint(attestation_mint)
+            .sas_pda(sas_pda)
+            .recipient_token_account(recipient_token_account)
+            .recipient(recipient)
+            .token_program(TOKEN_2022_PROGRAM_ID)
+            .associated_token_program(ATA_PROGRAM_ID)
+            .name("Test Asset".to_string())
+            .uri("https://x.com".to_string())
+            .symbol("VAT".to_string())
+            .schema_mint_bump(schema_mint_bump)
+            .attestation_mint_bump(attestation_mint_bump)
+            .add_remaining_accounts(&resolver_accounts(&fixtures, subject_signs))
+            .instruction()
+    };
+
+    // The resolver rejects updating the Attestation without the subject.
+    let tx_err = process(&fixtures, tokenize_attestation_ix(false), &[])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(sample_resolver::REJECTED))
+    );
+    let state = get_attestation(&fixtures, &attestation).await.unwrap();
+    assert_eq!(state.token_account, Pubkey::default());
+
+    process(
+        &fixtures,
+        tokenize_attestation_ix(true),
+        &[&fixtures.subject],
+    )
+    .await
+    .unwrap();
+    let state = get_attestation(&fixtures, &attestation).await.unwrap();
+    assert_eq!(state.token_account, recipient_token_account);
+}
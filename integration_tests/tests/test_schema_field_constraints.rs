@@ -0,0 +1,345 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::Schema,
+    instructions::{
+        ChangeSchemaVersionBuilder, CreateAttestationBuilder, CreateCredentialBuilder,
+        CreateSchemaBuilder,
+    },
+};
+use solana_attestation_service_macros::SchemaStructSerialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+#[derive(BorshSerialize, SchemaStructSerialize)]
+struct TestData {
+    name: String,
+    location: u8,
+}
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    schema: Pubkey,
+    authority: Keypair,
+}
+
+fn schema_pda(credential: &Pubkey, version: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"schema",
+            &credential.to_bytes(),
+            "test_data".as_bytes(),
+            &[version],
+        ],
+        &solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID,
+    )
+}
+
+async fn setup(field_constraints: Vec<u8>) -> Result<TestFixtures, TransactionError> {
+    let ctx = program_test_context().await;
+
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID,
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
+        .instruction();
+
+    // Create Schema
+    let schema_name = "test_data";
+    let description = "schema for test data";
+    let schema_data = TestData::get_serialized_representation();
+    let field_names = vec!["name".into(), "location".into()];
+    let (schema_pda, schema_bump) = schema_pda(&credential_pda, 1);
+    let create_schema_ix = CreateSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential_pda)
+        .schema(schema_pda)
+        .system_program(system_program::ID)
+        .description(description.to_string())
+        .name(schema_name.to_string())
+        .layout(schema_data.clone())
+        .field_names(field_names)
+        .bump(schema_bump)
+        .field_constraints(field_constraints)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix, create_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())?;
+
+    Ok(TestFixtures {
+        ctx,
+        credential: credential_pda,
+        schema: schema_pda,
+        authority,
+    })
+}
+
+/// name is at most 8 bytes and location is in [1, 10].
+fn test_constraints() -> Vec<u8> {
+    let mut constraints = Vec::new();
+    // Field 0, MaxLength
+    constraints.extend([0, 1]);
+    constraints.extend(8u32.to_le_bytes());
+    // Field 1, Range
+    constraints.extend([1, 0]);
+    constraints.extend(1u128.to_le_bytes());
+    constraints.extend(10u128.to_le_bytes());
+    constraints
+}
+
+fn create_attestation_ix(fixtures: &TestFixtures, schema: Pubkey, data: &TestData) -> Instruction {
+    let mut serialized_attestation_data = Vec::new();
+    data.serialize(&mut serialized_attestation_data).unwrap();
+    let nonce = Pubkey::new_unique();
+    let (attestation_pda, attestation_bump) = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            &fixtures.credential.to_bytes(),
+            &schema.to_bytes(),
+            &nonce.to_bytes(),
+        ],
+        &solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID,
+    );
+    CreateAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .data(serialized_attestation_data)
+        .expiry(0)
+        .nonce(nonce)
+        .bump(attestation_bump)
+        .instruction()
+}
+
+#[tokio::test]
+async fn create_schema_with_field_constraints_success() {
+    let constraints = test_constraints();
+    let fixtures = setup(constraints.clone()).await.unwrap();
+    let ctx = &fixtures.ctx;
+
+    // Assert schema account
+    let schema_account = ctx
+        .banks_client
+        .get_account(fixtures.schema)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let schema = Schema::try_from_slice(&schema_account.data).unwrap();
+    assert_eq!(schema.field_constraints, constraints);
+
+    // Attestation within constraints succeeds.
+    let ix = create_attestation_ix(
+        &fixtures,
+        fixtures.schema,
+        &TestData {
+            name: "attest".to_string(),
+            location: 10,
+        },
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Attestation with location out of range fails.
+    let ix = create_attestation_ix(
+        &fixtures,
+        fixtures.schema,
+        &TestData {
+            name: "attest".to_string(),
+            location: 11,
+        },
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(13))
+    );
+
+    // Attestation with name too long fails.
+    let ix = create_attestation_ix(
+        &fixtures,
+        fixtures.schema,
+        &TestData {
+            name: "attestation".to_string(),
+            location: 1,
+        },
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(13))
+    );
+}
+
+#[tokio::test]
+async fn create_schema_fail_field_constraint_type_mismatch() {
+    // Range constraint on the String field.
+    let mut constraints = Vec::new();
+    constraints.extend([0, 0]);
+    constraints.extend(1u128.to_le_bytes());
+    constraints.extend(10u128.to_le_bytes());
+    // CreateSchema follows CreateCredential in the setup transaction.
+    let tx_err = setup(constraints).await.err().expect("should error");
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(1, InstructionError::Custom(12))
+    );
+}
+
+#[tokio::test]
+async fn change_schema_version_with_field_constraints_success() {
+    let fixtures = setup(test_constraints()).await.unwrap();
+    let ctx = &fixtures.ctx;
+
+    // The new version only allows a location of 11 or more.
+    let mut constraints = Vec::new();
+    constraints.extend([1, 0]);
+    constraints.extend(11u128.to_le_bytes());
+    constraints.extend(255u128.to_le_bytes());
+    let (new_schema, new_schema_bump) = schema_pda(&fixtures.credential, 2);
+    let ix = ChangeSchemaVersionBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .existing_schema(fixtures.schema)
+        .new_schema(new_schema)
+        .system_program(system_program::ID)
+        .layout(TestData::get_serialized_representation())
+        .field_names(vec!["name".into(), "location".into()])
+        .bump(new_schema_bump)
+        .field_constraints(constraints.clone())
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let schema_account = ctx
+        .banks_client
+        .get_account(new_schema)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let schema = Schema::try_from_slice(&schema_account.data).unwrap();
+    assert_eq!(schema.field_constraints, constraints);
+
+    // The previous version keeps its constraints.
+    let schema_account = ctx
+        .banks_client
+        .get_account(fixtures.schema)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    let schema = Schema::try_from_slice(&schema_account.data).unwrap();
+    assert_eq!(schema.field_constraints, test_constraints());
+
+    // A location of 11 is accepted by the new version only.
+    let data = TestData {
+        name: "attest".to_string(),
+        location: 11,
+    };
+    let ix = create_attestation_ix(&fixtures, new_schema, &data);
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let ix = create_attestation_ix(&fixtures, fixtures.schema, &data);
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(13))
+    );
+}
@@ -1,30 +1,48 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use helpers::program_test_context;
+use helpers::{non_canonical_program_address, program_test_context};
 use solana_attestation_service_client::{
-    accounts::Attestation,
+    accounts::{Attestation, Schema},
     instructions::{
-        CloseTokenizedAttestationBuilder, CreateCredentialBuilder, CreateSchemaBuilder,
-        CreateTokenizedAttestationBuilder, TokenizeSchemaBuilder,
+        ChangeCredentialStatusBuilder, ChangeSchemaTokenMetadataFieldsBuilder,
+        CloseTokenizedAttestationBuilder, CreateAttestationBuilder, CreateCredentialBuilder,
+        CreateSchemaBuilder, CreateTokenizedAttestationBuilder,
+        FreezeExpiredAttestationTokenBuilder, TokenizeAttestationBuilder, TokenizeSchemaBuilder,
+        UpdateSchemaTokenMetadataBuilder,
     },
     programs::SOLANA_ATTESTATION_SERVICE_ID,
+    token::{
+        attestation_mint_space, schema_mint_space, DEFAULT_TOKEN_POLICY,
+        TOKEN_POLICY_FREEZE_ON_EXPIRY, TOKEN_POLICY_NON_TRANSFERABLE,
+        TOKEN_POLICY_PERMANENT_DELEGATE,
+    },
 };
 use solana_attestation_service_macros::SchemaStructSerialize;
 use solana_program_test::ProgramTestContext;
 use solana_sdk::{
-    clock::Clock, program_option::COption, program_pack::Pack, pubkey::Pubkey, signature::Keypair,
-    signer::Signer, system_program, transaction::Transaction,
+    clock::Clock,
+    instruction::{Instruction, InstructionError},
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
 };
 use spl_associated_token_account::{
-    get_associated_token_address_with_program_id, ID as ATA_PROGRAM_ID,
+    get_associated_token_address_with_program_id, instruction::create_associated_token_account,
+    ID as ATA_PROGRAM_ID,
 };
 use spl_token_2022::{
+    error::TokenError,
     extension::{
         group_member_pointer::GroupMemberPointer, group_pointer::GroupPointer,
         metadata_pointer::MetadataPointer, mint_close_authority::MintCloseAuthority,
         non_transferable::NonTransferable, permanent_delegate::PermanentDelegate,
         BaseStateWithExtensions, ExtensionType, StateWithExtensions,
     },
-    state::{Account, Mint},
+    instruction::{burn_checked, transfer_checked},
+    state::{Account, AccountState, Mint},
     ID as TOKEN_2022_PROGRAM_ID,
 };
 use spl_token_group_interface::state::{TokenGroup, TokenGroupMember};
@@ -44,9 +62,12 @@ struct TestFixtures {
     schema: Pubkey,
     authority: Keypair,
     schema_mint_pda: Pubkey,
+    schema_mint_bump: u8,
     sas_pda: Pubkey,
     attestation_pda: Pubkey,
+    attestation_bump: u8,
     attestation_mint_pda: Pubkey,
+    attestation_mint_bump: u8,
     recipient: Pubkey,
     recipient_token_account: Pubkey,
     nonce: Pubkey,
@@ -58,7 +79,7 @@ async fn setup() -> TestFixtures {
 
     let authority = Keypair::new();
     let credential_name = "test";
-    let (credential_pda, _bump) = Pubkey::find_program_address(
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
         &[
             b"credential",
             &authority.pubkey().to_bytes(),
@@ -74,6 +95,7 @@ async fn setup() -> TestFixtures {
         .system_program(system_program::ID)
         .name(credential_name.to_string())
         .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
         .instruction();
 
     // Create Schema
@@ -81,7 +103,7 @@ async fn setup() -> TestFixtures {
     let description = "schema for test data";
     let schema_data = TestData::get_serialized_representation();
     let field_names = vec!["name".into(), "location".into()];
-    let (schema_pda, _bump) = Pubkey::find_program_address(
+    let (schema_pda, schema_bump) = Pubkey::find_program_address(
         &[
             b"schema",
             &credential_pda.to_bytes(),
@@ -100,6 +122,7 @@ async fn setup() -> TestFixtures {
         .name(schema_name.to_string())
         .layout(schema_data.clone())
         .field_names(field_names)
+        .bump(schema_bump)
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -114,13 +137,13 @@ async fn setup() -> TestFixtures {
         .unwrap();
 
     let (sas_pda, _bump) = Pubkey::find_program_address(&[b"sas"], &SOLANA_ATTESTATION_SERVICE_ID);
-    let (schema_mint_pda, _bump) = Pubkey::find_program_address(
+    let (schema_mint_pda, schema_mint_bump) = Pubkey::find_program_address(
         &[b"schemaMint", &schema_pda.to_bytes()],
         &SOLANA_ATTESTATION_SERVICE_ID,
     );
 
     let nonce = Pubkey::new_unique();
-    let attestation_pda = Pubkey::find_program_address(
+    let (attestation_pda, attestation_bump) = Pubkey::find_program_address(
         &[
             b"attestation",
             &credential_pda.to_bytes(),
@@ -128,9 +151,8 @@ async fn setup() -> TestFixtures {
             &nonce.to_bytes(),
         ],
         &SOLANA_ATTESTATION_SERVICE_ID,
-    )
-    .0;
-    let (attestation_mint_pda, _bump) = Pubkey::find_program_address(
+    );
+    let (attestation_mint_pda, attestation_mint_bump) = Pubkey::find_program_address(
         &[b"attestationMint", &attestation_pda.to_bytes()],
         &SOLANA_ATTESTATION_SERVICE_ID,
     );
@@ -158,8 +180,11 @@ async fn setup() -> TestFixtures {
         authority,
         sas_pda,
         schema_mint_pda,
+        schema_mint_bump,
         attestation_pda,
+        attestation_bump,
         attestation_mint_pda,
+        attestation_mint_bump,
         recipient,
         recipient_token_account,
         serialized_attestation_data,
@@ -176,8 +201,11 @@ async fn tokenize_schema_success() {
         authority,
         sas_pda,
         schema_mint_pda,
+        schema_mint_bump,
         attestation_pda: _,
+        attestation_bump: _,
         attestation_mint_pda: _,
+        attestation_mint_bump: _,
         recipient: _,
         recipient_token_account: _,
         nonce: _,
@@ -194,6 +222,10 @@ async fn tokenize_schema_success() {
         .sas_pda(sas_pda)
         .max_size(max_size)
         .token_program(TOKEN_2022_PROGRAM_ID)
+        .mint_bump(schema_mint_bump)
+        .name("Test Schema".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -214,15 +246,43 @@ async fn tokenize_schema_success() {
         .unwrap()
         .unwrap();
 
+    assert_eq!(mint_account.owner, TOKEN_2022_PROGRAM_ID);
+
+    let mint_state = StateWithExtensions::<Mint>::unpack(&mint_account.data).unwrap();
+    let token_metadata = mint_state
+        .get_variable_len_extension::<TokenMetadata>()
+        .unwrap();
     let expected_acc_size = ExtensionType::try_calculate_account_len::<Mint>(&[
         ExtensionType::GroupPointer,
+        ExtensionType::MetadataPointer,
         ExtensionType::TokenGroup,
     ])
-    .unwrap();
+    .unwrap()
+        + token_metadata.tlv_size_of().unwrap();
     assert_eq!(mint_account.data.len(), expected_acc_size);
-    assert_eq!(mint_account.owner, TOKEN_2022_PROGRAM_ID);
+    assert_eq!(
+        mint_account.data.len(),
+        schema_mint_space(
+            "Test Schema",
+            "VAT",
+            "https://x.com",
+            &[
+                ("credential", &credential.to_string()),
+                ("schema", &schema.to_string()),
+                ("version", "1"),
+            ],
+        )
+    );
+
+    // The mint is funded for its full size with token metadata.
+    let expected_lamports = ctx
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(mint_account.data.len());
+    assert_eq!(mint_account.lamports, expected_lamports);
 
-    let mint_state = StateWithExtensions::<Mint>::unpack(&mint_account.data).unwrap();
     assert!(mint_state.base.is_initialized);
     assert_eq!(mint_state.base.decimals, 0);
     assert_eq!(mint_state.base.supply, 0);
@@ -240,6 +300,190 @@ async fn tokenize_schema_success() {
     assert_eq!(token_group.mint, schema_mint_pda);
     assert_eq!(u64::from(token_group.size), 0);
     assert_eq!(u64::from(token_group.max_size), max_size);
+
+    // Verify the MetadataPointer extension.
+    let metadata_pointer = mint_state.get_extension::<MetadataPointer>().unwrap();
+    assert_eq!(metadata_pointer.authority.0, sas_pda);
+    assert_eq!(metadata_pointer.metadata_address.0, schema_mint_pda);
+
+    // Verify the TokenMetadata extension.
+    assert_eq!(token_metadata.update_authority.0, sas_pda);
+    assert_eq!(token_metadata.mint, schema_mint_pda);
+    assert_eq!(token_metadata.name, "Test Schema");
+    assert_eq!(token_metadata.uri, "https://x.com");
+    assert_eq!(token_metadata.symbol, "VAT");
+    assert_eq!(
+        token_metadata.additional_metadata,
+        vec![
+            ("credential".to_string(), credential.to_string()),
+            ("schema".to_string(), schema.to_string()),
+            ("version".to_string(), "1".to_string()),
+        ]
+    );
+
+    // Verify the default token policy is stored on the Schema.
+    let schema_account = ctx.banks_client.get_account(schema).await.unwrap().unwrap();
+    let schema_state = Schema::try_from_slice(&schema_account.data).unwrap();
+    assert_eq!(
+        schema_state.token_policy,
+        TOKEN_POLICY_NON_TRANSFERABLE | TOKEN_POLICY_PERMANENT_DELEGATE
+    );
+}
+
+async fn pause_credential(ctx: &ProgramTestContext, credential: Pubkey, authority: &Keypair) {
+    let pause_credential_ix = ChangeCredentialStatusBuilder::new()
+        .authority(authority.pubkey())
+        .credential(credential)
+        .is_paused(true)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[pause_credential_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn tokenize_schema_fail_credential_paused() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        authority,
+        sas_pda,
+        schema_mint_pda,
+        schema_mint_bump,
+        ..
+    } = setup().await;
+
+    pause_credential(&ctx, credential, &authority).await;
+
+    let tokenize_schema_ix = TokenizeSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .mint(schema_mint_pda)
+        .sas_pda(sas_pda)
+        .max_size(100)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .mint_bump(schema_mint_bump)
+        .name("Test Schema".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[tokenize_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(21))
+    );
+}
+
+#[tokio::test]
+async fn create_tokenized_attestation_fail_credential_paused() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        authority,
+        sas_pda,
+        schema_mint_pda,
+        schema_mint_bump,
+        attestation_pda,
+        attestation_bump,
+        attestation_mint_pda,
+        attestation_mint_bump,
+        recipient,
+        recipient_token_account,
+        nonce,
+        serialized_attestation_data,
+    } = setup().await;
+
+    let tokenize_schema_ix = TokenizeSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .mint(schema_mint_pda)
+        .sas_pda(sas_pda)
+        .max_size(100)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .mint_bump(schema_mint_bump)
+        .name("Test Schema".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[tokenize_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    pause_credential(&ctx, credential, &authority).await;
+
+    let create_attestation_ix = CreateTokenizedAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .schema_mint(schema_mint_pda)
+        .attestation_mint(attestation_mint_pda)
+        .sas_pda(sas_pda)
+        .recipient_token_account(recipient_token_account)
+        .recipient(recipient)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .associated_token_program(ATA_PROGRAM_ID)
+        .data(serialized_attestation_data)
+        .expiry(0)
+        .nonce(nonce)
+        .name("Test Asset".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .bump(attestation_bump)
+        .schema_mint_bump(schema_mint_bump)
+        .attestation_mint_bump(attestation_mint_bump)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(21))
+    );
 }
 
 #[tokio::test]
@@ -251,8 +495,11 @@ async fn create_tokenized_attestation_success() {
         authority,
         sas_pda,
         schema_mint_pda,
+        schema_mint_bump,
         attestation_pda,
+        attestation_bump,
         attestation_mint_pda,
+        attestation_mint_bump,
         recipient,
         recipient_token_account,
         nonce,
@@ -268,6 +515,10 @@ async fn create_tokenized_attestation_success() {
         .sas_pda(sas_pda)
         .max_size(100)
         .token_program(TOKEN_2022_PROGRAM_ID)
+        .mint_bump(schema_mint_bump)
+        .name("Test Schema".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -286,7 +537,6 @@ async fn create_tokenized_attestation_success() {
     let name = "Test Asset".to_string();
     let uri = "https://x.com".to_string();
     let symbol = "VAT".to_string();
-    let mint_account_space = 686;
     let create_attestation_ix = CreateTokenizedAttestationBuilder::new()
         .payer(ctx.payer.pubkey())
         .authority(authority.pubkey())
@@ -307,7 +557,9 @@ async fn create_tokenized_attestation_success() {
         .name(name.clone())
         .uri(uri.clone())
         .symbol(symbol.clone())
-        .mint_account_space(mint_account_space)
+        .bump(attestation_bump)
+        .schema_mint_bump(schema_mint_bump)
+        .attestation_mint_bump(attestation_mint_bump)
         .instruction();
     let transaction = Transaction::new_signed_with_payer(
         &[create_attestation_ix],
@@ -344,14 +596,30 @@ async fn create_tokenized_attestation_success() {
         .unwrap()
         .unwrap();
 
+    // The payer tops up the mint for token metadata beyond the space funded.
     let expected_lamports = ctx
         .banks_client
         .get_rent()
         .await
         .unwrap()
-        .minimum_balance(mint_account_space.into());
+        .minimum_balance(attestation_mint_account.data.len());
     assert_eq!(attestation_mint_account.lamports, expected_lamports);
-    assert!(attestation_mint_account.data.len() <= mint_account_space.into());
+    // The mint is sized on-chain for its extensions and token metadata.
+    assert_eq!(
+        attestation_mint_account.data.len(),
+        attestation_mint_space(
+            DEFAULT_TOKEN_POLICY,
+            &name,
+            &symbol,
+            &uri,
+            &[
+                ("attestation", &attestation_pda.to_string()),
+                ("schema", &schema.to_string()),
+                ("expiry", &expiry.to_string()),
+                ("signer", &authority.pubkey().to_string()),
+            ],
+        )
+    );
 
     assert_eq!(attestation_mint_account.owner, TOKEN_2022_PROGRAM_ID);
 
@@ -399,14 +667,15 @@ async fn create_tokenized_attestation_success() {
     assert_eq!(token_metadata.name, name);
     assert_eq!(token_metadata.uri, uri);
     assert_eq!(token_metadata.symbol, symbol);
-    assert_eq!(token_metadata.additional_metadata.len(), 2);
-    assert_eq!(token_metadata.additional_metadata[0].0, "attestation");
     assert_eq!(
-        token_metadata.additional_metadata[0].1,
-        attestation_pda.to_string()
+        token_metadata.additional_metadata,
+        vec![
+            ("attestation".to_string(), attestation_pda.to_string()),
+            ("schema".to_string(), schema.to_string()),
+            ("expiry".to_string(), expiry.to_string()),
+            ("signer".to_string(), authority.pubkey().to_string()),
+        ]
     );
-    assert_eq!(token_metadata.additional_metadata[1].0, "schema");
-    assert_eq!(token_metadata.additional_metadata[1].1, schema.to_string());
 
     let recipient_token_account_data = ctx
         .banks_client
@@ -431,8 +700,11 @@ async fn close_tokenized_attestation_success() {
         authority,
         sas_pda,
         schema_mint_pda,
+        schema_mint_bump,
         attestation_pda,
+        attestation_bump,
         attestation_mint_pda,
+        attestation_mint_bump,
         recipient,
         recipient_token_account,
         nonce,
@@ -448,6 +720,10 @@ async fn close_tokenized_attestation_success() {
         .sas_pda(sas_pda)
         .max_size(100)
         .token_program(TOKEN_2022_PROGRAM_ID)
+        .mint_bump(schema_mint_bump)
+        .name("Test Schema".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
         .instruction();
 
     let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
@@ -455,7 +731,6 @@ async fn close_tokenized_attestation_success() {
     let name = "Test Asset".to_string();
     let uri = "https://x.com".to_string();
     let symbol = "VAT".to_string();
-    let mint_account_space = 686;
     let create_attestation_ix = CreateTokenizedAttestationBuilder::new()
         .payer(ctx.payer.pubkey())
         .authority(authority.pubkey())
@@ -476,7 +751,9 @@ async fn close_tokenized_attestation_success() {
         .name(name.clone())
         .uri(uri.clone())
         .symbol(symbol.clone())
-        .mint_account_space(mint_account_space)
+        .bump(attestation_bump)
+        .schema_mint_bump(schema_mint_bump)
+        .attestation_mint_bump(attestation_mint_bump)
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -507,6 +784,7 @@ async fn close_tokenized_attestation_success() {
         .sas_pda(sas_pda)
         .attestation_token_account(recipient_token_account)
         .token_program(TOKEN_2022_PROGRAM_ID)
+        .attestation_mint_bump(attestation_mint_bump)
         .instruction();
     let transaction = Transaction::new_signed_with_payer(
         &[close_attestation_ix],
@@ -558,8 +836,11 @@ async fn update_tokenized_attestation_success() {
         authority,
         sas_pda,
         schema_mint_pda,
+        schema_mint_bump,
         attestation_pda,
+        attestation_bump,
         attestation_mint_pda,
+        attestation_mint_bump,
         recipient,
         recipient_token_account,
         nonce,
@@ -575,6 +856,10 @@ async fn update_tokenized_attestation_success() {
         .sas_pda(sas_pda)
         .max_size(100)
         .token_program(TOKEN_2022_PROGRAM_ID)
+        .mint_bump(schema_mint_bump)
+        .name("Test Schema".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
         .instruction();
 
     let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
@@ -582,7 +867,6 @@ async fn update_tokenized_attestation_success() {
     let name = "Test Asset".to_string();
     let uri = "https://x.com".to_string();
     let symbol = "VAT".to_string();
-    let mint_account_space = 686;
     let create_attestation_ix = CreateTokenizedAttestationBuilder::new()
         .payer(ctx.payer.pubkey())
         .authority(authority.pubkey())
@@ -603,7 +887,9 @@ async fn update_tokenized_attestation_success() {
         .name(name.clone())
         .uri(uri.clone())
         .symbol(symbol.clone())
-        .mint_account_space(mint_account_space)
+        .bump(attestation_bump)
+        .schema_mint_bump(schema_mint_bump)
+        .attestation_mint_bump(attestation_mint_bump)
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -634,6 +920,7 @@ async fn update_tokenized_attestation_success() {
         .sas_pda(sas_pda)
         .attestation_token_account(recipient_token_account)
         .token_program(TOKEN_2022_PROGRAM_ID)
+        .attestation_mint_bump(attestation_mint_bump)
         .instruction();
     let transaction = Transaction::new_signed_with_payer(
         &[close_attestation_ix],
@@ -666,7 +953,9 @@ async fn update_tokenized_attestation_success() {
         .name(name.clone())
         .uri(uri.clone())
         .symbol(symbol.clone())
-        .mint_account_space(mint_account_space)
+        .bump(attestation_bump)
+        .schema_mint_bump(schema_mint_bump)
+        .attestation_mint_bump(attestation_mint_bump)
         .instruction();
 
     let transaction = Transaction::new_signed_with_payer(
@@ -731,14 +1020,15 @@ async fn update_tokenized_attestation_success() {
     assert_eq!(token_metadata.name, name);
     assert_eq!(token_metadata.uri, uri);
     assert_eq!(token_metadata.symbol, symbol);
-    assert_eq!(token_metadata.additional_metadata.len(), 2);
-    assert_eq!(token_metadata.additional_metadata[0].0, "attestation");
     assert_eq!(
-        token_metadata.additional_metadata[0].1,
-        attestation_pda.to_string()
+        token_metadata.additional_metadata,
+        vec![
+            ("attestation".to_string(), attestation_pda.to_string()),
+            ("schema".to_string(), schema.to_string()),
+            ("expiry".to_string(), expiry.to_string()),
+            ("signer".to_string(), authority.pubkey().to_string()),
+        ]
     );
-    assert_eq!(token_metadata.additional_metadata[1].0, "schema");
-    assert_eq!(token_metadata.additional_metadata[1].1, schema.to_string());
 
     let recipient_token_account_data = ctx
         .banks_client
@@ -753,3 +1043,1090 @@ async fn update_tokenized_attestation_success() {
     assert_eq!(token_account.mint, attestation_mint_pda);
     assert_eq!(token_account.amount, 1);
 }
+
+#[tokio::test]
+async fn create_tokenized_attestation_with_token_metadata_fields() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        authority,
+        sas_pda,
+        schema_mint_pda,
+        schema_mint_bump,
+        attestation_pda,
+        attestation_bump,
+        attestation_mint_pda,
+        attestation_mint_bump,
+        recipient,
+        recipient_token_account,
+        nonce,
+        serialized_attestation_data,
+    } = setup().await;
+
+    let change_fields_ix = ChangeSchemaTokenMetadataFieldsBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .system_program(system_program::ID)
+        .field_names(vec!["location".into(), "name".into()])
+        .instruction();
+    let tokenize_schema_ix = TokenizeSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .mint(schema_mint_pda)
+        .sas_pda(sas_pda)
+        .max_size(100)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .mint_bump(schema_mint_bump)
+        .name("Test Schema".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .instruction();
+
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let expiry: i64 = clock.unix_timestamp + 60;
+    let create_attestation_ix = CreateTokenizedAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .attestation(attestation_pda)
+        .system_program(system_program::ID)
+        .schema_mint(schema_mint_pda)
+        .attestation_mint(attestation_mint_pda)
+        .sas_pda(sas_pda)
+        .recipient_token_account(recipient_token_account)
+        .recipient(recipient)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .associated_token_program(ATA_PROGRAM_ID)
+        .data(serialized_attestation_data)
+        .expiry(expiry)
+        .nonce(nonce)
+        .name("Test Asset".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .bump(attestation_bump)
+        .schema_mint_bump(schema_mint_bump)
+        .attestation_mint_bump(attestation_mint_bump)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[change_fields_ix, tokenize_schema_ix, create_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let schema_account = ctx.banks_client.get_account(schema).await.unwrap().unwrap();
+    let schema_state = Schema::try_from_slice(&schema_account.data).unwrap();
+    assert_eq!(schema_state.token_metadata_fields, vec![1, 0]);
+
+    let attestation_mint_account = ctx
+        .banks_client
+        .get_account(attestation_mint_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let mint_state = StateWithExtensions::<Mint>::unpack(&attestation_mint_account.data).unwrap();
+    let token_metadata = mint_state
+        .get_variable_len_extension::<TokenMetadata>()
+        .unwrap();
+    // Configured fields follow the expiry and signer, in the configured order.
+    assert_eq!(
+        token_metadata.additional_metadata,
+        vec![
+            ("attestation".to_string(), attestation_pda.to_string()),
+            ("schema".to_string(), schema.to_string()),
+            ("expiry".to_string(), expiry.to_string()),
+            ("signer".to_string(), authority.pubkey().to_string()),
+            ("location".to_string(), "11".to_string()),
+            ("name".to_string(), "attest".to_string()),
+        ]
+    );
+    let expected_lamports = ctx
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(attestation_mint_account.data.len());
+    assert_eq!(attestation_mint_account.lamports, expected_lamports);
+}
+
+#[tokio::test]
+async fn change_schema_token_metadata_fields_fail() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        authority,
+        ..
+    } = setup().await;
+
+    let change_fields_ix = |field_names: Vec<String>| {
+        ChangeSchemaTokenMetadataFieldsBuilder::new()
+            .payer(ctx.payer.pubkey())
+            .authority(authority.pubkey())
+            .credential(credential)
+            .schema(schema)
+            .system_program(system_program::ID)
+            .field_names(field_names)
+            .instruction()
+    };
+
+    // Unknown field, repeated field.
+    for field_names in [
+        vec!["age".to_string()],
+        vec!["name".to_string(), "name".to_string()],
+    ] {
+        let transaction = Transaction::new_signed_with_payer(
+            &[change_fields_ix(field_names)],
+            Some(&ctx.payer.pubkey()),
+            &[&ctx.payer, &authority],
+            ctx.last_blockhash,
+        );
+        let tx_err = ctx
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(
+            tx_err,
+            TransactionError::InstructionError(0, InstructionError::Custom(35))
+        );
+    }
+}
+
+/// Tokenize the Schema with `token_policy` and create a tokenized Attestation
+/// held by `recipient`.
+async fn tokenize_with_policy(
+    fixtures: &TestFixtures,
+    token_policy: u8,
+    recipient: Pubkey,
+    expiry: i64,
+) -> Pubkey {
+    let recipient_token_account = get_associated_token_address_with_program_id(
+        &recipient,
+        &fixtures.attestation_mint_pda,
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    let tokenize_schema_ix = TokenizeSchemaBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .mint(fixtures.schema_mint_pda)
+        .sas_pda(fixtures.sas_pda)
+        .max_size(100)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .mint_bump(fixtures.schema_mint_bump)
+        .name("Test Schema".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .token_policy(token_policy)
+        .instruction();
+    let create_attestation_ix = CreateTokenizedAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(fixtures.attestation_pda)
+        .system_program(system_program::ID)
+        .schema_mint(fixtures.schema_mint_pda)
+        .attestation_mint(fixtures.attestation_mint_pda)
+        .sas_pda(fixtures.sas_pda)
+        .recipient_token_account(recipient_token_account)
+        .recipient(recipient)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .associated_token_program(ATA_PROGRAM_ID)
+        .data(fixtures.serialized_attestation_data.clone())
+        .expiry(expiry)
+        .nonce(fixtures.nonce)
+        .name("Test Asset".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .bump(fixtures.attestation_bump)
+        .schema_mint_bump(fixtures.schema_mint_bump)
+        .attestation_mint_bump(fixtures.attestation_mint_bump)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[tokenize_schema_ix, create_attestation_ix],
+        Some(&fixtures.ctx.payer.pubkey()),
+        &[&fixtures.ctx.payer, &fixtures.authority],
+        fixtures.ctx.last_blockhash,
+    );
+    fixtures
+        .ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    recipient_token_account
+}
+
+fn close_tokenized_attestation_tx(fixtures: &TestFixtures, token_account: Pubkey) -> Transaction {
+    let (event_auth_pda, _bump) =
+        Pubkey::find_program_address(&[b"__event_authority"], &SOLANA_ATTESTATION_SERVICE_ID);
+    let close_attestation_ix = CloseTokenizedAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .attestation(fixtures.attestation_pda)
+        .event_authority(event_auth_pda)
+        .system_program(system_program::ID)
+        .attestation_program(SOLANA_ATTESTATION_SERVICE_ID)
+        .attestation_mint(fixtures.attestation_mint_pda)
+        .sas_pda(fixtures.sas_pda)
+        .attestation_token_account(token_account)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .attestation_mint_bump(fixtures.attestation_mint_bump)
+        .instruction();
+    Transaction::new_signed_with_payer(
+        &[close_attestation_ix],
+        Some(&fixtures.ctx.payer.pubkey()),
+        &[&fixtures.ctx.payer, &fixtures.authority],
+        fixtures.ctx.last_blockhash,
+    )
+}
+
+#[tokio::test]
+async fn tokenize_schema_with_transferable_token_policy() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let holder = Keypair::new();
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let holder_token_account =
+        tokenize_with_policy(&fixtures, 0, holder.pubkey(), clock.unix_timestamp + 60).await;
+
+    let schema_account = ctx
+        .banks_client
+        .get_account(fixtures.schema)
+        .await
+        .unwrap()
+        .unwrap();
+    let schema_state = Schema::try_from_slice(&schema_account.data).unwrap();
+    assert_eq!(schema_state.token_policy, 0);
+
+    // Verify the mint has neither the NonTransferable nor the PermanentDelegate
+    // extension.
+    let mint_account = ctx
+        .banks_client
+        .get_account(fixtures.attestation_mint_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let mint_state = StateWithExtensions::<Mint>::unpack(&mint_account.data).unwrap();
+    assert!(mint_state.get_extension::<NonTransferable>().is_err());
+    assert!(mint_state.get_extension::<PermanentDelegate>().is_err());
+    assert!(mint_state.get_extension::<MintCloseAuthority>().is_ok());
+
+    // The holder transfers the token to another wallet, which burns it.
+    let new_holder = Keypair::new();
+    let new_holder_token_account = get_associated_token_address_with_program_id(
+        &new_holder.pubkey(),
+        &fixtures.attestation_mint_pda,
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            create_associated_token_account(
+                &ctx.payer.pubkey(),
+                &new_holder.pubkey(),
+                &fixtures.attestation_mint_pda,
+                &TOKEN_2022_PROGRAM_ID,
+            ),
+            transfer_checked(
+                &TOKEN_2022_PROGRAM_ID,
+                &holder_token_account,
+                &fixtures.attestation_mint_pda,
+                &new_holder_token_account,
+                &holder.pubkey(),
+                &[],
+                1,
+                0,
+            )
+            .unwrap(),
+            burn_checked(
+                &TOKEN_2022_PROGRAM_ID,
+                &new_holder_token_account,
+                &fixtures.attestation_mint_pda,
+                &new_holder.pubkey(),
+                &[],
+                1,
+                0,
+            )
+            .unwrap(),
+        ],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &holder, &new_holder],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // The Attestation closes without burning the already burned token.
+    ctx.banks_client
+        .process_transaction(close_tokenized_attestation_tx(
+            &fixtures,
+            new_holder_token_account,
+        ))
+        .await
+        .unwrap();
+    for account in [fixtures.attestation_pda, fixtures.attestation_mint_pda] {
+        assert!(ctx
+            .banks_client
+            .get_account(account)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
+
+#[tokio::test]
+async fn close_tokenized_attestation_without_permanent_delegate_freezes_token() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let holder = Keypair::new();
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let holder_token_account = tokenize_with_policy(
+        &fixtures,
+        TOKEN_POLICY_NON_TRANSFERABLE,
+        holder.pubkey(),
+        clock.unix_timestamp + 60,
+    )
+    .await;
+
+    // The holder does not burn the token, yet the issuer can revoke the
+    // Attestation.
+    ctx.banks_client
+        .process_transaction(close_tokenized_attestation_tx(
+            &fixtures,
+            holder_token_account,
+        ))
+        .await
+        .unwrap();
+    assert!(ctx
+        .banks_client
+        .get_account(fixtures.attestation_pda)
+        .await
+        .unwrap()
+        .is_none());
+
+    // The token is frozen since it cannot be burned, and its mint stays open.
+    assert!(ctx
+        .banks_client
+        .get_account(fixtures.attestation_mint_pda)
+        .await
+        .unwrap()
+        .is_some());
+    let token_account_data = ctx
+        .banks_client
+        .get_account(holder_token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let token_account_state = Account::unpack(&token_account_data.data[..Account::LEN]).unwrap();
+    assert_eq!(token_account_state.amount, 1);
+    assert_eq!(token_account_state.state, AccountState::Frozen);
+
+    // The holder can no longer burn the revoked token.
+    let transaction = Transaction::new_signed_with_payer(
+        &[burn_checked(
+            &TOKEN_2022_PROGRAM_ID,
+            &holder_token_account,
+            &fixtures.attestation_mint_pda,
+            &holder.pubkey(),
+            &[],
+            1,
+            0,
+        )
+        .unwrap()],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &holder],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::AccountFrozen as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn freeze_expired_attestation_token_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let token_account = tokenize_with_policy(
+        &fixtures,
+        TOKEN_POLICY_NON_TRANSFERABLE
+            | TOKEN_POLICY_PERMANENT_DELEGATE
+            | TOKEN_POLICY_FREEZE_ON_EXPIRY,
+        fixtures.recipient,
+        clock.unix_timestamp + 60,
+    )
+    .await;
+
+    let freeze_ix = FreezeExpiredAttestationTokenBuilder::new()
+        .attestation(fixtures.attestation_pda)
+        .schema(fixtures.schema)
+        .attestation_mint(fixtures.attestation_mint_pda)
+        .sas_pda(fixtures.sas_pda)
+        .attestation_token_account(token_account)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .attestation_mint_bump(fixtures.attestation_mint_bump)
+        .instruction();
+
+    // The Attestation has not expired yet.
+    let transaction = Transaction::new_signed_with_payer(
+        &[freeze_ix.clone()],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(37))
+    );
+
+    clock.unix_timestamp += 120;
+    ctx.set_sysvar(&clock);
+
+    // Sign with a new blockhash so the transaction is not a duplicate.
+    let blockhash = ctx
+        .banks_client
+        .get_new_latest_blockhash(&ctx.last_blockhash)
+        .await
+        .unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[freeze_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let token_account_data = ctx
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let token_account_state = Account::unpack(&token_account_data.data[..Account::LEN]).unwrap();
+    assert_eq!(token_account_state.state, AccountState::Frozen);
+
+    // Closing the Attestation thaws and burns the frozen token.
+    ctx.banks_client
+        .process_transaction(close_tokenized_attestation_tx(&fixtures, token_account))
+        .await
+        .unwrap();
+    for account in [fixtures.attestation_pda, fixtures.attestation_mint_pda] {
+        assert!(ctx
+            .banks_client
+            .get_account(account)
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
+
+#[tokio::test]
+async fn freeze_expired_attestation_token_fail_policy() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let token_account = tokenize_with_policy(
+        &fixtures,
+        TOKEN_POLICY_NON_TRANSFERABLE | TOKEN_POLICY_PERMANENT_DELEGATE,
+        fixtures.recipient,
+        clock.unix_timestamp + 60,
+    )
+    .await;
+
+    clock.unix_timestamp += 120;
+    ctx.set_sysvar(&clock);
+
+    let freeze_ix = FreezeExpiredAttestationTokenBuilder::new()
+        .attestation(fixtures.attestation_pda)
+        .schema(fixtures.schema)
+        .attestation_mint(fixtures.attestation_mint_pda)
+        .sas_pda(fixtures.sas_pda)
+        .attestation_token_account(token_account)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .attestation_mint_bump(fixtures.attestation_mint_bump)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[freeze_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(36))
+    );
+}
+
+#[tokio::test]
+async fn tokenize_schema_fail_invalid_token_policy() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        authority,
+        sas_pda,
+        schema_mint_pda,
+        schema_mint_bump,
+        ..
+    } = setup().await;
+
+    let tokenize_schema_ix = TokenizeSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .mint(schema_mint_pda)
+        .sas_pda(sas_pda)
+        .max_size(100)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .mint_bump(schema_mint_bump)
+        .name("Test Schema".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .token_policy(1 << 3)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[tokenize_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(36))
+    );
+}
+
+#[tokio::test]
+async fn tokenize_schema_fail_non_canonical_mint_bump() {
+    let TestFixtures {
+        ctx,
+        credential,
+        schema,
+        authority,
+        sas_pda,
+        ..
+    } = setup().await;
+
+    // A valid program address, but not the canonical one for the seeds.
+    let (schema_mint_pda, schema_mint_bump) =
+        non_canonical_program_address(&[b"schemaMint", &schema.to_bytes()]);
+
+    let tokenize_schema_ix = TokenizeSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(authority.pubkey())
+        .credential(credential)
+        .schema(schema)
+        .mint(schema_mint_pda)
+        .sas_pda(sas_pda)
+        .max_size(100)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .mint_bump(schema_mint_bump)
+        .name("Test Schema".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[tokenize_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(8))
+    );
+}
+
+#[tokio::test]
+async fn update_schema_token_metadata_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    tokenize_with_policy(
+        &fixtures,
+        TOKEN_POLICY_NON_TRANSFERABLE | TOKEN_POLICY_PERMANENT_DELEGATE,
+        fixtures.recipient,
+        clock.unix_timestamp + 60,
+    )
+    .await;
+
+    let name = "Renamed Schema Collection".to_string();
+    let uri = "https://example.com/collection.json".to_string();
+    let symbol = "RSC".to_string();
+    let update_metadata_ix = UpdateSchemaTokenMetadataBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .mint(fixtures.schema_mint_pda)
+        .sas_pda(fixtures.sas_pda)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .name(name.clone())
+        .uri(uri.clone())
+        .symbol(symbol.clone())
+        .mint_bump(fixtures.schema_mint_bump)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[update_metadata_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let mint_account = ctx
+        .banks_client
+        .get_account(fixtures.schema_mint_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let mint_state = StateWithExtensions::<Mint>::unpack(&mint_account.data).unwrap();
+    let token_metadata = mint_state
+        .get_variable_len_extension::<TokenMetadata>()
+        .unwrap();
+    assert_eq!(token_metadata.name, name);
+    assert_eq!(token_metadata.uri, uri);
+    assert_eq!(token_metadata.symbol, symbol);
+    assert_eq!(token_metadata.additional_metadata.len(), 3);
+
+    // The payer tops up the mint for the longer metadata.
+    let expected_lamports = ctx
+        .banks_client
+        .get_rent()
+        .await
+        .unwrap()
+        .minimum_balance(mint_account.data.len());
+    assert_eq!(mint_account.lamports, expected_lamports);
+}
+
+#[tokio::test]
+async fn update_schema_token_metadata_fail_wrong_authority() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    tokenize_with_policy(
+        &fixtures,
+        TOKEN_POLICY_NON_TRANSFERABLE | TOKEN_POLICY_PERMANENT_DELEGATE,
+        fixtures.recipient,
+        clock.unix_timestamp + 60,
+    )
+    .await;
+
+    let other_authority = Keypair::new();
+    let update_metadata_ix = UpdateSchemaTokenMetadataBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(other_authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .mint(fixtures.schema_mint_pda)
+        .sas_pda(fixtures.sas_pda)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .name("Name".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("SYM".to_string())
+        .mint_bump(fixtures.schema_mint_bump)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[update_metadata_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &other_authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+fn tokenize_attestation_ix(fixtures: &TestFixtures) -> Instruction {
+    TokenizeAttestationBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(fixtures.attestation_pda)
+        .system_program(system_program::ID)
+        .schema_mint(fixtures.schema_mint_pda)
+        .attestation_mint(fixtures.attestation_mint_pda)
+        .sas_pda(fixtures.sas_pda)
+        .recipient_token_account(fixtures.recipient_token_account)
+        .recipient(fixtures.recipient)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .associated_token_program(ATA_PROGRAM_ID)
+        .name("Test Asset".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .schema_mint_bump(fixtures.schema_mint_bump)
+        .attestation_mint_bump(fixtures.attestation_mint_bump)
+        .instruction()
+}
+
+#[tokio::test]
+async fn tokenize_attestation_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    // Create the Attestation before its Schema is tokenized.
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let expiry: i64 = clock.unix_timestamp + 60;
+    let create_attestation_ix = CreateAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(fixtures.attestation_pda)
+        .system_program(system_program::ID)
+        .data(fixtures.serialized_attestation_data.clone())
+        .expiry(expiry)
+        .nonce(fixtures.nonce)
+        .bump(fixtures.attestation_bump)
+        .instruction();
+    let tokenize_schema_ix = TokenizeSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .mint(fixtures.schema_mint_pda)
+        .sas_pda(fixtures.sas_pda)
+        .max_size(100)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .mint_bump(fixtures.schema_mint_bump)
+        .name("Test Schema".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_attestation_ix, tokenize_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[tokenize_attestation_ix(&fixtures)],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // Verify the Attestation records the token account.
+    let attestation_account = ctx
+        .banks_client
+        .get_account(fixtures.attestation_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let attestation = Attestation::try_from_slice(&attestation_account.data).unwrap();
+    assert_eq!(attestation.token_account, fixtures.recipient_token_account);
+    assert_eq!(attestation.expiry, expiry);
+
+    // Verify the mint is a member of the Schema group with token metadata.
+    let attestation_mint_account = ctx
+        .banks_client
+        .get_account(fixtures.attestation_mint_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        attestation_mint_account.data.len(),
+        attestation_mint_space(
+            DEFAULT_TOKEN_POLICY,
+            "Test Asset",
+            "VAT",
+            "https://x.com",
+            &[
+                ("attestation", &fixtures.attestation_pda.to_string()),
+                ("schema", &fixtures.schema.to_string()),
+                ("expiry", &expiry.to_string()),
+                ("signer", &fixtures.authority.pubkey().to_string()),
+            ],
+        )
+    );
+    let mint_state = StateWithExtensions::<Mint>::unpack(&attestation_mint_account.data).unwrap();
+    assert_eq!(mint_state.base.supply, 1);
+    let token_group_member = mint_state.get_extension::<TokenGroupMember>().unwrap();
+    assert_eq!(token_group_member.group, fixtures.schema_mint_pda);
+    let token_metadata = mint_state
+        .get_variable_len_extension::<TokenMetadata>()
+        .unwrap();
+    assert_eq!(
+        token_metadata.additional_metadata,
+        vec![
+            (
+                "attestation".to_string(),
+                fixtures.attestation_pda.to_string()
+            ),
+            ("schema".to_string(), fixtures.schema.to_string()),
+            ("expiry".to_string(), expiry.to_string()),
+            (
+                "signer".to_string(),
+                fixtures.authority.pubkey().to_string()
+            ),
+        ]
+    );
+
+    // Verify the recipient holds the token.
+    let recipient_token_account_data = ctx
+        .banks_client
+        .get_account(fixtures.recipient_token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let token_account =
+        Account::unpack(&recipient_token_account_data.data[..Account::LEN]).unwrap();
+    assert_eq!(token_account.mint, fixtures.attestation_mint_pda);
+    assert_eq!(token_account.owner, fixtures.recipient);
+    assert_eq!(token_account.amount, 1);
+
+    // The Attestation is now closed with its token.
+    ctx.banks_client
+        .process_transaction(close_tokenized_attestation_tx(
+            &fixtures,
+            fixtures.recipient_token_account,
+        ))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn tokenize_attestation_fail_already_tokenized() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    tokenize_with_policy(
+        &fixtures,
+        DEFAULT_TOKEN_POLICY,
+        fixtures.recipient,
+        clock.unix_timestamp + 60,
+    )
+    .await;
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[tokenize_attestation_ix(&fixtures)],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(38))
+    );
+}
+
+#[tokio::test]
+async fn tokenize_attestation_fail_expired() {
+    let mut fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let create_attestation_ix = CreateAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(fixtures.attestation_pda)
+        .system_program(system_program::ID)
+        .data(fixtures.serialized_attestation_data.clone())
+        .expiry(clock.unix_timestamp + 60)
+        .nonce(fixtures.nonce)
+        .bump(fixtures.attestation_bump)
+        .instruction();
+    let tokenize_schema_ix = TokenizeSchemaBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .mint(fixtures.schema_mint_pda)
+        .sas_pda(fixtures.sas_pda)
+        .max_size(100)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .mint_bump(fixtures.schema_mint_bump)
+        .name("Test Schema".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_attestation_ix, tokenize_schema_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // An expired Attestation cannot be tokenized, as it could not be created
+    // with a token.
+    clock.unix_timestamp += 120;
+    fixtures.ctx.set_sysvar(&clock);
+    fixtures.ctx.last_blockhash = fixtures.ctx.get_new_latest_blockhash().await.unwrap();
+    let ctx = &fixtures.ctx;
+    let transaction = Transaction::new_signed_with_payer(
+        &[tokenize_attestation_ix(&fixtures)],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(6))
+    );
+}
+
+#[tokio::test]
+async fn reissued_tokenized_attestation_metadata_mirrors_new_expiry() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    let token_account = tokenize_with_policy(
+        &fixtures,
+        DEFAULT_TOKEN_POLICY,
+        fixtures.recipient,
+        clock.unix_timestamp + 60,
+    )
+    .await;
+
+    // Attestations cannot be updated, so a new expiry is issued by closing
+    // the Attestation and creating it again, which mints a new token.
+    ctx.banks_client
+        .process_transaction(close_tokenized_attestation_tx(&fixtures, token_account))
+        .await
+        .unwrap();
+    let expiry = clock.unix_timestamp + 120;
+    let create_attestation_ix = CreateTokenizedAttestationBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .authority(fixtures.authority.pubkey())
+        .credential(fixtures.credential)
+        .schema(fixtures.schema)
+        .attestation(fixtures.attestation_pda)
+        .system_program(system_program::ID)
+        .schema_mint(fixtures.schema_mint_pda)
+        .attestation_mint(fixtures.attestation_mint_pda)
+        .sas_pda(fixtures.sas_pda)
+        .recipient_token_account(token_account)
+        .recipient(fixtures.recipient)
+        .token_program(TOKEN_2022_PROGRAM_ID)
+        .associated_token_program(ATA_PROGRAM_ID)
+        .data(fixtures.serialized_attestation_data.clone())
+        .expiry(expiry)
+        .nonce(fixtures.nonce)
+        .name("Test Asset".to_string())
+        .uri("https://x.com".to_string())
+        .symbol("VAT".to_string())
+        .bump(fixtures.attestation_bump)
+        .schema_mint_bump(fixtures.schema_mint_bump)
+        .attestation_mint_bump(fixtures.attestation_mint_bump)
+        .instruction();
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_attestation_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    // The new token's metadata is written from the new Attestation.
+    let attestation_mint_account = ctx
+        .banks_client
+        .get_account(fixtures.attestation_mint_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let mint_state = StateWithExtensions::<Mint>::unpack(&attestation_mint_account.data).unwrap();
+    let token_metadata = mint_state
+        .get_variable_len_extension::<TokenMetadata>()
+        .unwrap();
+    assert!(token_metadata
+        .additional_metadata
+        .contains(&("expiry".to_string(), expiry.to_string())));
+}
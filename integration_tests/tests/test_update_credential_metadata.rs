@@ -0,0 +1,212 @@
+use borsh::BorshDeserialize;
+use helpers::program_test_context;
+use solana_attestation_service_client::{
+    accounts::Credential,
+    instructions::{CreateCredentialBuilder, UpdateCredentialMetadataBuilder},
+};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::{Transaction, TransactionError},
+};
+
+mod helpers;
+
+struct TestFixtures {
+    ctx: ProgramTestContext,
+    credential: Pubkey,
+    authority: Keypair,
+}
+
+async fn setup() -> TestFixtures {
+    let ctx = program_test_context().await;
+    let authority = Keypair::new();
+    let credential_name = "test";
+    let (credential_pda, credential_bump) = Pubkey::find_program_address(
+        &[
+            b"credential",
+            &authority.pubkey().to_bytes(),
+            credential_name.as_bytes(),
+        ],
+        &Pubkey::from(solana_attestation_service_client::programs::SOLANA_ATTESTATION_SERVICE_ID),
+    );
+
+    let create_credential_ix = CreateCredentialBuilder::new()
+        .payer(ctx.payer.pubkey())
+        .credential(credential_pda)
+        .authority(authority.pubkey())
+        .system_program(system_program::ID)
+        .name(credential_name.to_string())
+        .signers(vec![authority.pubkey()])
+        .bump(credential_bump)
+        .instruction();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_credential_ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    TestFixtures {
+        ctx,
+        credential: credential_pda,
+        authority,
+    }
+}
+
+fn update_metadata_ix(
+    fixtures: &TestFixtures,
+    authority: &Pubkey,
+    display_name: &str,
+    website: &str,
+) -> Instruction {
+    UpdateCredentialMetadataBuilder::new()
+        .payer(fixtures.ctx.payer.pubkey())
+        .authority(*authority)
+        .credential(fixtures.credential)
+        .system_program(system_program::ID)
+        .display_name(display_name.to_string())
+        .description("Issues test attestations".to_string())
+        .uri("https://example.com/profile.json".to_string())
+        .logo_uri("https://example.com/logo.png".to_string())
+        .website(website.to_string())
+        .instruction()
+}
+
+async fn get_credential(fixtures: &TestFixtures) -> Credential {
+    let credential_account = fixtures
+        .ctx
+        .banks_client
+        .get_account(fixtures.credential)
+        .await
+        .expect("get_account")
+        .expect("account not none");
+    Credential::try_from_slice(&credential_account.data).unwrap()
+}
+
+#[tokio::test]
+async fn update_credential_metadata_success() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let credential = get_credential(&fixtures).await;
+    assert!(credential.display_name.is_empty());
+    assert!(credential.description.is_empty());
+
+    let ix = update_metadata_ix(
+        &fixtures,
+        &fixtures.authority.pubkey(),
+        "Test Organization",
+        "https://example.com",
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let credential = get_credential(&fixtures).await;
+    assert_eq!(credential.name, b"test");
+    assert_eq!(
+        credential.authorized_signers,
+        vec![fixtures.authority.pubkey()]
+    );
+    assert_eq!(credential.display_name, b"Test Organization");
+    assert_eq!(credential.description, b"Issues test attestations");
+    assert_eq!(credential.uri, b"https://example.com/profile.json");
+    assert_eq!(credential.logo_uri, b"https://example.com/logo.png");
+    assert_eq!(credential.website, b"https://example.com");
+
+    // Shrink the metadata.
+    let ix = update_metadata_ix(&fixtures, &fixtures.authority.pubkey(), "Test", "");
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    ctx.banks_client
+        .process_transaction(transaction)
+        .await
+        .unwrap();
+
+    let credential = get_credential(&fixtures).await;
+    assert_eq!(credential.display_name, b"Test");
+    assert_eq!(credential.uri, b"https://example.com/profile.json");
+    assert!(credential.website.is_empty());
+}
+
+#[tokio::test]
+async fn update_credential_metadata_fail_wrong_authority() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+    let other_authority = Keypair::new();
+
+    let ix = update_metadata_ix(
+        &fixtures,
+        &other_authority.pubkey(),
+        "Test Organization",
+        "https://example.com",
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &other_authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::IncorrectAuthority)
+    );
+}
+
+#[tokio::test]
+async fn update_credential_metadata_fail_too_long() {
+    let fixtures = setup().await;
+    let ctx = &fixtures.ctx;
+
+    let ix = update_metadata_ix(
+        &fixtures,
+        &fixtures.authority.pubkey(),
+        &"a".repeat(65),
+        "https://example.com",
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        &[&ctx.payer, &fixtures.authority],
+        ctx.last_blockhash,
+    );
+    let tx_err = ctx
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .err()
+        .expect("should error")
+        .unwrap();
+    assert_eq!(
+        tx_err,
+        TransactionError::InstructionError(0, InstructionError::Custom(17))
+    );
+}
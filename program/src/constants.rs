@@ -1,5 +1,6 @@
 use const_crypto::ed25519;
 use pinocchio::pubkey::Pubkey;
+use pinocchio_pubkey::pubkey;
 
 pub const ATTESTATION_SEED: &[u8] = b"attestation";
 pub const CREDENTIAL_SEED: &[u8] = b"credential";
@@ -8,6 +9,47 @@ pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
 pub const SAS_SEED: &[u8] = b"sas";
 pub const SCHEMA_MINT_SEED: &[u8] = b"schemaMint";
 pub const ATTESTATION_MINT_SEED: &[u8] = b"attestationMint";
+pub const MERKLE_BATCH_SEED: &[u8] = b"merkleBatch";
+pub const ATTESTATION_REQUEST_SEED: &[u8] = b"attestationRequest";
+pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const RESOLVER_AUTHORITY_SEED: &[u8] = b"resolverAuthority";
+pub const USED_MESSAGE_SEED: &[u8] = b"usedMessage";
+
+pub const ED25519_PROGRAM_ID: Pubkey = pubkey!("Ed25519SigVerify111111111111111111111111111");
+pub const SECP256K1_PROGRAM_ID: Pubkey = pubkey!("KeccakSecp256k11111111111111111111111111111");
+pub const SECP256R1_PROGRAM_ID: Pubkey = pubkey!("Secp256r1SigVerify1111111111111111111111111");
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const INSTRUCTIONS_SYSVAR_ID: Pubkey = pubkey!("Sysvar1nstructions1111111111111111111111111");
+
+// Prefixed to messages signed off-chain so they cannot be mistaken for
+// messages of other programs or instructions.
+pub const ATTESTATION_MESSAGE_DOMAIN: &[u8] = b"SAS_CREATE_ATTESTATION";
+// EIP-191 personal message prefix, followed by the decimal message length.
+pub const EIP191_MESSAGE_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n";
+
+// Credential and Schema names are PDA seeds, which are limited to 32 bytes.
+pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_DESCRIPTION_LEN: usize = 512;
+pub const MAX_FIELD_NAME_LEN: usize = 64;
+pub const MAX_DISPLAY_NAME_LEN: usize = 64;
+pub const MAX_URI_LEN: usize = 200;
+
+// Additional token metadata keys of tokenized Attestations. Schema fields
+// mirrored into token metadata cannot use them as names.
+pub const ATTESTATION_METADATA_KEY: &str = "attestation";
+pub const SCHEMA_METADATA_KEY: &str = "schema";
+pub const EXPIRY_METADATA_KEY: &str = "expiry";
+pub const SIGNER_METADATA_KEY: &str = "signer";
+pub const RESERVED_METADATA_KEYS: [&str; 4] = [
+    ATTESTATION_METADATA_KEY,
+    SCHEMA_METADATA_KEY,
+    EXPIRY_METADATA_KEY,
+    SIGNER_METADATA_KEY,
+];
+
+// Additional token metadata keys of tokenized Schemas' group mints.
+pub const CREDENTIAL_METADATA_KEY: &str = "credential";
+pub const VERSION_METADATA_KEY: &str = "version";
 
 // Anchor Compatitable Discriminator: Sha256(anchor:event)[..8]
 pub const EVENT_IX_TAG: u64 = 0x1d9acb512ea545e4;
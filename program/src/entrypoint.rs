@@ -24,10 +24,28 @@ pub fn process_instruction(
         4 => process_change_schema_description(program_id, accounts, instruction_data),
         5 => process_change_schema_version(program_id, accounts, instruction_data),
         6 => process_create_attestation(program_id, accounts, instruction_data, None),
-        7 => process_close_attestation(program_id, accounts, None),
+        7 => process_close_attestation(program_id, accounts, false),
         9 => process_tokenize_schema(program_id, accounts, instruction_data),
         10 => process_create_tokenized_attestation(program_id, accounts, instruction_data),
-        11 => process_close_tokenized_attestation(program_id, accounts),
+        11 => process_close_tokenized_attestation(program_id, accounts, instruction_data),
+        13 => process_migrate_account(program_id, accounts, instruction_data),
+        14 => process_update_credential_metadata(program_id, accounts, instruction_data),
+        15 => process_change_credential_status(program_id, accounts, instruction_data),
+        16 => process_create_merkle_attestation_batch(program_id, accounts, instruction_data),
+        17 => process_verify_merkle_attestation(program_id, accounts, instruction_data),
+        18 => process_revoke_merkle_attestation_batch(program_id, accounts),
+        19 => process_create_attestation_with_signature(program_id, accounts, instruction_data),
+        20 => process_change_passkey_signers(program_id, accounts, instruction_data),
+        21 => process_create_attestation_with_passkey(program_id, accounts, instruction_data),
+        22 => process_create_attestation_request(program_id, accounts, instruction_data),
+        23 => process_approve_attestation_request(program_id, accounts, instruction_data),
+        24 => process_reject_attestation_request(program_id, accounts),
+        25 => process_change_schema_fee(program_id, accounts, instruction_data),
+        26 => process_withdraw_treasury(program_id, accounts, instruction_data),
+        27 => process_change_schema_token_metadata_fields(program_id, accounts, instruction_data),
+        28 => process_freeze_expired_attestation_token(program_id, accounts, instruction_data),
+        29 => process_update_schema_token_metadata(program_id, accounts, instruction_data),
+        30 => process_tokenize_attestation(program_id, accounts, instruction_data),
         228 => process_emit_event(program_id, accounts), // matches EVENT_IX_TAG[0]
         _ => Err(ProgramError::InvalidInstructionData),
     }
@@ -27,6 +27,67 @@ pub enum AttestationServiceError {
     InvalidTokenAccount,
     // 11 Schema is paused
     SchemaPaused,
+    // 12 Schema field constraint is malformed or does not apply to the field type
+    InvalidFieldConstraint,
+    // 13 Attestation data does not satisfy a Schema field constraint, the
+    // index of the field is logged
+    FieldConstraintViolation,
+    // 14 Name, description or field name is not valid UTF-8
+    InvalidUtf8,
+    // 15 Name or field name is empty
+    EmptyName,
+    // 16 Schema field names are not unique
+    DuplicateFieldName,
+    // 17 Name, description or field name exceeds its maximum length
+    ValueTooLong,
+    // 18 Account data ended before all fields were read
+    UnexpectedEndOfData,
+    // 19 Offset or length arithmetic overflowed
+    ArithmeticOverflow,
+    // 20 Account uses a legacy layout and must be migrated first
+    AccountNotMigrated,
+    // 21 Credential is paused
+    CredentialPaused,
+    // 22 Incorrect Schema commitment scheme
+    InvalidCommitmentScheme,
+    // 23 Merkle proof does not match the batch root
+    InvalidMerkleProof,
+    // 24 Attestation has expired
+    AttestationExpired,
+    // 25 Signature verification instruction is missing or does not match
+    InvalidSignature,
+    // 26 Signed message deadline has passed
+    SignatureExpired,
+    // 27 Passkey is not a compressed secp256r1 public key
+    InvalidPasskey,
+    // 28 WebAuthn assertion is not over the attestation message
+    InvalidWebAuthnAssertion,
+    // 29 Referenced Attestation is not an open Attestation of the required Schema
+    InvalidRefAttestation,
+    // 30 Attestation is referenced and its Schema blocks closing it
+    AttestationReferenced,
+    // 31 Incorrect Schema ref close policy
+    InvalidRefClosePolicy,
+    // 32 Incorrect AttestationRequest account
+    InvalidAttestationRequest,
+    // 33 Fee treasury does not match the Schema's or the Credential's treasury
+    InvalidTreasury,
+    // 34 Resolver program or resolver authority does not match the Schema's
+    InvalidResolver,
+    // 35 Token metadata field is not a plaintext Schema field, is repeated or uses a reserved key
+    InvalidTokenMetadataField,
+    // 36 Token policy has unknown flags or does not allow the operation
+    InvalidTokenPolicy,
+    // 37 Attestation has not expired
+    AttestationNotExpired,
+    // 38 Attestation already has a token
+    AttestationAlreadyTokenized,
+    // 39 Incorrect used message account
+    InvalidUsedMessage,
+    // 40 Signed attestation message was already used
+    MessageAlreadyUsed,
+    // 41 Authorized signer kind is missing, unknown or does not match the signer
+    InvalidSignerKind,
 }
 
 impl From<AttestationServiceError> for ProgramError {
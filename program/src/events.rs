@@ -19,6 +19,8 @@ pub struct CloseAttestationEvent {
     pub schema: Pubkey,
     /// Data that was verified and matches the Schema
     pub attestation_data: Vec<u8>,
+    /// Number of open Attestations that still referenced the closed one
+    pub ref_count: u32,
 }
 
 impl CloseAttestationEvent {
@@ -30,6 +32,7 @@ impl CloseAttestationEvent {
         data.extend_from_slice(self.schema.as_ref());
         data.extend_from_slice(&(self.attestation_data.len() as u32).to_le_bytes());
         data.extend_from_slice(&self.attestation_data);
+        data.extend_from_slice(&self.ref_count.to_le_bytes());
 
         data
     }
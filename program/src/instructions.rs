@@ -12,13 +12,32 @@ use shank::ShankInstruction;
 #[derive(Clone, Debug, PartialEq, ShankInstruction)]
 pub enum AttestationServiceInstruction {
     /// Creates the Credential PDA account for an Issuer.
+    /// `signer_kinds` holds the SignerKind of each of `signers`: 0 for an
+    /// ed25519 key, 1 for a zero-padded Ethereum address. Empty means every
+    /// signer is an ed25519 key.
     #[account(0, writable, signer, name = "payer")]
     #[account(1, writable, name = "credential")]
     #[account(2, signer, name = "authority")]
     #[account(3, name = "system_program")]
-    CreateCredential { name: String, signers: Vec<Pubkey> } = 0,
+    CreateCredential {
+        name: String,
+        signers: Vec<Pubkey>,
+        bump: u8,
+        signer_kinds: Vec<u8>,
+    } = 0,
 
     /// Create a Schema for a Credential that can eventually be attested to.
+    /// `commitment_scheme` 0 stores Attestation data in plaintext, 1 stores
+    /// a sha256 commitment to it, 2 stores a salted sha256 hash per field and
+    /// 3 stores a Poseidon commitment computed by the client.
+    /// A non-default `ref_schema` requires Attestations to reference an
+    /// Attestation of that Schema. `ref_close_policy` 1 (the default) allows
+    /// closing Attestations of this Schema while they are referenced and
+    /// reports the open references in the close event, 0 blocks it.
+    /// A non-default `resolver_program` is invoked when Attestations of this
+    /// Schema are created or closed, and can reject them.
+    /// `field_constraints` are serialized `FieldConstraint` entries checked
+    /// against Attestation data. They are fixed for the life of the Schema.
     #[account(0, writable, signer, name = "payer")]
     #[account(1, signer, name = "authority")]
     #[account(
@@ -33,6 +52,12 @@ pub enum AttestationServiceInstruction {
         description: String,
         layout: Vec<u8>,
         field_names: Vec<String>,
+        bump: u8,
+        commitment_scheme: u8,
+        ref_schema: Pubkey,
+        ref_close_policy: u8,
+        resolver_program: Pubkey,
+        field_constraints: Vec<u8>,
     } = 1,
 
     /// Sets Schema is_paused status
@@ -50,7 +75,8 @@ pub enum AttestationServiceInstruction {
     )]
     ChangeSchemaStatus { is_paused: bool } = 2,
 
-    /// Sets Credential authorized_signers
+    /// Sets Credential authorized_signers, with `signer_kinds` as in
+    /// CreateCredential.
     #[account(0, writable, signer, name = "payer")]
     #[account(1, signer, name = "authority")]
     #[account(
@@ -60,7 +86,10 @@ pub enum AttestationServiceInstruction {
         desc = "Credential the Schema is associated with"
     )]
     #[account(3, name = "system_program")]
-    ChangeAuthorizedSigners { signers: Vec<Pubkey> } = 3,
+    ChangeAuthorizedSigners {
+        signers: Vec<Pubkey>,
+        signer_kinds: Vec<u8>,
+    } = 3,
 
     /// Change description on a Schema
     #[account(0, writable, signer, name = "payer")]
@@ -79,7 +108,7 @@ pub enum AttestationServiceInstruction {
     #[account(4, name = "system_program")]
     ChangeSchemaDescription { description: String } = 4,
 
-    /// Change Schema version
+    /// Change Schema version, with its own `field_constraints`
     #[account(0, writable, signer, name = "payer")]
     #[account(1, signer, name = "authority")]
     #[account(
@@ -93,9 +122,19 @@ pub enum AttestationServiceInstruction {
     ChangeSchemaVersion {
         layout: Vec<u8>,
         field_names: Vec<String>,
+        bump: u8,
+        field_constraints: Vec<u8>,
     } = 5,
 
     /// Create an Attestation for a Schema by an authorized signer.
+    /// When the Schema charges a fee, the accounts paying it follow the other
+    /// accounts: the Schema's `fee_treasury` for lamport fees, or the payer's
+    /// token account, the `fee_treasury` token account, the fee mint and its
+    /// token program for token fees.
+    /// An open Attestation it references can be passed, writable, after them,
+    /// and must be when the Schema has a `ref_schema`.
+    /// When the Schema has a `resolver_program`, it is passed last, followed
+    /// by the resolver authority PDA and the accounts forwarded to it.
     #[account(0, writable, signer, name = "payer")]
     #[account(
         1,
@@ -115,9 +154,14 @@ pub enum AttestationServiceInstruction {
         nonce: Pubkey,
         data: Vec<u8>,
         expiry: i64,
+        bump: u8,
     } = 6,
 
-    /// Close an Attestation account.
+    /// Close an Attestation account. Closing an Attestation that references
+    /// another requires the referenced Attestation, writable, after the other
+    /// accounts, and closing a referenced one requires its Schema there.
+    /// Closing an Attestation with a `resolver_program` requires it there,
+    /// followed by the resolver authority PDA and the accounts forwarded to it.
     #[account(0, writable, signer, name = "payer")]
     #[account(
         1,
@@ -132,7 +176,12 @@ pub enum AttestationServiceInstruction {
     #[account(6, name = "attestation_program")]
     CloseAttestation {} = 7,
 
-    /// Enable tokenization for a Schema
+    /// Enable tokenization for a Schema. The token policy, as `TOKEN_POLICY_*`
+    /// flags, sets whether Attestation tokens are non-transferable, have the
+    /// program as permanent delegate and can be frozen once expired. It
+    /// defaults to non-transferable tokens with the permanent delegate. The
+    /// group mint's token metadata has the name, uri and symbol, and the
+    /// Schema's credential, schema and version.
     #[account(0, writable, signer, name = "payer")]
     #[account(1, signer, name = "authority")]
     #[account(
@@ -140,7 +189,7 @@ pub enum AttestationServiceInstruction {
         name = "credential",
         desc = "Credential the Schema is associated with"
     )]
-    #[account(3, name = "schema")]
+    #[account(3, writable, name = "schema")]
     #[account(4, writable, name = "mint", desc = "Mint of Schema Token")]
     #[account(
         5,
@@ -149,9 +198,20 @@ pub enum AttestationServiceInstruction {
     )]
     #[account(6, name = "system_program")]
     #[account(7, name = "token_program")]
-    TokenizeSchema { max_size: u64 } = 9,
+    TokenizeSchema {
+        max_size: u64,
+        mint_bump: u8,
+        token_policy: u8,
+        name: String,
+        uri: String,
+        symbol: String,
+    } = 9,
 
-    /// Create attestation with token.
+    /// Create attestation with token. Fee accounts and a referenced
+    /// Attestation follow the other accounts as for CreateAttestation.
+    /// The token metadata mirrors the Attestation's expiry, signer and the
+    /// Schema's token metadata fields. `mint_account_space` is ignored, the
+    /// program computes the mint space from the name, uri and symbol.
     #[account(0, writable, signer, name = "payer")]
     #[account(
         1,
@@ -192,13 +252,20 @@ pub enum AttestationServiceInstruction {
         nonce: Pubkey,
         data: Vec<u8>,
         expiry: i64,
+        bump: u8,
         name: String,
         uri: String,
         symbol: String,
         mint_account_space: u16,
+        schema_mint_bump: u8,
+        attestation_mint_bump: u8,
     } = 10,
 
-    /// Close an Attestation and Attestation token.
+    /// Close an Attestation and Attestation token. Accounts for references
+    /// follow the other accounts as for CloseAttestation. The token is burned
+    /// from `attestation_token_account` unless it is empty. If the mint does
+    /// not have the program as permanent delegate and the holder still has
+    /// the token, the token is frozen instead and the mint stays open.
     #[account(0, writable, signer, name = "payer")]
     #[account(
         1,
@@ -226,10 +293,390 @@ pub enum AttestationServiceInstruction {
         9,
         writable,
         name = "attestation_token_account",
-        desc = "Associated token account of the related Attestation Token"
+        desc = "Token account holding the Attestation Token"
     )]
     #[account(10, name = "token_program")]
-    CloseTokenizedAttestation {} = 11,
+    CloseTokenizedAttestation { attestation_mint_bump: u8 } = 11,
+
+    /// Migrate a Credential, Schema or Attestation account created with an
+    /// older layout to the current layout. Permissionless, the payer funds
+    /// any additional rent.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(1, writable, name = "account", desc = "Account to migrate")]
+    #[account(2, name = "system_program")]
+    MigrateAccount { bump: u8 } = 13,
+
+    /// Replace the display metadata of a Credential.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(1, signer, name = "authority")]
+    #[account(2, writable, name = "credential")]
+    #[account(3, name = "system_program")]
+    UpdateCredentialMetadata {
+        display_name: String,
+        description: String,
+        uri: String,
+        logo_uri: String,
+        website: String,
+    } = 14,
+
+    /// Sets Credential is_paused status. A paused Credential cannot issue
+    /// Attestations or tokenize Schemas.
+    #[account(0, signer, name = "authority")]
+    #[account(1, writable, name = "credential")]
+    ChangeCredentialStatus { is_paused: bool } = 15,
+
+    /// Create a batch of Attestations committed to by the Merkle root of
+    /// their `(subject, data, expiry)` leaves. Schemas with a resolver, a
+    /// required referenced Attestation or an issuance fee cannot be batched.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Authorized signer of the Schema's Credential"
+    )]
+    #[account(
+        2,
+        name = "credential",
+        desc = "Credential the Schema is associated with"
+    )]
+    #[account(3, name = "schema", desc = "Schema the batched Attestations adhere to")]
+    #[account(4, writable, name = "merkle_batch")]
+    #[account(5, name = "system_program")]
+    CreateMerkleAttestationBatch {
+        nonce: Pubkey,
+        root: [u8; 32],
+        bump: u8,
+    } = 16,
+
+    /// Check that an Attestation is included in a batch and has not expired.
+    /// `proof` holds the sibling hashes from the leaf up to the root.
+    #[account(0, name = "merkle_batch")]
+    VerifyMerkleAttestation {
+        subject: Pubkey,
+        data: Vec<u8>,
+        expiry: i64,
+        proof: Vec<[u8; 32]>,
+    } = 17,
+
+    /// Revoke every Attestation in a batch by closing it.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Authorized signer of the batch's Credential"
+    )]
+    #[account(
+        2,
+        name = "credential",
+        desc = "Credential the batch is associated with"
+    )]
+    #[account(3, writable, name = "merkle_batch")]
+    RevokeMerkleAttestationBatch {} = 18,
+
+    /// Create an Attestation approved by an authorized signer's off-chain
+    /// signature, so any payer can submit it. The previous instruction must be
+    /// an Ed25519 program instruction verifying the signature over the
+    /// attestation message, or a secp256k1 program instruction verifying an
+    /// Ethereum signer's signature over it as an EIP-191 message. Rejected
+    /// after `deadline`, or when the message was already used.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(
+        1,
+        name = "authority",
+        desc = "Authorized signer of the Schema's Credential that signed the attestation message"
+    )]
+    #[account(
+        2,
+        name = "credential",
+        desc = "Credential the Schema is associated with"
+    )]
+    #[account(3, name = "schema", desc = "Schema the Attestation is associated with")]
+    #[account(4, writable, name = "attestation")]
+    #[account(5, name = "system_program")]
+    #[account(6, name = "instructions_sysvar")]
+    #[account(
+        7,
+        writable,
+        name = "used_message",
+        desc = "PDA marking the attestation message as used"
+    )]
+    CreateAttestationWithSignature {
+        deadline: i64,
+        nonce: Pubkey,
+        data: Vec<u8>,
+        expiry: i64,
+        bump: u8,
+        used_message_bump: u8,
+    } = 19,
+
+    /// Sets Credential passkey_signers, compressed secp256r1 public keys.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(1, signer, name = "authority")]
+    #[account(
+        2,
+        writable,
+        name = "credential",
+        desc = "Credential the Schema is associated with"
+    )]
+    #[account(3, name = "system_program")]
+    ChangePasskeySigners { passkeys: Vec<[u8; 33]> } = 20,
+
+    /// Create an Attestation approved by a WebAuthn assertion of one of the
+    /// Credential's passkeys, whose challenge is the sha256 digest of the
+    /// attestation message. The previous instruction must be a secp256r1
+    /// program instruction verifying the passkey's signature over
+    /// `authenticator_data` followed by the sha256 hash of `client_data_json`.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(
+        1,
+        name = "authority",
+        desc = "sha256 hash of the passkey that signed the assertion"
+    )]
+    #[account(
+        2,
+        name = "credential",
+        desc = "Credential the Schema is associated with"
+    )]
+    #[account(3, name = "schema", desc = "Schema the Attestation is associated with")]
+    #[account(4, writable, name = "attestation")]
+    #[account(5, name = "system_program")]
+    #[account(6, name = "instructions_sysvar")]
+    #[account(
+        7,
+        writable,
+        name = "used_message",
+        desc = "PDA marking the attestation message as used"
+    )]
+    CreateAttestationWithPasskey {
+        deadline: i64,
+        authenticator_data: Vec<u8>,
+        client_data_json: Vec<u8>,
+        nonce: Pubkey,
+        data: Vec<u8>,
+        expiry: i64,
+        bump: u8,
+        used_message_bump: u8,
+    } = 21,
+
+    /// Request an Attestation from a Credential. The subject pays for the
+    /// request, which holds the proposed data validated against the Schema,
+    /// until an authorized signer approves or rejects it.
+    #[account(0, writable, signer, name = "subject")]
+    #[account(
+        1,
+        name = "credential",
+        desc = "Credential the Schema is associated with"
+    )]
+    #[account(
+        2,
+        name = "schema",
+        desc = "Schema the requested Attestation is associated with"
+    )]
+    #[account(3, writable, name = "attestation_request")]
+    #[account(4, name = "system_program")]
+    CreateAttestationRequest {
+        nonce: Pubkey,
+        data: Vec<u8>,
+        expiry: i64,
+        bump: u8,
+    } = 22,
+
+    /// Approve an AttestationRequest, creating its Attestation and refunding
+    /// the request's rent to the subject. Fee accounts and a referenced
+    /// Attestation follow the listed accounts, as for CreateAttestation.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Authorized signer of the Schema's Credential"
+    )]
+    #[account(
+        2,
+        name = "credential",
+        desc = "Credential the Schema is associated with"
+    )]
+    #[account(3, name = "schema", desc = "Schema the Attestation is associated with")]
+    #[account(4, writable, name = "attestation")]
+    #[account(5, name = "system_program")]
+    #[account(6, writable, name = "attestation_request")]
+    #[account(
+        7,
+        writable,
+        name = "subject",
+        desc = "Subject that created the request and is refunded its rent"
+    )]
+    ApproveAttestationRequest { bump: u8 } = 23,
+
+    /// Reject an AttestationRequest, refunding its rent to the subject.
+    #[account(
+        0,
+        signer,
+        name = "authority",
+        desc = "Authorized signer of the request's Credential"
+    )]
+    #[account(
+        1,
+        name = "credential",
+        desc = "Credential the request is associated with"
+    )]
+    #[account(2, writable, name = "attestation_request")]
+    #[account(
+        3,
+        writable,
+        name = "subject",
+        desc = "Subject that created the request and is refunded its rent"
+    )]
+    RejectAttestationRequest {} = 24,
+
+    /// Set the fee charged for each Attestation of a Schema, in lamports when
+    /// `fee_mint` is the default pubkey or in tokens of `fee_mint`. Fees are
+    /// paid to the Credential's treasury PDA, which is funded up to rent
+    /// exemption for lamport fees, or to a token account it owns.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(1, signer, name = "authority")]
+    #[account(
+        2,
+        name = "credential",
+        desc = "Credential the Schema is associated with"
+    )]
+    #[account(3, writable, name = "schema")]
+    #[account(
+        4,
+        writable,
+        name = "treasury",
+        desc = "Treasury PDA for lamport fees or its token account for token fees"
+    )]
+    #[account(5, name = "system_program")]
+    ChangeSchemaFee {
+        fee: u64,
+        fee_mint: Pubkey,
+        treasury_bump: u8,
+    } = 25,
+
+    /// Withdraw the fees collected by a Credential's treasury. Lamports above
+    /// the treasury's rent exemption are withdrawn, unless the treasury token
+    /// account, the destination token account, the mint and its token program
+    /// follow the other accounts, in which case all of its tokens are.
+    #[account(0, signer, name = "authority")]
+    #[account(1, name = "credential")]
+    #[account(
+        2,
+        writable,
+        name = "treasury",
+        desc = "Treasury PDA of the Credential"
+    )]
+    #[account(3, writable, name = "destination")]
+    #[account(4, name = "system_program")]
+    WithdrawTreasury { treasury_bump: u8 } = 26,
+
+    /// Set the Schema fields, by name, mirrored into the token metadata of
+    /// tokenized Attestations alongside their expiry and signer. Only Schemas
+    /// storing plaintext data can mirror fields.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(1, signer, name = "authority")]
+    #[account(
+        2,
+        name = "credential",
+        desc = "Credential the Schema is associated with"
+    )]
+    #[account(3, writable, name = "schema")]
+    #[account(4, name = "system_program")]
+    ChangeSchemaTokenMetadataFields { field_names: Vec<String> } = 27,
+
+    /// Freeze the token of an expired Attestation whose Schema's token policy
+    /// freezes tokens on expiry. Anyone can call it.
+    #[account(0, name = "attestation")]
+    #[account(1, name = "schema", desc = "Schema the Attestation is associated with")]
+    #[account(2, name = "attestation_mint", desc = "Mint of Attestation Token")]
+    #[account(
+        3,
+        name = "sas_pda",
+        desc = "Program derived address used as program signer authority"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "attestation_token_account",
+        desc = "Token account holding the Attestation Token"
+    )]
+    #[account(5, name = "token_program")]
+    FreezeExpiredAttestationToken { attestation_mint_bump: u8 } = 28,
+
+    /// Replace the name, uri and symbol in the token metadata of a tokenized
+    /// Schema's group mint.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(1, signer, name = "authority")]
+    #[account(
+        2,
+        name = "credential",
+        desc = "Credential the Schema is associated with"
+    )]
+    #[account(3, name = "schema")]
+    #[account(4, writable, name = "mint", desc = "Mint of Schema Token")]
+    #[account(
+        5,
+        name = "sas_pda",
+        desc = "Program derived address used as program signer authority"
+    )]
+    #[account(6, name = "system_program")]
+    #[account(7, name = "token_program")]
+    UpdateSchemaTokenMetadata {
+        name: String,
+        uri: String,
+        symbol: String,
+        mint_bump: u8,
+    } = 29,
+
+    /// Mint the token of an existing Attestation whose Schema is tokenized
+    /// and set its `token_account`. When the Attestation has a
+    /// `resolver_program`, it is passed after these accounts, followed by the
+    /// resolver authority PDA and the accounts forwarded to it.
+    #[account(0, writable, signer, name = "payer")]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        desc = "Authorized signer of the Schema's Credential"
+    )]
+    #[account(
+        2,
+        name = "credential",
+        desc = "Credential the Schema is associated with"
+    )]
+    #[account(3, name = "schema", desc = "Schema the Attestation is associated with")]
+    #[account(4, writable, name = "attestation")]
+    #[account(5, name = "system_program")]
+    #[account(6, writable, name = "schema_mint", desc = "Mint of Schema Token")]
+    #[account(
+        7,
+        writable,
+        name = "attestation_mint",
+        desc = "Mint of Attestation Token"
+    )]
+    #[account(
+        8,
+        name = "sas_pda",
+        desc = "Program derived address used as program signer authority"
+    )]
+    #[account(
+        9,
+        writable,
+        name = "recipient_token_account",
+        desc = "Associated token account of Recipient for Attestation Token"
+    )]
+    #[account(10, name = "recipient", desc = "Wallet to receive Attestation Token")]
+    #[account(11, name = "token_program")]
+    #[account(12, name = "associated_token_program")]
+    TokenizeAttestation {
+        name: String,
+        uri: String,
+        symbol: String,
+        schema_mint_bump: u8,
+        attestation_mint_bump: u8,
+    } = 30,
 
     /// Invoked via CPI from SAS Program to log event via instruction data.
     #[account(0, signer, name = "event_authority")]
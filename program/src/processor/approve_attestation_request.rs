@@ -0,0 +1,78 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+use crate::{
+    error::AttestationServiceError,
+    require_len,
+    state::{AttestationRequest, SignerKind},
+};
+
+use super::{
+    close_attestation_request, create_authorized_attestation, split_remaining_accounts,
+    verify_owner_mutability, verify_signer, CreateAttestationArgs,
+};
+
+#[inline(always)]
+pub fn process_approve_attestation_request(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let (accounts, remaining_accounts) = split_remaining_accounts(accounts, 8)?;
+    let [_payer_info, authorized_signer, credential_info, schema_info, _attestation_info, _system_program, attestation_request_info, subject_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authorized_signer, false)?;
+
+    // Validate AttestationRequest is owned by our program
+    verify_owner_mutability(attestation_request_info, program_id, true)?;
+    let attestation_request =
+        AttestationRequest::try_from_bytes(&attestation_request_info.try_borrow_data()?)?;
+
+    // Validate the request is for this Credential and Schema, and refunds its
+    // subject.
+    if attestation_request.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+    if attestation_request.schema.ne(schema_info.key()) {
+        return Err(AttestationServiceError::InvalidSchema.into());
+    }
+    if attestation_request.subject.ne(subject_info.key()) {
+        return Err(AttestationServiceError::InvalidAttestationRequest.into());
+    }
+
+    // The Attestation is created from the requested data, which is validated
+    // against the Schema again in case it changed since the request.
+    create_authorized_attestation(
+        program_id,
+        &accounts[..6],
+        &CreateAttestationArgs {
+            nonce: attestation_request.nonce,
+            data: &attestation_request.data,
+            expiry: attestation_request.expiry,
+            bump: args.bump,
+        },
+        SignerKind::Ed25519,
+        None,
+        remaining_accounts,
+    )?;
+
+    close_attestation_request(attestation_request_info, subject_info)
+}
+
+struct ApproveAttestationRequestArgs {
+    bump: u8,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<ApproveAttestationRequestArgs, ProgramError> {
+    require_len!(data, 1);
+    let bump = data[0];
+
+    Ok(ApproveAttestationRequestArgs { bump })
+}
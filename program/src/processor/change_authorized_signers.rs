@@ -11,7 +11,10 @@ use pinocchio::{
 use pinocchio_system::instructions::Transfer;
 
 use crate::{
-    processor::{verify_owner_mutability, verify_signer, verify_system_program},
+    processor::{
+        process_signer_kinds, verify_current_layout, verify_owner_mutability, verify_signer,
+        verify_system_program,
+    },
     require_len,
     state::{discriminator::AccountSerialize, Credential},
 };
@@ -33,6 +36,8 @@ pub fn process_change_authorized_signers(
     verify_system_program(system_program)?;
     // Verify program ownership, mutability and PDAs.
     verify_owner_mutability(credential_info, program_id, true)?;
+    // Legacy accounts must be migrated before they are modified.
+    verify_current_layout::<Credential>(credential_info)?;
 
     let data = credential_info.try_borrow_data()?;
     let mut credential = Credential::try_from_bytes(&data)?;
@@ -43,16 +48,15 @@ pub fn process_change_authorized_signers(
         return Err(ProgramError::IncorrectAuthority);
     }
 
-    // Resize account if needed.
+    // Update authorized_signers and their kinds on struct.
+    credential.authorized_signers = args.signers;
+    credential.authorized_signer_kinds = args.signer_kinds;
+    credential.validate_authorized_signers()?;
+
+    // Resize account to fit the new signers.
     let prev_space = credential_info.data_len();
-    let mut new_space = prev_space;
-    let prev_len = credential.authorized_signers.len();
-    let new_len = args.signers.len();
-    if new_len > prev_len {
-        new_space += (new_len - prev_len) * 32;
-    } else {
-        new_space -= (prev_len - new_len) * 32;
-    }
+    let credential_bytes = credential.to_bytes();
+    let new_space = credential_bytes.len();
     if new_space != credential_info.data_len() {
         credential_info.realloc(new_space, false)?;
         let diff = new_space.saturating_sub(prev_space);
@@ -73,18 +77,16 @@ pub fn process_change_authorized_signers(
         }
     }
 
-    // Update authorized_signers on struct.
-    credential.authorized_signers = args.signers;
-
     // Write updated data.
     let mut credential_data = credential_info.try_borrow_mut_data()?;
-    credential_data.copy_from_slice(&credential.to_bytes());
+    credential_data.copy_from_slice(&credential_bytes);
 
     Ok(())
 }
 
 struct ChangeAuthorizedSignersArgs {
     signers: Vec<Pubkey>,
+    signer_kinds: Vec<u8>,
 }
 
 fn process_instruction_data(data: &[u8]) -> Result<ChangeAuthorizedSignersArgs, ProgramError> {
@@ -102,5 +104,10 @@ fn process_instruction_data(data: &[u8]) -> Result<ChangeAuthorizedSignersArgs,
         offset += 32;
     }
 
-    Ok(ChangeAuthorizedSignersArgs { signers })
+    let signer_kinds = process_signer_kinds(&data[offset..], signers.len())?;
+
+    Ok(ChangeAuthorizedSignersArgs {
+        signers,
+        signer_kinds,
+    })
 }
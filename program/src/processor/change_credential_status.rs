@@ -0,0 +1,61 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+use pinocchio_log::log;
+
+use crate::{
+    processor::{verify_current_layout, verify_owner_mutability, verify_signer},
+    require_len,
+    state::{discriminator::AccountSerialize, Credential},
+};
+
+#[inline(always)]
+pub fn process_change_credential_status(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [authority_info, credential_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authority_info, false)?;
+
+    // Verify program ownership, mutability and PDAs.
+    verify_owner_mutability(credential_info, program_id, true)?;
+    // Legacy accounts must be migrated before they are modified.
+    verify_current_layout::<Credential>(credential_info)?;
+
+    let mut credential_data = credential_info.try_borrow_mut_data()?;
+    let mut credential = Credential::try_from_bytes(&credential_data)?;
+
+    // Verify signer matches credential authority.
+    if credential.authority.ne(authority_info.key()) {
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    credential.is_paused = args.is_paused;
+    log!(
+        "Setting credential's is_paused to: {}",
+        args.is_paused as u8
+    );
+    credential_data.copy_from_slice(&credential.to_bytes());
+
+    Ok(())
+}
+
+struct ChangeCredentialStatusArgs {
+    is_paused: bool,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<ChangeCredentialStatusArgs, ProgramError> {
+    require_len!(data, 1);
+    let is_paused = data
+        .first()
+        .ok_or(ProgramError::InvalidInstructionData)?
+        .eq(&1);
+
+    Ok(ChangeCredentialStatusArgs { is_paused })
+}
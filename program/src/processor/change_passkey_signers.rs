@@ -0,0 +1,120 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::Transfer;
+
+use crate::{
+    error::AttestationServiceError,
+    processor::{
+        verify_current_layout, verify_owner_mutability, verify_signer, verify_system_program,
+    },
+    require_len,
+    state::{discriminator::AccountSerialize, Credential, PASSKEY_LEN},
+};
+
+#[inline(always)]
+pub fn process_change_passkey_signers(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [payer_info, authority_info, credential_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authority_info, false)?;
+    // Validate: system program
+    verify_system_program(system_program)?;
+    // Verify program ownership, mutability and PDAs.
+    verify_owner_mutability(credential_info, program_id, true)?;
+    // Legacy accounts must be migrated before they are modified.
+    verify_current_layout::<Credential>(credential_info)?;
+
+    let data = credential_info.try_borrow_data()?;
+    let mut credential = Credential::try_from_bytes(&data)?;
+    drop(data); // Drop immutable borrow.
+
+    // Verify that signer matches credential authority.
+    if credential.authority.ne(authority_info.key()) {
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    // Validate passkeys are compressed secp256r1 public keys
+    if args
+        .passkeys
+        .iter()
+        .any(|passkey| !matches!(passkey[0], 0x02 | 0x03))
+    {
+        return Err(AttestationServiceError::InvalidPasskey.into());
+    }
+
+    // Resize account if needed.
+    let prev_space = credential_info.data_len();
+    let mut new_space = prev_space;
+    let prev_len = credential.passkey_signers.len();
+    let new_len = args.passkeys.len();
+    if new_len > prev_len {
+        new_space += (new_len - prev_len) * PASSKEY_LEN;
+    } else {
+        new_space -= (prev_len - new_len) * PASSKEY_LEN;
+    }
+    if new_space != credential_info.data_len() {
+        credential_info.realloc(new_space, false)?;
+        let diff = new_space.saturating_sub(prev_space);
+        if diff > 0 {
+            // top up lamports to account for additional rent.
+            let rent = Rent::get()?;
+            let min_rent = rent.minimum_balance(new_space);
+            let current_rent = credential_info.lamports();
+            let rent_diff = min_rent.saturating_sub(current_rent);
+            if rent_diff > 0 {
+                Transfer {
+                    from: payer_info,
+                    to: credential_info,
+                    lamports: rent_diff,
+                }
+                .invoke()?;
+            }
+        }
+    }
+
+    // Update passkey_signers on struct.
+    credential.passkey_signers = args.passkeys;
+
+    // Write updated data.
+    let mut credential_data = credential_info.try_borrow_mut_data()?;
+    credential_data.copy_from_slice(&credential.to_bytes());
+
+    Ok(())
+}
+
+struct ChangePasskeySignersArgs {
+    passkeys: Vec<[u8; PASSKEY_LEN]>,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<ChangePasskeySignersArgs, ProgramError> {
+    let mut offset: usize = 0;
+
+    require_len!(data, 4);
+    let passkeys_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+    offset += 4;
+
+    require_len!(data, 4 + passkeys_len * PASSKEY_LEN);
+    let mut passkeys = Vec::with_capacity(passkeys_len);
+    for _ in 0..passkeys_len {
+        let passkey: [u8; PASSKEY_LEN] = data[offset..offset + PASSKEY_LEN].try_into().unwrap();
+        passkeys.push(passkey);
+        offset += PASSKEY_LEN;
+    }
+
+    Ok(ChangePasskeySignersArgs { passkeys })
+}
@@ -11,8 +11,12 @@ use pinocchio::{
 use pinocchio_system::instructions::Transfer;
 
 use crate::{
+    constants::MAX_DESCRIPTION_LEN,
     error::AttestationServiceError,
-    processor::{verify_owner_mutability, verify_signer, verify_system_program},
+    processor::{
+        validate_utf8, verify_current_layout, verify_owner_mutability, verify_signer,
+        verify_system_program,
+    },
     require_len,
     state::{discriminator::AccountSerialize, Credential, Schema},
 };
@@ -35,6 +39,8 @@ pub fn process_change_schema_description(
     // Verify program ownership, mutability and PDAs.
     verify_owner_mutability(credential_info, program_id, false)?;
     verify_owner_mutability(schema_info, program_id, true)?;
+    // Legacy accounts must be migrated before they are modified.
+    verify_current_layout::<Schema>(schema_info)?;
     verify_system_program(system_program)?;
 
     let credential = &Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
@@ -56,6 +62,7 @@ pub fn process_change_schema_description(
     let prev_description_len = schema.description.len();
 
     // Update description on struct.
+    validate_utf8(&args.description, MAX_DESCRIPTION_LEN)?;
     schema.description = args.description;
 
     // Resize account if needed.
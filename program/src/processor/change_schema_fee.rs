@@ -0,0 +1,120 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::Transfer;
+
+use crate::{
+    constants::TREASURY_SEED,
+    error::AttestationServiceError,
+    processor::{
+        is_program_address, read_token_account, verify_current_layout, verify_owner_mutability,
+        verify_signer, verify_system_program,
+    },
+    require_len,
+    state::{discriminator::AccountSerialize, Credential, Schema, NO_FEE_MINT},
+};
+
+#[inline(always)]
+pub fn process_change_schema_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [payer_info, authority_info, credential_info, schema_info, treasury_info, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: payer and authority should have signed
+    verify_signer(payer_info, true)?;
+    verify_signer(authority_info, false)?;
+    // Validate: system program
+    verify_system_program(system_program)?;
+    // Verify program ownership, mutability and PDAs.
+    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(schema_info, program_id, true)?;
+    // Legacy accounts must be migrated before they are modified.
+    verify_current_layout::<Schema>(schema_info)?;
+
+    let credential = &Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
+
+    // Verify signer matches credential authority.
+    if credential.authority.ne(authority_info.key()) {
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let mut schema_data = schema_info.try_borrow_mut_data()?;
+    let mut schema = Schema::try_from_bytes(&schema_data)?;
+
+    // Verify that schema is under the same credential.
+    if schema.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidSchema.into());
+    }
+
+    // The treasury must use the canonical bump, which WithdrawTreasury
+    // requires to sign for it.
+    let bump_seed = [args.treasury_bump];
+    let treasury_seeds: [&[u8]; 3] = [TREASURY_SEED, credential_info.key(), &bump_seed];
+
+    if args.fee_mint.eq(&NO_FEE_MINT) {
+        // Lamport fees are paid to the Credential's treasury PDA.
+        if !is_program_address(treasury_info.key(), &treasury_seeds, program_id) {
+            return Err(AttestationServiceError::InvalidTreasury.into());
+        }
+        // Fund the treasury up to rent exemption so fees smaller than the
+        // minimum balance can be paid to it.
+        let rent_diff = Rent::get()?
+            .minimum_balance(0)
+            .saturating_sub(treasury_info.lamports());
+        if rent_diff > 0 {
+            Transfer {
+                from: payer_info,
+                to: treasury_info,
+                lamports: rent_diff,
+            }
+            .invoke()?;
+        }
+    } else {
+        // Token fees are paid to a token account of the fee mint owned by the
+        // Credential's treasury PDA.
+        let (mint, owner, _amount) = read_token_account(treasury_info)?;
+        if mint.ne(&args.fee_mint) {
+            return Err(AttestationServiceError::InvalidMint.into());
+        }
+        if !is_program_address(&owner, &treasury_seeds, program_id) {
+            return Err(AttestationServiceError::InvalidTreasury.into());
+        }
+    }
+
+    schema.fee = args.fee;
+    schema.fee_mint = args.fee_mint;
+    schema.fee_treasury = *treasury_info.key();
+    schema_data.copy_from_slice(&schema.to_bytes());
+
+    Ok(())
+}
+
+struct ChangeSchemaFeeArgs {
+    fee: u64,
+    fee_mint: Pubkey,
+    treasury_bump: u8,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<ChangeSchemaFeeArgs, ProgramError> {
+    require_len!(data, 8 + 32 + 1);
+    let fee = u64::from_le_bytes(data[0..8].try_into().unwrap());
+    let fee_mint: Pubkey = data[8..40].try_into().unwrap();
+    let treasury_bump = data[40];
+
+    Ok(ChangeSchemaFeeArgs {
+        fee,
+        fee_mint,
+        treasury_bump,
+    })
+}
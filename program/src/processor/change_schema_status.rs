@@ -5,7 +5,7 @@ use pinocchio_log::log;
 
 use crate::{
     error::AttestationServiceError,
-    processor::{verify_owner_mutability, verify_signer},
+    processor::{verify_current_layout, verify_owner_mutability, verify_signer},
     require_len,
     state::{discriminator::AccountSerialize, Credential, Schema},
 };
@@ -27,6 +27,8 @@ pub fn process_change_schema_status(
     // Verify program ownership, mutability and PDAs.
     verify_owner_mutability(credential_info, program_id, false)?;
     verify_owner_mutability(schema_info, program_id, true)?;
+    // Legacy accounts must be migrated before they are modified.
+    verify_current_layout::<Schema>(schema_info)?;
 
     let credential = &Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
 
@@ -0,0 +1,133 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::Transfer;
+
+use crate::{
+    error::AttestationServiceError,
+    processor::{
+        verify_current_layout, verify_owner_mutability, verify_signer, verify_system_program,
+    },
+    require_len,
+    state::{discriminator::AccountSerialize, iter_field_names, Credential, Schema},
+};
+
+#[inline(always)]
+pub fn process_change_schema_token_metadata_fields(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [payer_info, authority_info, credential_info, schema_info, system_program] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authority_info, false)?;
+
+    // Verify program ownership, mutability and PDAs.
+    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(schema_info, program_id, true)?;
+    // Legacy accounts must be migrated before they are modified.
+    verify_current_layout::<Schema>(schema_info)?;
+    verify_system_program(system_program)?;
+
+    let credential = &Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
+
+    // Verify signer matches credential authority.
+    if credential.authority.ne(authority_info.key()) {
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let schema_data = schema_info.try_borrow_data()?;
+    let mut schema = Schema::try_from_bytes(&schema_data)?;
+    drop(schema_data); // Drop immutable borrow.
+
+    // Verify that schema is under the same credential.
+    if schema.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidSchema.into());
+    }
+
+    let prev_fields_len = schema.token_metadata_fields.len();
+
+    // Resolve the field names to their indexes in the Schema layout.
+    let names: Vec<&[u8]> = iter_field_names(&schema.field_names).collect();
+    schema.token_metadata_fields = args
+        .field_names
+        .iter()
+        .map(|field_name| {
+            names
+                .iter()
+                .position(|name| name.eq(field_name))
+                .map(|index| index as u8)
+                .ok_or(AttestationServiceError::InvalidTokenMetadataField)
+        })
+        .collect::<Result<_, _>>()?;
+    schema.validate_token_metadata_fields()?;
+
+    // Resize account if needed.
+    let new_fields_len = schema.token_metadata_fields.len();
+    if new_fields_len != prev_fields_len {
+        let previous_space = schema_info.data_len();
+        let new_space = previous_space + new_fields_len - prev_fields_len;
+        schema_info.realloc(new_space, false)?;
+        let diff = new_space.saturating_sub(previous_space);
+        if diff > 0 {
+            // top up lamports to account for additional rent.
+            let rent = Rent::get()?;
+            let min_rent = rent.minimum_balance(new_space);
+            let current_rent = schema_info.lamports();
+            let rent_diff = min_rent.saturating_sub(current_rent);
+            if rent_diff > 0 {
+                Transfer {
+                    from: payer_info,
+                    to: schema_info,
+                    lamports: rent_diff,
+                }
+                .invoke()?;
+            }
+        }
+    }
+
+    // Write updated data.
+    let mut schema_data = schema_info.try_borrow_mut_data()?;
+    schema_data.copy_from_slice(&schema.to_bytes());
+
+    Ok(())
+}
+
+struct ChangeSchemaTokenMetadataFieldsArgs<'a> {
+    field_names: Vec<&'a [u8]>,
+}
+
+fn process_instruction_data(
+    data: &[u8],
+) -> Result<ChangeSchemaTokenMetadataFieldsArgs, ProgramError> {
+    let mut offset: usize = 0;
+
+    require_len!(data, 4);
+    let field_names_count = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+    offset += 4;
+
+    let mut field_names = Vec::new();
+    for _ in 0..field_names_count {
+        require_len!(data, offset + 4);
+        let name_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+
+        require_len!(data, offset + name_len);
+        field_names.push(&data[offset..offset + name_len]);
+        offset += name_len;
+    }
+
+    Ok(ChangeSchemaTokenMetadataFieldsArgs { field_names })
+}
@@ -1,3 +1,6 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
 use pinocchio::{
     account_info::AccountInfo,
     instruction::Seed,
@@ -6,17 +9,16 @@ use pinocchio::{
     sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
-use solana_program::pubkey::Pubkey as SolanaPubkey;
 
 use crate::{
     constants::SCHEMA_SEED,
     error::AttestationServiceError,
     processor::{
-        create_pda_account, verify_owner_mutability, verify_signer, verify_system_account,
-        verify_system_program,
+        create_pda_account, is_program_address, verify_owner_mutability, verify_signer,
+        verify_system_account, verify_system_program,
     },
     require_len,
-    state::{discriminator::AccountSerialize, Credential, Schema},
+    state::{discriminator::AccountSerialize, Credential, Schema, DEFAULT_TOKEN_POLICY},
 };
 
 #[inline(always)]
@@ -57,25 +59,59 @@ pub fn process_change_schema_version(
         return Err(AttestationServiceError::InvalidSchema.into());
     }
 
-    let name = &existing_schema.name;
-    let description = existing_schema.description;
-    let version = &[existing_schema.version.checked_add(1).unwrap()];
+    let version = &[existing_schema
+        .version
+        .checked_add(1)
+        .ok_or(AttestationServiceError::ArithmeticOverflow)?];
+
+    let schema = Schema {
+        credential: *credential_info.key(),
+        name: existing_schema.name,
+        description: existing_schema.description,
+        layout: args.layout.to_vec(),
+        field_names: args.field_names_bytes.to_vec(),
+        is_paused: false,
+        version: version[0],
+        field_constraints: args.field_constraints.to_vec(),
+        bump: args.bump,
+        commitment_scheme: existing_schema.commitment_scheme,
+        ref_schema: existing_schema.ref_schema,
+        ref_close_policy: existing_schema.ref_close_policy,
+        fee: existing_schema.fee,
+        fee_mint: existing_schema.fee_mint,
+        fee_treasury: existing_schema.fee_treasury,
+        resolver_program: existing_schema.resolver_program,
+        // Mirrored fields are selected again for the new layout.
+        token_metadata_fields: Vec::new(),
+        // The new version has its own mint, tokenized with its own policy.
+        token_policy: DEFAULT_TOKEN_POLICY,
+    };
 
-    // NOTE: this could be optimized further by removing the `solana-program` dependency
-    // and using `pubkey::checked_create_program_address` from Pinocchio to verify the
-    // pubkey and associated bump (needed to be added as arg) is valid.
-    let (schema_pda, schema_bump) = SolanaPubkey::find_program_address(
-        &[SCHEMA_SEED, credential_info.key(), name.as_ref(), version],
-        &SolanaPubkey::from(*program_id),
-    );
+    // Checks that layout, field names and field constraints are valid.
+    schema.validate(args.field_names_count)?;
 
-    if new_schema_info.key().ne(&schema_pda.to_bytes()) {
+    let name = &schema.name;
+    let description = &schema.description;
+
+    let bump_seed = [args.bump];
+    if !is_program_address(
+        new_schema_info.key(),
+        &[
+            SCHEMA_SEED,
+            credential_info.key(),
+            name.as_slice(),
+            version,
+            &bump_seed,
+        ],
+        program_id,
+    ) {
         // PDA was invalid
-        return Err(AttestationServiceError::InvalidCredential.into());
+        return Err(AttestationServiceError::InvalidSchema.into());
     }
 
     // Account layout
     // discriminator - 1
+    // layout_version - 1
     // credential - 32
     // name - 4 + length
     // description - 4 + length
@@ -83,16 +119,38 @@ pub fn process_change_schema_version(
     // field_names - 4 + length
     // is_paused - 1
     // version - 1
+    // field_constraints - 4 + length
+    // bump - 1
+    // commitment_scheme - 1
+    // ref_schema - 32
+    // ref_close_policy - 1
+    // fee - 8
+    // fee_mint - 32
+    // fee_treasury - 32
+    // resolver_program - 32
+    // token_metadata_fields - 4
+    // token_policy - 1
     let space = 1
+        + 1
         + 32
         + (4 + name.len())
         + (4 + description.len())
         + (4 + args.layout.len())
         + (4 + args.field_names_bytes.len())
         + 1
+        + 1
+        + (4 + args.field_constraints.len())
+        + 1
+        + 1
+        + 32
+        + 1
+        + 8
+        + 32
+        + 32
+        + 32
+        + 4
         + 1;
     let rent = Rent::get()?;
-    let bump_seed = [schema_bump];
     let signer_seeds = [
         Seed::from(SCHEMA_SEED),
         Seed::from(credential_info.key()),
@@ -110,19 +168,6 @@ pub fn process_change_schema_version(
         None,
     )?;
 
-    let schema = Schema {
-        credential: *credential_info.key(),
-        name: name.to_vec(),
-        description,
-        layout: args.layout.to_vec(),
-        field_names: args.field_names_bytes.to_vec(),
-        is_paused: false,
-        version: version[0],
-    };
-
-    // Checks that layout and field names are valid.
-    schema.validate(args.field_names_count)?;
-
     let mut schema_data = new_schema_info.try_borrow_mut_data()?;
     schema_data.copy_from_slice(&schema.to_bytes());
 
@@ -133,6 +178,8 @@ struct ChangeSchemaVersionArgs<'a> {
     layout: &'a [u8],
     field_names_count: u32,
     field_names_bytes: &'a [u8],
+    bump: u8,
+    field_constraints: &'a [u8],
 }
 
 fn process_instruction_data(data: &[u8]) -> Result<ChangeSchemaVersionArgs, ProgramError> {
@@ -162,10 +209,29 @@ fn process_instruction_data(data: &[u8]) -> Result<ChangeSchemaVersionArgs, Prog
 
     require_len!(data, offset + byte_len);
     let field_names_bytes = &data[offset..offset + byte_len];
+    offset += byte_len;
+
+    require_len!(data, offset + 1);
+    let bump = data[offset];
+    offset += 1;
+
+    // Optional so instructions encoded before constraints could be set on a
+    // new version still create versions without constraints.
+    let field_constraints: &[u8] = match data.get(offset..offset + 4) {
+        Some(len_bytes) => {
+            let constraints_len = u32::from_le_bytes(len_bytes.try_into().unwrap()) as usize;
+            offset += 4;
+            require_len!(data, offset + constraints_len);
+            &data[offset..offset + constraints_len]
+        }
+        None => &[],
+    };
 
     Ok(ChangeSchemaVersionArgs {
         layout,
         field_names_count,
         field_names_bytes,
+        bump,
+        field_constraints,
     })
 }
@@ -11,20 +11,24 @@ use crate::{
     constants::{event_authority_pda, EVENT_AUTHORITY_SEED},
     error::AttestationServiceError,
     events::{CloseAttestationEvent, EventDiscriminators},
-    state::{Attestation, Credential},
+    state::{
+        discriminator::AccountSerialize, Attestation, AttestationRef, CredentialRef,
+        RefClosePolicy, SchemaRef, NO_REF_ATTESTATION,
+    },
 };
 
 use super::{
-    verify_current_program, verify_owner_mutability, verify_signer, verify_system_program,
+    invoke_resolver, split_resolver_accounts, verify_current_layout, verify_current_program,
+    verify_owner_mutability, verify_signer, verify_system_program, ResolverHook,
 };
 
 #[inline(always)]
 pub fn process_close_attestation(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    token_account: Option<Pubkey>,
+    tokenized: bool,
 ) -> ProgramResult {
-    let [payer_info, authorized_signer, credential_info, attestation_info, event_authority_info, system_program, attestation_program] =
+    let [payer_info, authorized_signer, credential_info, attestation_info, event_authority_info, system_program, attestation_program, remaining_accounts @ ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -45,19 +49,15 @@ pub fn process_close_attestation(
 
     // Check that one of credential's authorized signers have signed.
     let credential_data = credential_info.try_borrow_data()?;
-    let credential = Credential::try_from_bytes(&credential_data)?;
+    let credential = CredentialRef::try_from_bytes(&credential_data)?;
     credential.validate_authorized_signer(authorized_signer.key())?;
 
     let attestation_data = attestation_info.try_borrow_data()?;
-    let attestation = Attestation::try_from_bytes(&attestation_data)?;
-    drop(attestation_data); // Drop immutable borrow.
+    let attestation = AttestationRef::try_from_bytes(&attestation_data)?;
 
-    // Verify token_account matches address in Attestation
-    if let Some(token_account) = token_account {
-        if token_account.ne(&attestation.token_account) {
-            return Err(AttestationServiceError::InvalidTokenAccount.into());
-        }
-    } else if attestation.token_account.ne(&Pubkey::default()) {
+    // Verify tokenized Attestations are closed with their token. The token
+    // may have moved from the account in the Attestation if it is transferable.
+    if tokenized == attestation.token_account.eq(&Pubkey::default()) {
         return Err(AttestationServiceError::InvalidTokenAccount.into());
     }
 
@@ -66,6 +66,52 @@ pub fn process_close_attestation(
         return Err(AttestationServiceError::InvalidCredential.into());
     }
 
+    // Validate a referenced Attestation may be closed. Its Schema is passed
+    // after the other accounts.
+    if attestation.ref_count > 0 {
+        let schema_info = find_account(remaining_accounts, attestation.schema)?;
+        verify_owner_mutability(schema_info, program_id, false)?;
+        let schema_data = schema_info.try_borrow_data()?;
+        let schema = SchemaRef::try_from_bytes(&schema_data)?;
+        if RefClosePolicy::try_from(schema.ref_close_policy)? == RefClosePolicy::Block {
+            return Err(AttestationServiceError::AttestationReferenced.into());
+        }
+    }
+
+    // Remove the reference from the Attestation this one references, passed
+    // after the other accounts. It may already be closed if its Schema allows
+    // closing referenced Attestations.
+    if attestation.ref_attestation.ne(&NO_REF_ATTESTATION) {
+        let ref_attestation_info = find_account(remaining_accounts, attestation.ref_attestation)?;
+        if ref_attestation_info.is_owned_by(program_id) {
+            verify_owner_mutability(ref_attestation_info, program_id, true)?;
+            // Legacy accounts must be migrated before they are modified.
+            verify_current_layout::<Attestation>(ref_attestation_info)?;
+            let mut ref_attestation =
+                Attestation::try_from_bytes(&ref_attestation_info.try_borrow_data()?)?;
+            ref_attestation.ref_count = ref_attestation.ref_count.saturating_sub(1);
+            ref_attestation_info
+                .try_borrow_mut_data()?
+                .copy_from_slice(&ref_attestation.to_bytes());
+        }
+    }
+
+    // Serialize the event while the Attestation data is still borrowed.
+    let event = CloseAttestationEvent {
+        discriminator: EventDiscriminators::CloseEvent as u8,
+        schema: *attestation.schema,
+        attestation_data: attestation.data.to_vec(),
+        ref_count: attestation.ref_count,
+    }
+    .to_bytes();
+    let resolver_program = *attestation.resolver_program;
+    drop(attestation_data); // Drop immutable borrow.
+
+    // Let the resolver the Attestation was created with reject closing it.
+    // The resolver accounts are passed after the other accounts.
+    let (_, resolver_accounts) = split_resolver_accounts(remaining_accounts, &resolver_program)?;
+    invoke_resolver(ResolverHook::Close, attestation_info, resolver_accounts)?;
+
     // Close account and transfer rent to payer.
     let payer_lamports = payer_info.lamports();
     *payer_info.try_borrow_mut_lamports().unwrap() = payer_lamports
@@ -80,16 +126,11 @@ pub fn process_close_attestation(
     }
 
     // CPI to emit_event ix on same program to store event data in ix arg.
-    let event = CloseAttestationEvent {
-        discriminator: EventDiscriminators::CloseEvent as u8,
-        schema: attestation.schema,
-        attestation_data: attestation.data,
-    };
     invoke_signed(
         &Instruction {
             program_id,
             accounts: &[AccountMeta::new(event_authority_info.key(), false, true)],
-            data: event.to_bytes().as_slice(),
+            data: event.as_slice(),
         },
         &[event_authority_info],
         &[Signer::from(&[
@@ -100,3 +141,13 @@ pub fn process_close_attestation(
 
     Ok(())
 }
+
+fn find_account<'a>(
+    accounts: &'a [AccountInfo],
+    key: &Pubkey,
+) -> Result<&'a AccountInfo, ProgramError> {
+    accounts
+        .iter()
+        .find(|account| account.key().eq(key))
+        .ok_or(ProgramError::NotEnoughAccountKeys)
+}
@@ -5,14 +5,19 @@ use pinocchio::{
     pubkey::Pubkey,
     ProgramResult,
 };
-use solana_program::pubkey::Pubkey as SolanaPubkey;
 
 use crate::{
     constants::{sas_pda, ATTESTATION_MINT_SEED, SAS_SEED},
     error::AttestationServiceError,
-    processor::verify_token22_program,
+    processor::{
+        is_frozen_token_account, is_program_address, mint_has_extension, read_token_account,
+        verify_token22_program, PERMANENT_DELEGATE_EXTENSION_TYPE,
+    },
+    require_len,
+};
+use pinocchio_token::instructions::{
+    BurnChecked, CloseAccount, FreezeAccount, ThawAccount, TokenProgramVariant,
 };
-use pinocchio_token::instructions::{BurnChecked, CloseAccount, TokenProgramVariant};
 
 use super::process_close_attestation;
 
@@ -20,8 +25,10 @@ use super::process_close_attestation;
 pub fn process_close_tokenized_attestation(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
+    instruction_data: &[u8],
 ) -> ProgramResult {
-    let [payer_info, _authorized_signer, _credential_info, attestation_info, _event_authority_info, _system_program, _attestation_program, attestation_mint_info, sas_pda_info, attestation_token_account, token_program] =
+    let args = process_instruction_data(instruction_data)?;
+    let [payer_info, _authorized_signer, _credential_info, attestation_info, _event_authority_info, _system_program, _attestation_program, attestation_mint_info, sas_pda_info, attestation_token_account, token_program, ..] =
         accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
@@ -31,14 +38,15 @@ pub fn process_close_tokenized_attestation(
     verify_token22_program(token_program)?;
 
     // Validate that mint matches expected PDA
-    let (attestation_mint_pda, _) = SolanaPubkey::find_program_address(
-        &[ATTESTATION_MINT_SEED, attestation_info.key()],
-        &SolanaPubkey::from(*program_id),
-    );
-    if attestation_mint_info
-        .key()
-        .ne(&attestation_mint_pda.to_bytes())
-    {
+    if !is_program_address(
+        attestation_mint_info.key(),
+        &[
+            ATTESTATION_MINT_SEED,
+            attestation_info.key(),
+            &[args.attestation_mint_bump],
+        ],
+        program_id,
+    ) {
         return Err(AttestationServiceError::InvalidMint.into());
     }
 
@@ -50,18 +58,59 @@ pub fn process_close_tokenized_attestation(
     let bump_seed = [sas_pda::BUMP];
     let sas_pda_seeds = [Seed::from(SAS_SEED), Seed::from(&bump_seed)];
 
-    // Burn Attestation Token
-    BurnChecked {
-        account: attestation_token_account,
-        mint: attestation_mint_info,
-        authority: sas_pda_info,
-        amount: 1,
-        decimals: 0,
+    let (token_mint, _, amount) = read_token_account(attestation_token_account)?;
+    if token_mint.ne(attestation_mint_info.key()) {
+        return Err(AttestationServiceError::InvalidTokenAccount.into());
+    }
+
+    // Without the permanent delegate, the program cannot burn a token its
+    // holder keeps. The Attestation is revoked by freezing the token with the
+    // mint's freeze authority instead, so the holder can no longer use or
+    // move it. The mint stays open while the token exists.
+    let permanent_delegate =
+        mint_has_extension(attestation_mint_info, PERMANENT_DELEGATE_EXTENSION_TYPE)?;
+    if amount > 0 && !permanent_delegate {
+        if !is_frozen_token_account(attestation_token_account)? {
+            FreezeAccount {
+                account: attestation_token_account,
+                mint: attestation_mint_info,
+                freeze_authority: sas_pda_info,
+            }
+            .invoke_signed(
+                &[Signer::from(&sas_pda_seeds)],
+                TokenProgramVariant::Token2022,
+            )?;
+        }
+        return process_close_attestation(program_id, accounts, true);
+    }
+
+    // Burn the Attestation Token, unless its holder already burned it.
+    if amount > 0 {
+        // Tokens frozen on expiry must be thawed before they can be burned.
+        if is_frozen_token_account(attestation_token_account)? {
+            ThawAccount {
+                account: attestation_token_account,
+                mint: attestation_mint_info,
+                freeze_authority: sas_pda_info,
+            }
+            .invoke_signed(
+                &[Signer::from(&sas_pda_seeds)],
+                TokenProgramVariant::Token2022,
+            )?;
+        }
+
+        BurnChecked {
+            account: attestation_token_account,
+            mint: attestation_mint_info,
+            authority: sas_pda_info,
+            amount: 1,
+            decimals: 0,
+        }
+        .invoke_signed(
+            &[Signer::from(&sas_pda_seeds)],
+            TokenProgramVariant::Token2022,
+        )?;
     }
-    .invoke_signed(
-        &[Signer::from(&sas_pda_seeds)],
-        TokenProgramVariant::Token2022,
-    )?;
 
     // Close Attestation Token Mint
     CloseAccount {
@@ -75,13 +124,24 @@ pub fn process_close_tokenized_attestation(
     )?;
 
     // Close Attestation: This needs to be called after closing of Mint due to Solana
-    // limitations around lamports balance. This also verifies accounts[0..7] and
-    // attestation_token_account.
-    process_close_attestation(
-        program_id,
-        &accounts[0..7],
-        Some(*attestation_token_account.key()),
-    )?;
+    // limitations around lamports balance. This also verifies accounts[0..7],
+    // and finds the accounts of references among the rest. Closing the Mint
+    // fails while its token has not been burned, so an empty
+    // attestation_token_account cannot hide the holder's token.
+    process_close_attestation(program_id, accounts, true)?;
 
     Ok(())
 }
+
+struct CloseTokenizedAttestationArgs {
+    attestation_mint_bump: u8,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<CloseTokenizedAttestationArgs, ProgramError> {
+    require_len!(data, 1);
+    let attestation_mint_bump = data[0];
+
+    Ok(CloseTokenizedAttestationArgs {
+        attestation_mint_bump,
+    })
+}
@@ -6,16 +6,22 @@ use pinocchio::{
     sysvars::{clock::Clock, rent::Rent, Sysvar},
     ProgramResult,
 };
-use solana_program::pubkey::Pubkey as SolanaPubkey;
 
 use crate::{
     constants::ATTESTATION_SEED,
     error::AttestationServiceError,
     require_len,
-    state::{discriminator::AccountSerialize, Attestation, Credential, Schema},
+    state::{
+        discriminator::AccountSerialize, validate_data, Attestation, AttestationRef,
+        CommitmentScheme, CredentialRef, SchemaRef, SignerKind, NO_REF_ATTESTATION, NO_REF_SCHEMA,
+    },
 };
 
-use super::{create_pda_account, verify_owner_mutability, verify_signer, verify_system_program};
+use super::{
+    collect_fee, create_pda_account, invoke_resolver, is_program_address, split_resolver_accounts,
+    verify_current_layout, verify_owner_mutability, verify_signer, verify_system_program,
+    ResolverHook,
+};
 
 #[inline(always)]
 pub fn process_create_attestation(
@@ -25,15 +31,55 @@ pub fn process_create_attestation(
     token_account: Option<Pubkey>,
 ) -> ProgramResult {
     let args = process_instruction_data(instruction_data)?;
-    let [payer_info, authorized_signer, credential_info, schema_info, attestation_info, system_program] =
-        accounts
-    else {
+    let (accounts, remaining_accounts) = split_remaining_accounts(accounts, 6)?;
+    let [_payer_info, authorized_signer, ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
     // Validate: authority should have signed
     verify_signer(authorized_signer, false)?;
 
+    create_authorized_attestation(
+        program_id,
+        accounts,
+        &args,
+        SignerKind::Ed25519,
+        token_account,
+        remaining_accounts,
+    )
+}
+
+/// Split the accounts of an instruction that creates an Attestation into its
+/// `count` accounts and the remaining accounts that follow them: the accounts
+/// paying the Schema's fee, if it charges one, the optional referenced
+/// Attestation and its Credential when it is another Credential's, then the
+/// resolver accounts if the Schema has a resolver.
+pub fn split_remaining_accounts(
+    accounts: &[AccountInfo],
+    count: usize,
+) -> Result<(&[AccountInfo], &[AccountInfo]), ProgramError> {
+    if accounts.len() < count {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    Ok(accounts.split_at(count))
+}
+
+/// Create an Attestation from the CreateAttestation accounts, once the caller
+/// has verified that the authority, a key of `signer_kind`, approved it.
+pub fn create_authorized_attestation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    args: &CreateAttestationArgs,
+    signer_kind: SignerKind,
+    token_account: Option<Pubkey>,
+    remaining_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [payer_info, authorized_signer, credential_info, schema_info, attestation_info, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
     // Validate system program
     verify_system_program(system_program)?;
     // Validate Credential and Schema are owned by our program
@@ -41,13 +87,18 @@ pub fn process_create_attestation(
     verify_owner_mutability(schema_info, program_id, false)?;
 
     let credential_data = credential_info.try_borrow_data()?;
-    let credential = Credential::try_from_bytes(&credential_data)?;
+    let credential = CredentialRef::try_from_bytes(&credential_data)?;
+
+    // Validate Credential is not paused
+    if credential.is_paused {
+        return Err(AttestationServiceError::CredentialPaused.into());
+    }
 
     // Validate Authority is an authorized signer
-    credential.validate_authorized_signer(authorized_signer.key())?;
+    credential.validate_signer(authorized_signer.key(), signer_kind)?;
 
     let schema_data = schema_info.try_borrow_data()?;
-    let schema = Schema::try_from_bytes(&schema_data)?;
+    let schema = SchemaRef::try_from_bytes(&schema_data)?;
 
     // Validate Schema is not paused
     if schema.is_paused {
@@ -65,28 +116,70 @@ pub fn process_create_attestation(
         return Err(AttestationServiceError::InvalidAttestationData.into());
     }
 
-    // NOTE: this could be optimized further by removing the `solana-program` dependency
-    // and using `pubkey::checked_create_program_address` from Pinocchio to verify the
-    // pubkey and associated bump (needed to be added as arg) is valid.
-    let (attestation_pda, attestation_bump) = SolanaPubkey::find_program_address(
+    // Collect the Schema's issuance fee from the payer.
+    let remaining_accounts = collect_fee(payer_info, &schema, remaining_accounts)?;
+    let (remaining_accounts, resolver_accounts) =
+        split_resolver_accounts(remaining_accounts, schema.resolver_program)?;
+
+    // Validate the referenced Attestation, which the Schema may require.
+    let ref_attestation = match remaining_accounts {
+        [ref_attestation_info, ..] => {
+            add_attestation_ref(
+                program_id,
+                remaining_accounts,
+                credential_info.key(),
+                authorized_signer.key(),
+                signer_kind,
+                schema.ref_schema,
+                clock.unix_timestamp,
+            )?;
+            *ref_attestation_info.key()
+        }
+        [] if schema.ref_schema.ne(&NO_REF_SCHEMA) => {
+            return Err(AttestationServiceError::InvalidRefAttestation.into());
+        }
+        [] => NO_REF_ATTESTATION,
+    };
+
+    // Validate attestation PDA is correct
+    let bump_seed = [args.bump];
+    if !is_program_address(
+        attestation_info.key(),
         &[
             ATTESTATION_SEED,
             credential_info.key(),
             schema_info.key(),
             &args.nonce,
+            &bump_seed,
         ],
-        &SolanaPubkey::from(*program_id),
-    );
-
-    // Validate attestation PDA is correct
-    if attestation_info.key().ne(&attestation_pda.to_bytes()) {
+        program_id,
+    ) {
         return Err(AttestationServiceError::InvalidAttestation.into());
     }
 
+    validate_schema_data(&schema, args.data)?;
+
+    let token_account = token_account.unwrap_or_default();
+    let attestation = AttestationRef {
+        nonce: &args.nonce,
+        credential: credential_info.key(),
+        schema: schema_info.key(),
+        data: args.data,
+        signer: authorized_signer.key(),
+        expiry: args.expiry,
+        token_account: &token_account,
+        bump: args.bump,
+        signer_kind: signer_kind as u8,
+        ref_attestation: &ref_attestation,
+        ref_count: 0,
+        resolver_program: schema.resolver_program,
+    };
+
     // Create Attestation account
 
     // Account layout
     // discriminator - 1
+    // layout_version - 1
     // nonce - 32
     // Credential - 32
     // Schema - 32
@@ -94,9 +187,13 @@ pub fn process_create_attestation(
     // signer - 32
     // expiry - 8
     // token account - 32
-    let space = 1 + 32 + 32 + 32 + (4 + args.data.len()) + 32 + 8 + 32;
+    // bump - 1
+    // signer_kind - 1
+    // ref_attestation - 32
+    // ref_count - 4
+    // resolver_program - 32
+    let space = 1 + 1 + 32 + 32 + 32 + (4 + args.data.len()) + 32 + 8 + 32 + 1 + 1 + 32 + 4 + 32;
 
-    let bump_seed = [attestation_bump];
     let signer_seeds = [
         Seed::from(ATTESTATION_SEED),
         Seed::from(credential_info.key()),
@@ -116,32 +213,84 @@ pub fn process_create_attestation(
         None,
     )?;
 
-    let attestation = Attestation {
-        nonce: args.nonce,
-        credential: *credential_info.key(),
-        schema: *schema_info.key(),
-        data: args.data.to_vec(),
-        signer: *authorized_signer.key(),
-        expiry: args.expiry,
-        token_account: token_account.unwrap_or_default(),
+    attestation_info
+        .try_borrow_mut_data()?
+        .copy_from_slice(&attestation.to_bytes());
+
+    // Let the Schema's resolver reject the new Attestation.
+    invoke_resolver(ResolverHook::Create, attestation_info, resolver_accounts)
+}
+
+/// Validate Attestation `data` matches the layout of the Schema, or is a
+/// commitment when the Schema keeps its data private.
+pub fn validate_schema_data(schema: &SchemaRef, data: &[u8]) -> Result<(), ProgramError> {
+    let commitment_scheme = CommitmentScheme::try_from(schema.commitment_scheme)?;
+    if commitment_scheme.validates_data() {
+        validate_data(data, schema.layout, schema.field_constraints)?;
+    } else if !commitment_scheme.is_commitment(schema.layout.len(), data) {
+        return Err(AttestationServiceError::InvalidAttestationData.into());
+    }
+    Ok(())
+}
+
+/// Validate the referenced Attestation, the first of `ref_accounts`, is open
+/// and, when `ref_schema` is set, of that Schema, and count the new reference
+/// to it. An Attestation of another Credential than `credential` can only be
+/// referenced when `signer` is also an authorized signer of that Credential,
+/// which follows it in `ref_accounts`, since references can block closing it.
+fn add_attestation_ref(
+    program_id: &Pubkey,
+    ref_accounts: &[AccountInfo],
+    credential: &Pubkey,
+    signer: &Pubkey,
+    signer_kind: SignerKind,
+    ref_schema: &Pubkey,
+    now: i64,
+) -> ProgramResult {
+    let [ref_attestation_info, ref_credential_info @ ..] = ref_accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
     };
+    verify_owner_mutability(ref_attestation_info, program_id, true)?;
+    let mut ref_attestation =
+        Attestation::try_from_bytes(&ref_attestation_info.try_borrow_data()?)?;
+    // Legacy accounts must be migrated before they are modified.
+    verify_current_layout::<Attestation>(ref_attestation_info)?;
 
-    // Validate the Attestation data matches the layout of the Schema
-    attestation.validate_data(schema.layout)?;
+    if ref_attestation.expiry != 0 && ref_attestation.expiry < now {
+        return Err(AttestationServiceError::AttestationExpired.into());
+    }
+    if ref_schema.ne(&NO_REF_SCHEMA) && ref_attestation.schema.ne(ref_schema) {
+        return Err(AttestationServiceError::InvalidRefAttestation.into());
+    }
+    if ref_attestation.credential.ne(credential) {
+        let ref_credential_info = ref_credential_info
+            .first()
+            .filter(|info| info.key().eq(&ref_attestation.credential))
+            .ok_or(AttestationServiceError::InvalidRefAttestation)?;
+        verify_owner_mutability(ref_credential_info, program_id, false)?;
+        CredentialRef::try_from_bytes(&ref_credential_info.try_borrow_data()?)?
+            .validate_signer(signer, signer_kind)?;
+    }
 
-    let mut attestation_data = attestation_info.try_borrow_mut_data()?;
-    attestation_data.copy_from_slice(&attestation.to_bytes());
+    ref_attestation.ref_count = ref_attestation
+        .ref_count
+        .checked_add(1)
+        .ok_or(AttestationServiceError::ArithmeticOverflow)?;
+    ref_attestation_info
+        .try_borrow_mut_data()?
+        .copy_from_slice(&ref_attestation.to_bytes());
 
     Ok(())
 }
 
-struct CreateAttestationArgs<'a> {
-    nonce: Pubkey,
-    data: &'a [u8],
-    expiry: i64,
+pub struct CreateAttestationArgs<'a> {
+    pub nonce: Pubkey,
+    pub data: &'a [u8],
+    pub expiry: i64,
+    pub bump: u8,
 }
 
-fn process_instruction_data(data: &[u8]) -> Result<CreateAttestationArgs, ProgramError> {
+pub(crate) fn process_instruction_data(data: &[u8]) -> Result<CreateAttestationArgs, ProgramError> {
     let mut offset: usize = 0;
 
     require_len!(data, 32);
@@ -158,10 +307,15 @@ fn process_instruction_data(data: &[u8]) -> Result<CreateAttestationArgs, Progra
 
     require_len!(data, offset + 8);
     let expiry = i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+    offset += 8;
+
+    require_len!(data, offset + 1);
+    let bump = data[offset];
 
     Ok(CreateAttestationArgs {
         nonce,
         data: data_bytes,
         expiry,
+        bump,
     })
 }
@@ -0,0 +1,134 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    constants::ATTESTATION_REQUEST_SEED,
+    error::AttestationServiceError,
+    state::{discriminator::AccountSerialize, AttestationRequest, CredentialRef, SchemaRef},
+};
+
+use super::{
+    create_attestation::process_instruction_data, create_pda_account, is_program_address,
+    validate_schema_data, verify_owner_mutability, verify_signer, verify_system_program,
+};
+
+#[inline(always)]
+pub fn process_create_attestation_request(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [subject_info, credential_info, schema_info, attestation_request_info, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: subject should have signed, and pays for the request
+    verify_signer(subject_info, true)?;
+    // Validate system program
+    verify_system_program(system_program)?;
+    // Validate Credential and Schema are owned by our program
+    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(schema_info, program_id, false)?;
+
+    let credential_data = credential_info.try_borrow_data()?;
+    let credential = CredentialRef::try_from_bytes(&credential_data)?;
+
+    // Validate Credential is not paused
+    if credential.is_paused {
+        return Err(AttestationServiceError::CredentialPaused.into());
+    }
+
+    let schema_data = schema_info.try_borrow_data()?;
+    let schema = SchemaRef::try_from_bytes(&schema_data)?;
+
+    // Validate Schema is not paused
+    if schema.is_paused {
+        return Err(AttestationServiceError::SchemaPaused.into());
+    }
+
+    // Validate Schema is owned by Credential
+    if schema.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+
+    // Validate expiry is greater than current timestamp
+    let clock = Clock::get()?;
+    if args.expiry < clock.unix_timestamp && args.expiry != 0 {
+        return Err(AttestationServiceError::InvalidAttestationData.into());
+    }
+
+    // Validate the proposed data the same way CreateAttestation does, which
+    // only accepts the commitment for Schemas with a commitment scheme.
+    validate_schema_data(&schema, args.data)?;
+
+    // Validate request PDA is correct
+    let bump_seed = [args.bump];
+    if !is_program_address(
+        attestation_request_info.key(),
+        &[
+            ATTESTATION_REQUEST_SEED,
+            credential_info.key(),
+            schema_info.key(),
+            &args.nonce,
+            &bump_seed,
+        ],
+        program_id,
+    ) {
+        return Err(AttestationServiceError::InvalidAttestationRequest.into());
+    }
+
+    // Account layout
+    // discriminator - 1
+    // layout_version - 1
+    // subject - 32
+    // nonce - 32
+    // Credential - 32
+    // Schema - 32
+    // data - 4 + len
+    // expiry - 8
+    // bump - 1
+    let space = 1 + 1 + 32 + 32 + 32 + 32 + (4 + args.data.len()) + 8 + 1;
+
+    let signer_seeds = [
+        Seed::from(ATTESTATION_REQUEST_SEED),
+        Seed::from(credential_info.key()),
+        Seed::from(schema_info.key()),
+        Seed::from(&args.nonce),
+        Seed::from(&bump_seed),
+    ];
+
+    let rent = Rent::get()?;
+    create_pda_account(
+        subject_info,
+        &rent,
+        space,
+        program_id,
+        attestation_request_info,
+        signer_seeds,
+        None,
+    )?;
+
+    let attestation_request = AttestationRequest {
+        subject: *subject_info.key(),
+        nonce: args.nonce,
+        credential: *credential_info.key(),
+        schema: *schema_info.key(),
+        data: args.data.to_vec(),
+        expiry: args.expiry,
+        bump: args.bump,
+    };
+
+    let mut attestation_request_data = attestation_request_info.try_borrow_mut_data()?;
+    attestation_request_data.copy_from_slice(&attestation_request.to_bytes());
+
+    Ok(())
+}
@@ -0,0 +1,177 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use solana_sha256_hasher::hashv;
+
+use crate::{error::AttestationServiceError, require_len, state::SignerKind};
+
+use super::{
+    attestation_message, consume_attestation_message, create_attestation::process_instruction_data,
+    create_authorized_attestation, split_remaining_accounts, verify_signature,
+};
+
+// Authenticator data layout: rpIdHash - 32, flags - 1, signCount - 4
+const AUTHENTICATOR_DATA_MIN_LEN: usize = 32 + 1 + 4;
+const AUTHENTICATOR_FLAGS_OFFSET: usize = 32;
+const USER_PRESENT_FLAG: u8 = 0x01;
+const WEBAUTHN_GET_TYPE: &[u8] = br#""type":"webauthn.get""#;
+const CHALLENGE_KEY: &[u8] = br#""challenge":""#;
+const BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+#[inline(always)]
+pub fn process_create_attestation_with_passkey(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (webauthn_args, args_offset) = process_webauthn_data(instruction_data)?;
+    let args = process_instruction_data(&instruction_data[args_offset..])?;
+    // The bump of the used message PDA follows the CreateAttestation arguments.
+    let used_message_bump_offset = args_offset + 32 + 4 + args.data.len() + 8 + 1;
+    require_len!(instruction_data, used_message_bump_offset + 1);
+    let used_message_bump = instruction_data[used_message_bump_offset];
+
+    let (accounts, remaining_accounts) = split_remaining_accounts(accounts, 8)?;
+    let [payer_info, authority_info, credential_info, schema_info, _attestation_info, _system_program, instructions_sysvar, used_message_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate the signed message has not expired
+    let clock = Clock::get()?;
+    if webauthn_args.deadline < clock.unix_timestamp {
+        return Err(AttestationServiceError::SignatureExpired.into());
+    }
+
+    // Validate the WebAuthn assertion challenges the attestation digest
+    let message = attestation_message(
+        program_id,
+        credential_info.key(),
+        schema_info.key(),
+        &args.nonce,
+        args.data,
+        args.expiry,
+        webauthn_args.deadline,
+    );
+    let digest = hashv(&[message.as_slice()]).to_bytes();
+    validate_client_data(webauthn_args.client_data_json, &digest)?;
+    let authenticator_data = webauthn_args.authenticator_data;
+    if authenticator_data.len() < AUTHENTICATOR_DATA_MIN_LEN
+        || authenticator_data[AUTHENTICATOR_FLAGS_OFFSET] & USER_PRESENT_FLAG == 0
+    {
+        return Err(AttestationServiceError::InvalidWebAuthnAssertion.into());
+    }
+
+    // Validate: the passkey should have signed the assertion, which is
+    // authenticator data followed by the hash of the client data.
+    let client_data_hash = hashv(&[webauthn_args.client_data_json]).to_bytes();
+    let signed_message = [authenticator_data, client_data_hash.as_slice()].concat();
+    if verify_signature(instructions_sysvar, authority_info.key(), &signed_message)?
+        != SignerKind::Secp256r1
+    {
+        return Err(AttestationServiceError::InvalidSignature.into());
+    }
+    consume_attestation_message(
+        program_id,
+        payer_info,
+        used_message_info,
+        &message,
+        used_message_bump,
+    )?;
+
+    create_authorized_attestation(
+        program_id,
+        &accounts[0..6],
+        &args,
+        SignerKind::Secp256r1,
+        None,
+        remaining_accounts,
+    )
+}
+
+/// Validate the client data is for an assertion whose challenge is `digest`.
+/// The origin is not checked, since the program cannot know the relying party.
+fn validate_client_data(client_data_json: &[u8], digest: &[u8; 32]) -> Result<(), ProgramError> {
+    let challenge = [
+        CHALLENGE_KEY,
+        base64url_encode(digest).as_slice(),
+        b"\"".as_slice(),
+    ]
+    .concat();
+    if !contains(client_data_json, WEBAUTHN_GET_TYPE) || !contains(client_data_json, &challenge) {
+        return Err(AttestationServiceError::InvalidWebAuthnAssertion.into());
+    }
+    Ok(())
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+/// Unpadded base64url encoding, as used for WebAuthn challenges.
+fn base64url_encode(bytes: &[u8]) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity((bytes.len() * 4).div_ceil(3));
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, byte)| {
+            group | ((*byte as u32) << (16 - 8 * i))
+        });
+        for i in 0..=chunk.len() {
+            encoded.push(BASE64URL_ALPHABET[((group >> (18 - 6 * i)) & 0x3f) as usize]);
+        }
+    }
+    encoded
+}
+
+struct WebAuthnArgs<'a> {
+    deadline: i64,
+    authenticator_data: &'a [u8],
+    client_data_json: &'a [u8],
+}
+
+/// Read the WebAuthn arguments, returning them with the offset of the
+/// CreateAttestation arguments that follow.
+fn process_webauthn_data(data: &[u8]) -> Result<(WebAuthnArgs, usize), ProgramError> {
+    let mut offset: usize = 0;
+
+    require_len!(data, 8);
+    let deadline = i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+    offset += 8;
+
+    require_len!(data, offset + 4);
+    let authenticator_data_len =
+        u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+    offset += 4;
+
+    require_len!(data, offset + authenticator_data_len);
+    let authenticator_data = &data[offset..offset + authenticator_data_len];
+    offset += authenticator_data_len;
+
+    require_len!(data, offset + 4);
+    let client_data_json_len =
+        u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+    offset += 4;
+
+    require_len!(data, offset + client_data_json_len);
+    let client_data_json = &data[offset..offset + client_data_json_len];
+    offset += client_data_json_len;
+
+    Ok((
+        WebAuthnArgs {
+            deadline,
+            authenticator_data,
+            client_data_json,
+        },
+        offset,
+    ))
+}
@@ -0,0 +1,150 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+use solana_sha256_hasher::hashv;
+
+use crate::{
+    constants::{ATTESTATION_MESSAGE_DOMAIN, USED_MESSAGE_SEED},
+    error::AttestationServiceError,
+    require_len,
+    state::SignerKind,
+};
+
+use super::{
+    create_attestation::process_instruction_data, create_authorized_attestation,
+    create_pda_account, is_program_address, split_remaining_accounts, verify_signature,
+};
+
+#[inline(always)]
+pub fn process_create_attestation_with_signature(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // The deadline is followed by the CreateAttestation arguments.
+    require_len!(instruction_data, 8);
+    let deadline = i64::from_le_bytes(instruction_data[0..8].try_into().unwrap());
+    let args = process_instruction_data(&instruction_data[8..])?;
+    // The bump of the used message PDA follows the CreateAttestation arguments.
+    let used_message_bump_offset = 8 + 32 + 4 + args.data.len() + 8 + 1;
+    require_len!(instruction_data, used_message_bump_offset + 1);
+    let used_message_bump = instruction_data[used_message_bump_offset];
+
+    let (accounts, remaining_accounts) = split_remaining_accounts(accounts, 8)?;
+    let [payer_info, authority_info, credential_info, schema_info, _attestation_info, _system_program, instructions_sysvar, used_message_info] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate the signed message has not expired
+    let clock = Clock::get()?;
+    if deadline < clock.unix_timestamp {
+        return Err(AttestationServiceError::SignatureExpired.into());
+    }
+
+    // Validate: authority should have signed the attestation message.
+    let message = attestation_message(
+        program_id,
+        credential_info.key(),
+        schema_info.key(),
+        &args.nonce,
+        args.data,
+        args.expiry,
+        deadline,
+    );
+    let signer_kind = verify_signature(instructions_sysvar, authority_info.key(), &message)?;
+    // Passkeys approve Attestations through WebAuthn assertions, which only
+    // CreateAttestationWithPasskey checks.
+    if signer_kind == SignerKind::Secp256r1 {
+        return Err(AttestationServiceError::InvalidSignature.into());
+    }
+    consume_attestation_message(
+        program_id,
+        payer_info,
+        used_message_info,
+        &message,
+        used_message_bump,
+    )?;
+
+    create_authorized_attestation(
+        program_id,
+        &accounts[0..6],
+        &args,
+        signer_kind,
+        None,
+        remaining_accounts,
+    )
+}
+
+/// Message an authorized signer signs off-chain to approve an Attestation.
+/// Ethereum signers sign it as an EIP-191 personal message.
+pub fn attestation_message(
+    program_id: &Pubkey,
+    credential: &Pubkey,
+    schema: &Pubkey,
+    nonce: &Pubkey,
+    data: &[u8],
+    expiry: i64,
+    deadline: i64,
+) -> Vec<u8> {
+    [
+        ATTESTATION_MESSAGE_DOMAIN,
+        program_id.as_slice(),
+        credential.as_slice(),
+        schema.as_slice(),
+        nonce.as_slice(),
+        (data.len() as u32).to_le_bytes().as_slice(),
+        data,
+        expiry.to_le_bytes().as_slice(),
+        deadline.to_le_bytes().as_slice(),
+    ]
+    .concat()
+}
+
+/// Create the PDA (`["usedMessage", sha256(message)]`) marking the attestation
+/// `message` as used. It is never closed, so the message cannot create
+/// another Attestation after the first one is closed.
+pub fn consume_attestation_message(
+    program_id: &Pubkey,
+    payer_info: &AccountInfo,
+    used_message_info: &AccountInfo,
+    message: &[u8],
+    bump: u8,
+) -> ProgramResult {
+    let message_hash = hashv(&[message]).to_bytes();
+    let bump_seed = [bump];
+    if !is_program_address(
+        used_message_info.key(),
+        &[USED_MESSAGE_SEED, &message_hash, &bump_seed],
+        program_id,
+    ) {
+        return Err(AttestationServiceError::InvalidUsedMessage.into());
+    }
+    if used_message_info.is_owned_by(program_id) {
+        return Err(AttestationServiceError::MessageAlreadyUsed.into());
+    }
+
+    let signer_seeds = [
+        Seed::from(USED_MESSAGE_SEED),
+        Seed::from(&message_hash),
+        Seed::from(&bump_seed),
+    ];
+    create_pda_account(
+        payer_info,
+        &Rent::get()?,
+        0,
+        program_id,
+        used_message_info,
+        signer_seeds,
+        None,
+    )
+}
@@ -1,6 +1,6 @@
 extern crate alloc;
 
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use pinocchio::{
     account_info::AccountInfo,
     instruction::Seed,
@@ -9,14 +9,16 @@ use pinocchio::{
     sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
-use solana_program::pubkey::Pubkey as SolanaPubkey;
 
 use crate::{
     constants::CREDENTIAL_SEED,
     error::AttestationServiceError,
-    processor::{create_pda_account, verify_signer, verify_system_account, verify_system_program},
+    processor::{
+        create_pda_account, is_program_address, verify_signer, verify_system_account,
+        verify_system_program,
+    },
     require_len,
-    state::{discriminator::AccountSerialize, Credential},
+    state::{discriminator::AccountSerialize, Credential, SignerKind},
 };
 
 #[inline(always)]
@@ -37,28 +39,57 @@ pub fn process_create_credential(
     // Validate: system program
     verify_system_program(system_program)?;
 
-    // NOTE: this could be optimized further by removing the `solana-program` dependency
-    // and using `pubkey::checked_create_program_address` from Pinocchio to verify the
-    // pubkey and associated bump (needed to be added as arg) is valid.
-    let (credential_pda, credential_bump) = SolanaPubkey::find_program_address(
-        &[CREDENTIAL_SEED, authority_info.key(), args.name],
-        &SolanaPubkey::from(*program_id),
-    );
-
-    if credential_info.key().ne(&credential_pda.to_bytes()) {
+    let credential = Credential {
+        authority: *authority_info.key(),
+        name: args.name.to_vec(),
+        authorized_signers: args.signers,
+        bump: args.bump,
+        display_name: Vec::new(),
+        description: Vec::new(),
+        uri: Vec::new(),
+        logo_uri: Vec::new(),
+        website: Vec::new(),
+        is_paused: false,
+        passkey_signers: Vec::new(),
+        authorized_signer_kinds: args.signer_kinds,
+    };
+    // Checks that the name is valid before it is used as a PDA seed.
+    credential.validate()?;
+    credential.validate_authorized_signers()?;
+
+    let bump_seed = [args.bump];
+    if !is_program_address(
+        credential_info.key(),
+        &[CREDENTIAL_SEED, authority_info.key(), args.name, &bump_seed],
+        program_id,
+    ) {
         // PDA was invalid
         return Err(AttestationServiceError::InvalidCredential.into());
     }
 
     // Account layout
     // discriminator - 1
+    // layout_version - 1
     // authorized_signers - 4 + 32 * len
     // authority - 32
     // name - 4 + len
-    let space = 1 + (4 + args.signers.len() * 32) + 32 + (4 + args.name.len());
+    // bump - 1
+    // metadata - 4 * 5, empty until UpdateCredentialMetadata
+    // is_paused - 1
+    // passkey_signers - 4 + 33 * len, empty until ChangePasskeySigners
+    // authorized_signer_kinds - 4 + len
+    let space = 1
+        + 1
+        + (4 + credential.authorized_signers.len() * 32)
+        + 32
+        + (4 + args.name.len())
+        + 1
+        + 4 * 5
+        + 1
+        + 4
+        + (4 + credential.authorized_signer_kinds.len());
 
     let rent = Rent::get()?;
-    let bump_seed = [credential_bump];
     let signer_seeds = [
         Seed::from(CREDENTIAL_SEED),
         Seed::from(authority_info.key()),
@@ -75,11 +106,6 @@ pub fn process_create_credential(
         None,
     )?;
 
-    let credential = Credential {
-        authority: *authority_info.key(),
-        name: args.name.to_vec(),
-        authorized_signers: args.signers,
-    };
     let mut credential_data = credential_info.try_borrow_mut_data()?;
     credential_data.copy_from_slice(&credential.to_bytes());
 
@@ -89,6 +115,8 @@ pub fn process_create_credential(
 struct CreateCredentialArgs<'a> {
     name: &'a [u8],
     signers: Vec<Pubkey>,
+    bump: u8,
+    signer_kinds: Vec<u8>,
 }
 
 fn process_instruction_data(data: &[u8]) -> Result<CreateCredentialArgs, ProgramError> {
@@ -114,5 +142,31 @@ fn process_instruction_data(data: &[u8]) -> Result<CreateCredentialArgs, Program
         offset += 32;
     }
 
-    Ok(CreateCredentialArgs { name, signers })
+    require_len!(data, offset + 1);
+    let bump = data[offset];
+    offset += 1;
+
+    let signer_kinds = process_signer_kinds(&data[offset..], signers.len())?;
+
+    Ok(CreateCredentialArgs {
+        name,
+        signers,
+        bump,
+        signer_kinds,
+    })
+}
+
+/// Kinds of `signers_len` authorized signers, read from the optional
+/// `signer_kinds` argument at the start of `data`. Instructions encoded before
+/// signer kinds were added, and empty `signer_kinds`, have ed25519 signers.
+pub fn process_signer_kinds(data: &[u8], signers_len: usize) -> Result<Vec<u8>, ProgramError> {
+    let Some(len_bytes) = data.get(0..4) else {
+        return Ok(vec![SignerKind::Ed25519 as u8; signers_len]);
+    };
+    let kinds_len = u32::from_le_bytes(len_bytes.try_into().unwrap()) as usize;
+    if kinds_len == 0 {
+        return Ok(vec![SignerKind::Ed25519 as u8; signers_len]);
+    }
+    require_len!(data, 4 + kinds_len);
+    Ok(data[4..4 + kinds_len].to_vec())
 }
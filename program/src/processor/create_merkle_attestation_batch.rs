@@ -0,0 +1,154 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    constants::MERKLE_BATCH_SEED,
+    error::AttestationServiceError,
+    require_len,
+    state::{
+        discriminator::AccountSerialize, CredentialRef, MerkleAttestationBatch, SchemaRef,
+        NO_REF_SCHEMA, NO_RESOLVER_PROGRAM,
+    },
+};
+
+use super::{
+    create_pda_account, is_program_address, verify_owner_mutability, verify_signer,
+    verify_system_program,
+};
+
+#[inline(always)]
+pub fn process_create_merkle_attestation_batch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [payer_info, authorized_signer, credential_info, schema_info, merkle_batch_info, system_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authorized_signer, false)?;
+
+    // Validate system program
+    verify_system_program(system_program)?;
+    // Validate Credential and Schema are owned by our program
+    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(schema_info, program_id, false)?;
+
+    let credential_data = credential_info.try_borrow_data()?;
+    let credential = CredentialRef::try_from_bytes(&credential_data)?;
+
+    // Validate Credential is not paused
+    if credential.is_paused {
+        return Err(AttestationServiceError::CredentialPaused.into());
+    }
+
+    // Validate Authority is an authorized signer
+    credential.validate_authorized_signer(authorized_signer.key())?;
+
+    let schema_data = schema_info.try_borrow_data()?;
+    let schema = SchemaRef::try_from_bytes(&schema_data)?;
+
+    // Validate Schema is not paused
+    if schema.is_paused {
+        return Err(AttestationServiceError::SchemaPaused.into());
+    }
+
+    // Validate Schema is owned by Credential
+    if schema.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+
+    // Validate the Schema has no per-Attestation rules a batch cannot apply:
+    // a resolver, a required referenced Attestation or an issuance fee.
+    if schema.resolver_program.ne(&NO_RESOLVER_PROGRAM)
+        || schema.ref_schema.ne(&NO_REF_SCHEMA)
+        || schema.fee != 0
+    {
+        return Err(AttestationServiceError::InvalidSchema.into());
+    }
+
+    // Validate batch PDA is correct
+    let bump_seed = [args.bump];
+    if !is_program_address(
+        merkle_batch_info.key(),
+        &[
+            MERKLE_BATCH_SEED,
+            credential_info.key(),
+            schema_info.key(),
+            &args.nonce,
+            &bump_seed,
+        ],
+        program_id,
+    ) {
+        return Err(AttestationServiceError::InvalidAttestation.into());
+    }
+
+    // Account layout
+    // discriminator - 1
+    // layout_version - 1
+    // nonce - 32
+    // Credential - 32
+    // Schema - 32
+    // root - 32
+    // signer - 32
+    // bump - 1
+    let space = 1 + 1 + 32 + 32 + 32 + 32 + 32 + 1;
+
+    let signer_seeds = [
+        Seed::from(MERKLE_BATCH_SEED),
+        Seed::from(credential_info.key()),
+        Seed::from(schema_info.key()),
+        Seed::from(&args.nonce),
+        Seed::from(&bump_seed),
+    ];
+
+    let rent = Rent::get()?;
+    create_pda_account(
+        payer_info,
+        &rent,
+        space,
+        program_id,
+        merkle_batch_info,
+        signer_seeds,
+        None,
+    )?;
+
+    let merkle_batch = MerkleAttestationBatch {
+        nonce: args.nonce,
+        credential: *credential_info.key(),
+        schema: *schema_info.key(),
+        root: args.root,
+        signer: *authorized_signer.key(),
+        bump: args.bump,
+    };
+
+    let mut merkle_batch_data = merkle_batch_info.try_borrow_mut_data()?;
+    merkle_batch_data.copy_from_slice(&merkle_batch.to_bytes());
+
+    Ok(())
+}
+
+struct CreateMerkleAttestationBatchArgs {
+    nonce: Pubkey,
+    root: [u8; 32],
+    bump: u8,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<CreateMerkleAttestationBatchArgs, ProgramError> {
+    require_len!(data, 32 + 32 + 1);
+    let nonce: Pubkey = data[0..32].try_into().unwrap();
+    let root: [u8; 32] = data[32..64].try_into().unwrap();
+    let bump = data[64];
+
+    Ok(CreateMerkleAttestationBatchArgs { nonce, root, bump })
+}
@@ -1,3 +1,6 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
 use pinocchio::{
     account_info::AccountInfo,
     instruction::Seed,
@@ -6,14 +9,19 @@ use pinocchio::{
     sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
-use solana_program::pubkey::Pubkey as SolanaPubkey;
 
 use crate::{
     constants::SCHEMA_SEED,
     error::AttestationServiceError,
-    processor::{create_pda_account, verify_signer, verify_system_account, verify_system_program},
+    processor::{
+        create_pda_account, is_program_address, verify_signer, verify_system_account,
+        verify_system_program,
+    },
     require_len,
-    state::{discriminator::AccountSerialize, Credential, Schema},
+    state::{
+        discriminator::AccountSerialize, Credential, RefClosePolicy, Schema, DEFAULT_TOKEN_POLICY,
+        NO_FEE_MINT, NO_REF_SCHEMA, NO_RESOLVER_PROGRAM,
+    },
 };
 
 use super::verify_owner_mutability;
@@ -45,22 +53,57 @@ pub fn process_create_schema(
         return Err(ProgramError::IncorrectAuthority);
     }
 
-    // NOTE: this could be optimized further by removing the `solana-program` dependency
-    // and using `pubkey::checked_create_program_address` from Pinocchio to verify the
-    // pubkey and associated bump (needed to be added as arg) is valid.
     let version = &[1];
-    let (schema_pda, schema_bump) = SolanaPubkey::find_program_address(
-        &[SCHEMA_SEED, credential_info.key(), args.name, version],
-        &SolanaPubkey::from(*program_id),
-    );
 
-    if schema_info.key().ne(&schema_pda.to_bytes()) {
+    let schema = Schema {
+        credential: *credential_info.key(),
+        name: args.name.to_vec(),
+        description: args.description.to_vec(),
+        layout: args.layout.to_vec(),
+        field_names: args.field_names_bytes.to_vec(),
+        is_paused: false,
+        version: version[0],
+        field_constraints: args.field_constraints.to_vec(),
+        bump: args.bump,
+        commitment_scheme: args.commitment_scheme,
+        ref_schema: args.ref_schema,
+        ref_close_policy: args.ref_close_policy,
+        fee: 0,
+        fee_mint: NO_FEE_MINT,
+        fee_treasury: Pubkey::default(),
+        resolver_program: args.resolver_program,
+        token_metadata_fields: Vec::new(),
+        token_policy: DEFAULT_TOKEN_POLICY,
+    };
+
+    // The resolver is invoked by this program, so it cannot be this program.
+    if schema.resolver_program.eq(program_id) {
+        return Err(AttestationServiceError::InvalidResolver.into());
+    }
+
+    // Checks that name, description, layout and field names are valid before
+    // they are used as PDA seeds.
+    schema.validate(args.field_names_count)?;
+
+    let bump_seed = [args.bump];
+    if !is_program_address(
+        schema_info.key(),
+        &[
+            SCHEMA_SEED,
+            credential_info.key(),
+            args.name,
+            version,
+            &bump_seed,
+        ],
+        program_id,
+    ) {
         // PDA was invalid
         return Err(AttestationServiceError::InvalidSchema.into());
     }
 
     // Account layout
     // discriminator - 1
+    // layout_version - 1
     // credential - 32
     // name - 4 + length
     // description - 4 + length
@@ -68,16 +111,38 @@ pub fn process_create_schema(
     // field_names - 4 + length
     // is_paused - 1
     // version - 1
+    // field_constraints - 4 + length
+    // bump - 1
+    // commitment_scheme - 1
+    // ref_schema - 32
+    // ref_close_policy - 1
+    // fee - 8
+    // fee_mint - 32
+    // fee_treasury - 32
+    // resolver_program - 32
+    // token_metadata_fields - 4
+    // token_policy - 1
     let space = 1
+        + 1
         + 32
         + (4 + args.name.len())
         + (4 + args.description.len())
         + (4 + args.layout.len())
         + (4 + args.field_names_bytes.len())
         + 1
+        + 1
+        + (4 + args.field_constraints.len())
+        + 1
+        + 1
+        + 32
+        + 1
+        + 8
+        + 32
+        + 32
+        + 32
+        + 4
         + 1;
     let rent = Rent::get()?;
-    let bump_seed = [schema_bump];
     let signer_seeds = [
         Seed::from(SCHEMA_SEED),
         Seed::from(credential_info.key()),
@@ -95,19 +160,6 @@ pub fn process_create_schema(
         None,
     )?;
 
-    let schema = Schema {
-        credential: *credential_info.key(),
-        name: args.name.to_vec(),
-        description: args.description.to_vec(),
-        layout: args.layout.to_vec(),
-        field_names: args.field_names_bytes.to_vec(),
-        is_paused: false,
-        version: version[0],
-    };
-
-    // Checks that layout and field names are valid.
-    schema.validate(args.field_names_count)?;
-
     let mut schema_data = schema_info.try_borrow_mut_data()?;
     schema_data.copy_from_slice(&schema.to_bytes());
 
@@ -120,6 +172,12 @@ struct CreateSchemaArgs<'a> {
     layout: &'a [u8],
     field_names_count: u32,
     field_names_bytes: &'a [u8],
+    bump: u8,
+    commitment_scheme: u8,
+    ref_schema: Pubkey,
+    ref_close_policy: u8,
+    resolver_program: Pubkey,
+    field_constraints: &'a [u8],
 }
 
 fn process_instruction_data(data: &[u8]) -> Result<CreateSchemaArgs, ProgramError> {
@@ -165,6 +223,49 @@ fn process_instruction_data(data: &[u8]) -> Result<CreateSchemaArgs, ProgramErro
 
     require_len!(data, offset + byte_len);
     let field_names_bytes = &data[offset..offset + byte_len];
+    offset += byte_len;
+
+    require_len!(data, offset + 1);
+    let bump = data[offset];
+    offset += 1;
+
+    // Optional so instructions encoded before commitment schemes were added
+    // still create plaintext Schemas.
+    let commitment_scheme = data.get(offset).copied().unwrap_or_default();
+    offset += 1;
+
+    // Optional so instructions encoded before references were added still
+    // create Schemas with optional references that do not block closing.
+    let ref_schema: Pubkey = data
+        .get(offset..offset + 32)
+        .map(|bytes| bytes.try_into().unwrap())
+        .unwrap_or(NO_REF_SCHEMA);
+    offset += 32;
+    let ref_close_policy = data
+        .get(offset)
+        .copied()
+        .unwrap_or(RefClosePolicy::AllowAndFlag as u8);
+    offset += 1;
+
+    // Optional so instructions encoded before resolvers were added still
+    // create Schemas without a resolver.
+    let resolver_program: Pubkey = data
+        .get(offset..offset + 32)
+        .map(|bytes| bytes.try_into().unwrap())
+        .unwrap_or(NO_RESOLVER_PROGRAM);
+    offset += 32;
+
+    // Optional so instructions encoded before constraints could be set at
+    // creation still create Schemas without constraints.
+    let field_constraints: &[u8] = match data.get(offset..offset + 4) {
+        Some(len_bytes) => {
+            let constraints_len = u32::from_le_bytes(len_bytes.try_into().unwrap()) as usize;
+            offset += 4;
+            require_len!(data, offset + constraints_len);
+            &data[offset..offset + constraints_len]
+        }
+        None => &[],
+    };
 
     Ok(CreateSchemaArgs {
         name,
@@ -172,5 +273,11 @@ fn process_instruction_data(data: &[u8]) -> Result<CreateSchemaArgs, ProgramErro
         layout,
         field_names_count,
         field_names_bytes,
+        bump,
+        commitment_scheme,
+        ref_schema,
+        ref_close_policy,
+        resolver_program,
+        field_constraints,
     })
 }
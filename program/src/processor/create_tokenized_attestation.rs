@@ -20,16 +20,27 @@ use pinocchio_token::{
     instructions::{InitializeMint2, MintToChecked, TokenProgramVariant},
     TOKEN_2022_PROGRAM_ID,
 };
-use solana_program::pubkey::Pubkey as SolanaPubkey;
 
 use crate::{
-    constants::{sas_pda, ATTESTATION_MINT_SEED, SAS_SEED, SCHEMA_MINT_SEED},
+    constants::{
+        sas_pda, ATTESTATION_METADATA_KEY, ATTESTATION_MINT_SEED, SAS_SEED, SCHEMA_METADATA_KEY,
+        SCHEMA_MINT_SEED,
+    },
     error::AttestationServiceError,
-    processor::process_create_attestation,
     require_len,
+    state::{
+        AttestationRef, SchemaRef, SignerKind, TOKEN_POLICY_NON_TRANSFERABLE,
+        TOKEN_POLICY_PERMANENT_DELEGATE,
+    },
 };
 
-use super::{create_pda_account, verify_ata_program, verify_token22_program};
+use super::{
+    attestation_mint_base_len, attestation_mint_space,
+    create_attestation::process_instruction_data as process_create_attestation_data,
+    create_authorized_attestation, create_pda_account, is_program_address,
+    split_remaining_accounts, verify_ata_program, verify_signer, verify_token22_program,
+    write_token_metadata,
+};
 
 #[inline(always)]
 pub fn process_create_tokenized_attestation(
@@ -37,21 +48,45 @@ pub fn process_create_tokenized_attestation(
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    let [payer_info, _authorized_signer, _credential_info, schema_info, attestation_info, system_program, schema_mint_info, attestation_mint_info, sas_pda_info, recipient_token_account_info, recipient_info, token_program, ata_program] =
-        accounts
+    let args = process_instruction_data(instruction_data)?;
+
+    let (accounts, remaining_accounts) = split_remaining_accounts(accounts, 13)?;
+    let [payer_info, authorized_signer, .., recipient_token_account_info, _, _, _] = accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    // Validate: authority should have signed
+    verify_signer(authorized_signer, false)?;
+
     // Create Attestation first
-    process_create_attestation(
+    create_authorized_attestation(
         program_id,
         &accounts[0..6],
-        instruction_data,
+        &process_create_attestation_data(instruction_data)?,
+        SignerKind::Ed25519,
         Some(*recipient_token_account_info.key()),
+        remaining_accounts,
     )?;
 
-    let args = process_instruction_data(instruction_data)?;
+    // Mint the Attestation token to the recipient.
+    mint_attestation_token(program_id, payer_info, &accounts[3..], &args)
+}
+
+/// Create the mint of an Attestation's token as a member of its Schema's
+/// group mint, with the token metadata, and mint the token to the recipient's
+/// associated token account.
+pub fn mint_attestation_token(
+    program_id: &Pubkey,
+    payer_info: &AccountInfo,
+    accounts: &[AccountInfo],
+    args: &AttestationTokenArgs,
+) -> ProgramResult {
+    let [schema_info, attestation_info, system_program, schema_mint_info, attestation_mint_info, sas_pda_info, recipient_token_account_info, recipient_info, token_program, ata_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
 
     // Validate Recipient TokenAccount is writable
     if !recipient_token_account_info.is_writable() {
@@ -63,22 +98,27 @@ pub fn process_create_tokenized_attestation(
     verify_ata_program(ata_program)?;
 
     // Validate that mint matches expected PDA
-    let (attestation_mint_pda, attestation_mint_bump) = SolanaPubkey::find_program_address(
-        &[ATTESTATION_MINT_SEED, attestation_info.key()],
-        &SolanaPubkey::from(*program_id),
-    );
-    if attestation_mint_info
-        .key()
-        .ne(&attestation_mint_pda.to_bytes())
-    {
+    let attestation_mint_bump_seed = [args.attestation_mint_bump];
+    if !is_program_address(
+        attestation_mint_info.key(),
+        &[
+            ATTESTATION_MINT_SEED,
+            attestation_info.key(),
+            &attestation_mint_bump_seed,
+        ],
+        program_id,
+    ) {
         return Err(AttestationServiceError::InvalidMint.into());
     }
-    let (schema_mint_pda, _) = SolanaPubkey::find_program_address(
-        &[SCHEMA_MINT_SEED, schema_info.key()],
-        &SolanaPubkey::from(*program_id),
-    );
-
-    if schema_mint_info.key().ne(&schema_mint_pda.to_bytes()) {
+    if !is_program_address(
+        schema_mint_info.key(),
+        &[
+            SCHEMA_MINT_SEED,
+            schema_info.key(),
+            &[args.schema_mint_bump],
+        ],
+        program_id,
+    ) {
         return Err(AttestationServiceError::InvalidMint.into());
     }
 
@@ -87,21 +127,39 @@ pub fn process_create_tokenized_attestation(
         return Err(AttestationServiceError::InvalidProgramSigner.into());
     }
 
+    // Extensions enabled by the Schema's token policy.
+    let token_policy = SchemaRef::try_from_bytes(&schema_info.try_borrow_data()?)?.token_policy;
+    let non_transferable = token_policy & TOKEN_POLICY_NON_TRANSFERABLE != 0;
+    let permanent_delegate = token_policy & TOKEN_POLICY_PERMANENT_DELEGATE != 0;
+
+    let attestation_value = bs58::encode(attestation_info.key()).into_string();
+    let schema_value = bs58::encode(schema_info.key()).into_string();
+    let mint_space = attestation_mint_space(
+        token_policy,
+        args.name,
+        args.symbol,
+        args.uri,
+        &[
+            (ATTESTATION_METADATA_KEY, &attestation_value),
+            (SCHEMA_METADATA_KEY, &schema_value),
+        ],
+    );
+
     // Initialize new account owned by token_program.
     create_pda_account(
         payer_info,
         &Rent::get()?,
-        378, // Size before Token extensions after InitializeMint2
+        attestation_mint_base_len(token_policy), // Size before Token extensions after InitializeMint2
         &TOKEN_2022_PROGRAM_ID,
         attestation_mint_info,
         [
             Seed::from(ATTESTATION_MINT_SEED),
             Seed::from(attestation_info.key()),
-            Seed::from(&[attestation_mint_bump]),
+            Seed::from(&attestation_mint_bump_seed),
         ],
-        // Sufficient rent needs to be allocated or instruction fails with
-        // "Lamport balance below rent-exempt threshold" or "InsufficientFundsForRent".
-        Some(args.mint_account_space.into()),
+        // Fund the mint with the group member and the token metadata. The
+        // expiry, signer and Schema fields are topped up when they are set.
+        Some(mint_space),
     )?;
 
     // Initialize GroupMemberPointer extension
@@ -113,10 +171,12 @@ pub fn process_create_tokenized_attestation(
     .invoke()?;
 
     // Initialize NonTransferable extension
-    InitializeNonTransferableMint {
-        mint: attestation_mint_info,
+    if non_transferable {
+        InitializeNonTransferableMint {
+            mint: attestation_mint_info,
+        }
+        .invoke()?;
     }
-    .invoke()?;
 
     // Initialize MetadataPointer extension
     InitializeMetadataPointer {
@@ -127,11 +187,13 @@ pub fn process_create_tokenized_attestation(
     .invoke()?;
 
     // Initialize Permanent Delegate extension
-    InitializePermanentDelegate {
-        mint: attestation_mint_info,
-        delegate: *sas_pda_info.key(),
+    if permanent_delegate {
+        InitializePermanentDelegate {
+            mint: attestation_mint_info,
+            delegate: *sas_pda_info.key(),
+        }
+        .invoke()?;
     }
-    .invoke()?;
 
     // Initialize Mint Close extension
     InitializeMintCloseAuthority {
@@ -158,9 +220,9 @@ pub fn process_create_tokenized_attestation(
         update_authority: sas_pda_info,
         mint: attestation_mint_info,
         mint_authority: sas_pda_info,
-        name: core::str::from_utf8(args.name).unwrap(),
-        symbol: core::str::from_utf8(args.symbol).unwrap(),
-        uri: core::str::from_utf8(args.uri).unwrap(),
+        name: args.name,
+        symbol: args.symbol,
+        uri: args.uri,
     }
     .invoke_signed(&[Signer::from(&sas_pda_seeds)])?;
 
@@ -168,16 +230,16 @@ pub fn process_create_tokenized_attestation(
     UpdateField {
         metadata: attestation_mint_info,
         update_authority: sas_pda_info,
-        field: Field::Key("attestation"),
-        value: &bs58::encode(attestation_info.key()).into_string(),
+        field: Field::Key(ATTESTATION_METADATA_KEY),
+        value: &attestation_value,
     }
     .invoke_signed(&[Signer::from(&sas_pda_seeds)])?;
 
     UpdateField {
         metadata: attestation_mint_info,
         update_authority: sas_pda_info,
-        field: Field::Key("schema"),
-        value: &bs58::encode(schema_info.key()).into_string(),
+        field: Field::Key(SCHEMA_METADATA_KEY),
+        value: &schema_value,
     }
     .invoke_signed(&[Signer::from(&sas_pda_seeds)])?;
 
@@ -191,6 +253,16 @@ pub fn process_create_tokenized_attestation(
     }
     .invoke_signed(&[Signer::from(&sas_pda_seeds)])?;
 
+    // Mirror the Attestation's expiry, signer and fields configured on the
+    // Schema into the token metadata.
+    write_token_metadata(
+        payer_info,
+        attestation_mint_info,
+        sas_pda_info,
+        &AttestationRef::try_from_bytes(&attestation_info.try_borrow_data()?)?,
+        &SchemaRef::try_from_bytes(&schema_info.try_borrow_data()?)?,
+    )?;
+
     // Only create the ATA when the TokenAccount is owned by the System program with empty data.
     // Create new associated token account to hold Attestation token.
     CreateIdempotent {
@@ -219,27 +291,30 @@ pub fn process_create_tokenized_attestation(
     Ok(())
 }
 
-struct CreateTokenizedAttestationArgs<'a> {
-    name: &'a [u8],
-    uri: &'a [u8],
-    symbol: &'a [u8],
-    mint_account_space: u16,
+pub struct AttestationTokenArgs<'a> {
+    pub name: &'a str,
+    pub uri: &'a str,
+    pub symbol: &'a str,
+    pub schema_mint_bump: u8,
+    pub attestation_mint_bump: u8,
 }
 
-fn process_instruction_data(data: &[u8]) -> Result<CreateTokenizedAttestationArgs, ProgramError> {
+fn process_instruction_data(data: &[u8]) -> Result<AttestationTokenArgs, ProgramError> {
     let mut offset: usize = 32; // Skip Nonce
 
     require_len!(data, offset + 4);
     let data_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
     offset += 4 + data_len; // Skip Data field
     offset += 8; // Skip Expiry
+    offset += 1; // Skip Bump
 
     require_len!(data, offset + 4);
     let name_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
     offset += 4;
 
     require_len!(data, offset + name_len);
-    let name = &data[offset..offset + name_len];
+    let name = core::str::from_utf8(&data[offset..offset + name_len])
+        .map_err(|_| AttestationServiceError::InvalidUtf8)?;
     offset += name_len;
 
     require_len!(data, offset + 4);
@@ -247,7 +322,8 @@ fn process_instruction_data(data: &[u8]) -> Result<CreateTokenizedAttestationArg
     offset += 4;
 
     require_len!(data, offset + uri_len);
-    let uri = &data[offset..offset + uri_len];
+    let uri = core::str::from_utf8(&data[offset..offset + uri_len])
+        .map_err(|_| AttestationServiceError::InvalidUtf8)?;
     offset += uri_len;
 
     require_len!(data, offset + 4);
@@ -255,16 +331,24 @@ fn process_instruction_data(data: &[u8]) -> Result<CreateTokenizedAttestationArg
     offset += 4;
 
     require_len!(data, offset + symbol_len);
-    let symbol = &data[offset..offset + symbol_len];
+    let symbol = core::str::from_utf8(&data[offset..offset + symbol_len])
+        .map_err(|_| AttestationServiceError::InvalidUtf8)?;
     offset += symbol_len;
 
+    // mint_account_space is ignored, the mint space is computed from the
+    // token metadata.
+    require_len!(data, offset + 2);
+    offset += 2;
+
     require_len!(data, offset + 2);
-    let mint_account_space = u16::from_le_bytes(data[offset..offset + 2].try_into().unwrap());
+    let schema_mint_bump = data[offset];
+    let attestation_mint_bump = data[offset + 1];
 
-    Ok(CreateTokenizedAttestationArgs {
+    Ok(AttestationTokenArgs {
         name,
         uri,
         symbol,
-        mint_account_space,
+        schema_mint_bump,
+        attestation_mint_bump,
     })
 }
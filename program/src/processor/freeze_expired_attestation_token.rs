@@ -0,0 +1,104 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+use pinocchio_token::instructions::{FreezeAccount, TokenProgramVariant};
+
+use crate::{
+    constants::{sas_pda, ATTESTATION_MINT_SEED, SAS_SEED},
+    error::AttestationServiceError,
+    processor::{is_program_address, verify_owner_mutability, verify_token22_program},
+    require_len,
+    state::{AttestationRef, SchemaRef, TOKEN_POLICY_FREEZE_ON_EXPIRY},
+};
+
+#[inline(always)]
+pub fn process_freeze_expired_attestation_token(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [attestation_info, schema_info, attestation_mint_info, sas_pda_info, attestation_token_account, token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate Attestation and Schema are owned by our program
+    verify_owner_mutability(attestation_info, program_id, false)?;
+    verify_owner_mutability(schema_info, program_id, false)?;
+    verify_token22_program(token_program)?;
+
+    let attestation_data = attestation_info.try_borrow_data()?;
+    let attestation = AttestationRef::try_from_bytes(&attestation_data)?;
+    if attestation.schema.ne(schema_info.key()) {
+        return Err(AttestationServiceError::InvalidSchema.into());
+    }
+
+    // Validate the Schema's token policy freezes expired tokens.
+    let schema_data = schema_info.try_borrow_data()?;
+    let schema = SchemaRef::try_from_bytes(&schema_data)?;
+    if schema.token_policy & TOKEN_POLICY_FREEZE_ON_EXPIRY == 0 {
+        return Err(AttestationServiceError::InvalidTokenPolicy.into());
+    }
+
+    // Validate the Attestation has expired.
+    let now = Clock::get()?.unix_timestamp;
+    if attestation.expiry == 0 || attestation.expiry >= now {
+        return Err(AttestationServiceError::AttestationNotExpired.into());
+    }
+
+    // Validate that mint matches expected PDA
+    if !is_program_address(
+        attestation_mint_info.key(),
+        &[
+            ATTESTATION_MINT_SEED,
+            attestation_info.key(),
+            &[args.attestation_mint_bump],
+        ],
+        program_id,
+    ) {
+        return Err(AttestationServiceError::InvalidMint.into());
+    }
+
+    // Validate that sas_pda matches
+    if sas_pda_info.key().ne(&sas_pda::ID) {
+        return Err(AttestationServiceError::InvalidProgramSigner.into());
+    }
+
+    // Freeze the token account. The token program verifies it is an account
+    // of the Attestation mint.
+    let bump_seed = [sas_pda::BUMP];
+    let sas_pda_seeds = [Seed::from(SAS_SEED), Seed::from(&bump_seed)];
+    FreezeAccount {
+        account: attestation_token_account,
+        mint: attestation_mint_info,
+        freeze_authority: sas_pda_info,
+    }
+    .invoke_signed(
+        &[Signer::from(&sas_pda_seeds)],
+        TokenProgramVariant::Token2022,
+    )?;
+
+    Ok(())
+}
+
+struct FreezeExpiredAttestationTokenArgs {
+    attestation_mint_bump: u8,
+}
+
+fn process_instruction_data(
+    data: &[u8],
+) -> Result<FreezeExpiredAttestationTokenArgs, ProgramError> {
+    require_len!(data, 1);
+    let attestation_mint_bump = data[0];
+
+    Ok(FreezeExpiredAttestationTokenArgs {
+        attestation_mint_bump,
+    })
+}
@@ -0,0 +1,165 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::Transfer;
+
+use crate::{
+    constants::{ATTESTATION_SEED, CREDENTIAL_SEED, SCHEMA_SEED},
+    error::AttestationServiceError,
+    processor::{
+        is_program_address, verify_owner_mutability, verify_signer, verify_system_program,
+    },
+    require_len,
+    state::{
+        discriminator::{is_current_layout, AccountSerialize, Discriminator},
+        Attestation, Credential, Schema,
+    },
+};
+
+#[inline(always)]
+pub fn process_migrate_account(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [payer_info, account_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: payer should have signed
+    verify_signer(payer_info, true)?;
+    // Validate: system program
+    verify_system_program(system_program)?;
+    // Verify program ownership and mutability.
+    verify_owner_mutability(account_info, program_id, true)?;
+
+    let data = account_info.try_borrow_data()?;
+    let bump_seed = [args.bump];
+    // Legacy accounts did not store their bump, so the bump passed in is
+    // verified against the seeds read from the account before it is stored.
+    let migrated = match data.first().copied() {
+        discriminator if discriminator == Credential::LEGACY_DISCRIMINATOR => {
+            let credential = Credential {
+                bump: args.bump,
+                ..Credential::try_from_bytes(&data)?
+            };
+            if !is_program_address(
+                account_info.key(),
+                &[
+                    CREDENTIAL_SEED,
+                    &credential.authority,
+                    &credential.name,
+                    &bump_seed,
+                ],
+                program_id,
+            ) {
+                return Err(AttestationServiceError::InvalidCredential.into());
+            }
+            credential.to_bytes()
+        }
+        discriminator if discriminator == Schema::LEGACY_DISCRIMINATOR => {
+            let schema = Schema {
+                bump: args.bump,
+                ..Schema::try_from_bytes(&data)?
+            };
+            if !is_program_address(
+                account_info.key(),
+                &[
+                    SCHEMA_SEED,
+                    &schema.credential,
+                    &schema.name,
+                    &[schema.version],
+                    &bump_seed,
+                ],
+                program_id,
+            ) {
+                return Err(AttestationServiceError::InvalidSchema.into());
+            }
+            schema.to_bytes()
+        }
+        discriminator if discriminator == Attestation::LEGACY_DISCRIMINATOR => {
+            let attestation = Attestation {
+                bump: args.bump,
+                ..Attestation::try_from_bytes(&data)?
+            };
+            if !is_program_address(
+                account_info.key(),
+                &[
+                    ATTESTATION_SEED,
+                    &attestation.credential,
+                    &attestation.schema,
+                    &attestation.nonce,
+                    &bump_seed,
+                ],
+                program_id,
+            ) {
+                return Err(AttestationServiceError::InvalidAttestation.into());
+            }
+            attestation.to_bytes()
+        }
+        // Accounts with an older layout version already store their bump.
+        Some(Credential::DISCRIMINATOR) if !is_current_layout::<Credential>(&data) => {
+            Credential::try_from_bytes(&data)?.to_bytes()
+        }
+        Some(Schema::DISCRIMINATOR) if !is_current_layout::<Schema>(&data) => {
+            Schema::try_from_bytes(&data)?.to_bytes()
+        }
+        Some(Attestation::DISCRIMINATOR) if !is_current_layout::<Attestation>(&data) => {
+            Attestation::try_from_bytes(&data)?.to_bytes()
+        }
+        Some(Credential::DISCRIMINATOR)
+        | Some(Schema::DISCRIMINATOR)
+        | Some(Attestation::DISCRIMINATOR) => {
+            msg!("Account already uses the current layout");
+            return Ok(());
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+    drop(data); // Drop immutable borrow.
+
+    // Resize account to the current layout.
+    let prev_space = account_info.data_len();
+    let new_space = migrated.len();
+    if new_space != prev_space {
+        account_info.realloc(new_space, false)?;
+        let diff = new_space.saturating_sub(prev_space);
+        if diff > 0 {
+            // top up lamports to account for additional rent.
+            let rent = Rent::get()?;
+            let min_rent = rent.minimum_balance(new_space);
+            let current_rent = account_info.lamports();
+            let rent_diff = min_rent.saturating_sub(current_rent);
+            if rent_diff > 0 {
+                Transfer {
+                    from: payer_info,
+                    to: account_info,
+                    lamports: rent_diff,
+                }
+                .invoke()?;
+            }
+        }
+    }
+
+    // Write migrated data.
+    let mut account_data = account_info.try_borrow_mut_data()?;
+    account_data.copy_from_slice(&migrated);
+
+    Ok(())
+}
+
+struct MigrateAccountArgs {
+    bump: u8,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<MigrateAccountArgs, ProgramError> {
+    require_len!(data, 1);
+    let bump = data[0];
+
+    Ok(MigrateAccountArgs { bump })
+}
@@ -1,27 +1,63 @@
+pub mod approve_attestation_request;
 pub mod change_authorized_signers;
+pub mod change_credential_status;
+pub mod change_passkey_signers;
 pub mod change_schema_description;
+pub mod change_schema_fee;
 pub mod change_schema_status;
+pub mod change_schema_token_metadata_fields;
 pub mod change_schema_version;
 pub mod close_attestation;
 pub mod close_tokenized_attestation;
 pub mod create_attestation;
+pub mod create_attestation_request;
+pub mod create_attestation_with_passkey;
+pub mod create_attestation_with_signature;
 pub mod create_credential;
+pub mod create_merkle_attestation_batch;
 pub mod create_schema;
 pub mod create_tokenized_attestation;
 pub mod emit_event;
+pub mod freeze_expired_attestation_token;
+pub mod migrate_account;
+pub mod reject_attestation_request;
+pub mod revoke_merkle_attestation_batch;
 pub mod shared;
+pub mod tokenize_attestation;
 pub mod tokenize_schema;
+pub mod update_credential_metadata;
+pub mod update_schema_token_metadata;
+pub mod verify_merkle_attestation;
+pub mod withdraw_treasury;
 
+pub use approve_attestation_request::*;
 pub use change_authorized_signers::*;
+pub use change_credential_status::*;
+pub use change_passkey_signers::*;
 pub use change_schema_description::*;
+pub use change_schema_fee::*;
 pub use change_schema_status::*;
+pub use change_schema_token_metadata_fields::*;
 pub use change_schema_version::*;
 pub use close_attestation::*;
 pub use close_tokenized_attestation::*;
 pub use create_attestation::*;
+pub use create_attestation_request::*;
+pub use create_attestation_with_passkey::*;
+pub use create_attestation_with_signature::*;
 pub use create_credential::*;
+pub use create_merkle_attestation_batch::*;
 pub use create_schema::*;
 pub use create_tokenized_attestation::*;
 pub use emit_event::*;
+pub use freeze_expired_attestation_token::*;
+pub use migrate_account::*;
+pub use reject_attestation_request::*;
+pub use revoke_merkle_attestation_batch::*;
 pub use shared::*;
+pub use tokenize_attestation::*;
 pub use tokenize_schema::*;
+pub use update_credential_metadata::*;
+pub use update_schema_token_metadata::*;
+pub use verify_merkle_attestation::*;
+pub use withdraw_treasury::*;
@@ -0,0 +1,59 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+use crate::{
+    error::AttestationServiceError,
+    state::{AttestationRequest, CredentialRef},
+};
+
+use super::{verify_owner_mutability, verify_signer};
+
+#[inline(always)]
+pub fn process_reject_attestation_request(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [authorized_signer, credential_info, attestation_request_info, subject_info] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authorized_signer, false)?;
+
+    // Validate Credential and AttestationRequest are owned by our program
+    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(attestation_request_info, program_id, true)?;
+
+    // Check that one of credential's authorized signers have signed.
+    let credential_data = credential_info.try_borrow_data()?;
+    let credential = CredentialRef::try_from_bytes(&credential_data)?;
+    credential.validate_authorized_signer(authorized_signer.key())?;
+
+    let attestation_request =
+        AttestationRequest::try_from_bytes(&attestation_request_info.try_borrow_data()?)?;
+
+    // Validate the request is for this Credential and refunds its subject.
+    if attestation_request.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+    if attestation_request.subject.ne(subject_info.key()) {
+        return Err(AttestationServiceError::InvalidAttestationRequest.into());
+    }
+
+    close_attestation_request(attestation_request_info, subject_info)
+}
+
+/// Close an AttestationRequest and refund its rent to the subject.
+pub fn close_attestation_request(
+    attestation_request_info: &AccountInfo,
+    subject_info: &AccountInfo,
+) -> ProgramResult {
+    let subject_lamports = subject_info.lamports();
+    *subject_info.try_borrow_mut_lamports()? = subject_lamports
+        .checked_add(attestation_request_info.lamports())
+        .ok_or(AttestationServiceError::ArithmeticOverflow)?;
+    *attestation_request_info.try_borrow_mut_lamports()? = 0;
+    attestation_request_info.close()
+}
@@ -0,0 +1,50 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+use crate::{
+    error::AttestationServiceError,
+    state::{CredentialRef, MerkleAttestationBatch},
+};
+
+use super::{verify_owner_mutability, verify_signer};
+
+#[inline(always)]
+pub fn process_revoke_merkle_attestation_batch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [payer_info, authorized_signer, credential_info, merkle_batch_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authorized_signer, false)?;
+
+    // Validate Credential and batch are owned by our program
+    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(merkle_batch_info, program_id, true)?;
+
+    // Check that one of credential's authorized signers have signed.
+    let credential_data = credential_info.try_borrow_data()?;
+    let credential = CredentialRef::try_from_bytes(&credential_data)?;
+    credential.validate_authorized_signer(authorized_signer.key())?;
+
+    // Check that credential matches the batch's.
+    let merkle_batch =
+        MerkleAttestationBatch::try_from_bytes(&merkle_batch_info.try_borrow_data()?)?;
+    if merkle_batch.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+
+    // Close account and transfer rent to payer, revoking every Attestation in
+    // the batch.
+    let payer_lamports = payer_info.lamports();
+    *payer_info.try_borrow_mut_lamports().unwrap() = payer_lamports
+        .checked_add(merkle_batch_info.lamports())
+        .unwrap();
+    *merkle_batch_info.try_borrow_mut_lamports().unwrap() = 0;
+    merkle_batch_info.close()?;
+
+    Ok(())
+}
@@ -4,7 +4,14 @@ use pinocchio_associated_token_account::ID as ATA_PROGRAM_ID;
 use pinocchio_log::log;
 use pinocchio_token::TOKEN_2022_PROGRAM_ID;
 
-use crate::{acc_info_as_str, key_as_str, ID};
+use crate::{
+    acc_info_as_str,
+    constants::{INSTRUCTIONS_SYSVAR_ID, TOKEN_PROGRAM_ID},
+    error::AttestationServiceError,
+    key_as_str,
+    state::discriminator::{is_current_layout, Discriminator},
+    ID,
+};
 
 /// Verify account as a signer, returning an error if it is not or if it is not writable while
 /// expected to be.
@@ -97,6 +104,22 @@ pub fn verify_token22_program(info: &AccountInfo) -> Result<(), ProgramError> {
     Ok(())
 }
 
+/// Verify account as the SPL Token or Token 2022 program, returning an error if it is neither.
+///
+/// # Arguments
+/// * `info` - The account to verify.
+///
+/// # Returns
+/// * `Result<(), ProgramError>` - The result of the operation
+pub fn verify_token_program(info: &AccountInfo) -> Result<(), ProgramError> {
+    if info.key().ne(&TOKEN_PROGRAM_ID) && info.key().ne(&TOKEN_2022_PROGRAM_ID) {
+        log!("Account {} is not a Token program", acc_info_as_str!(info));
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    Ok(())
+}
+
 /// Verify account as Associated Token program, returning an error if it is not.
 ///
 /// # Arguments
@@ -116,6 +139,25 @@ pub fn verify_ata_program(info: &AccountInfo) -> Result<(), ProgramError> {
     Ok(())
 }
 
+/// Verify account as the instructions sysvar, returning an error if it is not.
+///
+/// # Arguments
+/// * `info` - The account to verify.
+///
+/// # Returns
+/// * `Result<(), ProgramError>` - The result of the operation
+pub fn verify_instructions_sysvar(info: &AccountInfo) -> Result<(), ProgramError> {
+    if info.key().ne(&INSTRUCTIONS_SYSVAR_ID) {
+        log!(
+            "Account {} is not the instructions sysvar",
+            acc_info_as_str!(info)
+        );
+        return Err(ProgramError::UnsupportedSysvar);
+    }
+
+    Ok(())
+}
+
 /// Verify account as current program, returning an error if it is not.
 ///
 /// # Arguments
@@ -167,3 +209,20 @@ pub fn verify_owner_mutability(
 
     Ok(())
 }
+
+/// Verify account data uses the current layout of `T`. Legacy accounts must be
+/// migrated with MigrateAccount before they can be modified.
+///
+/// # Arguments
+/// * `info` - The account to verify.
+///
+/// # Returns
+/// * `Result<(), ProgramError>` - The result of the operation
+pub fn verify_current_layout<T: Discriminator>(info: &AccountInfo) -> Result<(), ProgramError> {
+    if !is_current_layout::<T>(&info.try_borrow_data()?) {
+        log!("Account {} must be migrated", acc_info_as_str!(info));
+        return Err(AttestationServiceError::AccountNotMigrated.into());
+    }
+
+    Ok(())
+}
@@ -1,8 +1,100 @@
 extern crate alloc;
 
 use alloc::vec::Vec;
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::error::AttestationServiceError;
 
 // Serializes an array of bytes to Vector representation by prepending array length.
 pub fn to_serialized_vec(data: &[u8]) -> Vec<u8> {
     [(data.len() as u32).to_le_bytes().as_slice(), data].concat()
 }
+
+/// Validate a byte string is UTF-8 encoded and at most `max_len` bytes.
+pub fn validate_utf8(value: &[u8], max_len: usize) -> Result<(), ProgramError> {
+    if value.len() > max_len {
+        return Err(AttestationServiceError::ValueTooLong.into());
+    }
+    if core::str::from_utf8(value).is_err() {
+        return Err(AttestationServiceError::InvalidUtf8.into());
+    }
+    Ok(())
+}
+
+/// Validate a name is non-empty, UTF-8 encoded and at most `max_len` bytes.
+pub fn validate_name(name: &[u8], max_len: usize) -> Result<(), ProgramError> {
+    if name.is_empty() {
+        return Err(AttestationServiceError::EmptyName.into());
+    }
+    validate_utf8(name, max_len)
+}
+
+/// Bounds-checked cursor over serialized account data.
+pub struct DataReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> DataReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    /// Read the next `len` bytes.
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], ProgramError> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .ok_or(AttestationServiceError::ArithmeticOverflow)?;
+        let bytes = self
+            .data
+            .get(self.offset..end)
+            .ok_or(AttestationServiceError::UnexpectedEndOfData)?;
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    pub fn read_array<const N: usize>(&mut self) -> Result<[u8; N], ProgramError> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.read_bytes(N)?);
+        Ok(array)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, ProgramError> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, ProgramError> {
+        Ok(u16::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, ProgramError> {
+        Ok(u32::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, ProgramError> {
+        Ok(u64::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_i64(&mut self) -> Result<i64, ProgramError> {
+        Ok(i64::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_pubkey(&mut self) -> Result<Pubkey, ProgramError> {
+        self.read_array()
+    }
+
+    /// Borrow the next 32 bytes as a Pubkey without copying.
+    pub fn read_pubkey_ref(&mut self) -> Result<&'a Pubkey, ProgramError> {
+        Ok(self
+            .read_bytes(32)?
+            .first_chunk()
+            .ok_or(AttestationServiceError::UnexpectedEndOfData)?)
+    }
+
+    /// Read a u32 length-prefixed byte vector.
+    pub fn read_vec(&mut self) -> Result<&'a [u8], ProgramError> {
+        let len = self.read_u32()? as usize;
+        self.read_bytes(len)
+    }
+}
@@ -0,0 +1,157 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Signer},
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+use pinocchio_system::instructions::Transfer;
+use pinocchio_token::TOKEN_2022_PROGRAM_ID;
+
+use crate::{
+    constants::TOKEN_PROGRAM_ID,
+    error::AttestationServiceError,
+    state::{SchemaRef, NO_FEE_MINT},
+};
+
+use super::verify_token_program;
+
+// Offsets of fields shared by SPL Token and Token 2022 accounts.
+const TOKEN_ACCOUNT_MINT_OFFSET: usize = 0;
+const TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
+const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+const TOKEN_ACCOUNT_STATE_OFFSET: usize = 108;
+const TOKEN_ACCOUNT_FROZEN: u8 = 2;
+const MINT_DECIMALS_OFFSET: usize = 44;
+
+// Token program instruction discriminator of TransferChecked.
+const TRANSFER_CHECKED_IX: u8 = 12;
+
+/// Transfer the Schema's fee from the payer to the Schema's treasury. The
+/// accounts paying it lead `remaining_accounts`: the treasury for lamport
+/// fees, or the payer's token account, the treasury token account, the fee
+/// mint and its token program for token fees. Returns the accounts after them.
+pub fn collect_fee<'a>(
+    payer_info: &AccountInfo,
+    schema: &SchemaRef,
+    remaining_accounts: &'a [AccountInfo],
+) -> Result<&'a [AccountInfo], ProgramError> {
+    if schema.fee == 0 {
+        return Ok(remaining_accounts);
+    }
+
+    if schema.fee_mint.eq(&NO_FEE_MINT) {
+        let [treasury_info, remaining_accounts @ ..] = remaining_accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+        if treasury_info.key().ne(schema.fee_treasury) {
+            return Err(AttestationServiceError::InvalidTreasury.into());
+        }
+        Transfer {
+            from: payer_info,
+            to: treasury_info,
+            lamports: schema.fee,
+        }
+        .invoke()?;
+        return Ok(remaining_accounts);
+    }
+
+    let [payer_token_account_info, treasury_token_account_info, fee_mint_info, token_program, remaining_accounts @ ..] =
+        remaining_accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if treasury_token_account_info.key().ne(schema.fee_treasury) {
+        return Err(AttestationServiceError::InvalidTreasury.into());
+    }
+    if fee_mint_info.key().ne(schema.fee_mint) {
+        return Err(AttestationServiceError::InvalidMint.into());
+    }
+    transfer_tokens_checked(
+        payer_token_account_info,
+        fee_mint_info,
+        treasury_token_account_info,
+        payer_info,
+        token_program,
+        schema.fee,
+        &[],
+    )?;
+
+    Ok(remaining_accounts)
+}
+
+/// Transfer `amount` tokens of `mint_info` with either token program. The CPI
+/// is built here since pinocchio_token instructions target a single program
+/// variant, while fee mints may belong to SPL Token or Token 2022.
+pub fn transfer_tokens_checked(
+    from_info: &AccountInfo,
+    mint_info: &AccountInfo,
+    to_info: &AccountInfo,
+    authority_info: &AccountInfo,
+    token_program: &AccountInfo,
+    amount: u64,
+    signers: &[Signer],
+) -> ProgramResult {
+    verify_token_program(token_program)?;
+    if !mint_info.is_owned_by(token_program.key()) {
+        return Err(AttestationServiceError::InvalidMint.into());
+    }
+    let decimals = *mint_info
+        .try_borrow_data()?
+        .get(MINT_DECIMALS_OFFSET)
+        .ok_or(AttestationServiceError::InvalidMint)?;
+
+    let mut data = [0; 10];
+    data[0] = TRANSFER_CHECKED_IX;
+    data[1..9].copy_from_slice(&amount.to_le_bytes());
+    data[9] = decimals;
+
+    invoke_signed(
+        &Instruction {
+            program_id: token_program.key(),
+            accounts: &[
+                AccountMeta::new(from_info.key(), true, false),
+                AccountMeta::new(mint_info.key(), false, false),
+                AccountMeta::new(to_info.key(), true, false),
+                AccountMeta::new(authority_info.key(), false, true),
+            ],
+            data: &data,
+        },
+        &[from_info, mint_info, to_info, authority_info],
+        signers,
+    )
+}
+
+/// Read the mint, owner and amount of a token account of either token
+/// program.
+pub fn read_token_account(info: &AccountInfo) -> Result<(Pubkey, Pubkey, u64), ProgramError> {
+    if !info.is_owned_by(&TOKEN_PROGRAM_ID) && !info.is_owned_by(&TOKEN_2022_PROGRAM_ID) {
+        return Err(AttestationServiceError::InvalidTokenAccount.into());
+    }
+    let data = info.try_borrow_data()?;
+    if data.len() < TOKEN_ACCOUNT_AMOUNT_OFFSET + 8 {
+        return Err(AttestationServiceError::InvalidTokenAccount.into());
+    }
+    let mint = data[TOKEN_ACCOUNT_MINT_OFFSET..TOKEN_ACCOUNT_MINT_OFFSET + 32]
+        .try_into()
+        .unwrap();
+    let owner = data[TOKEN_ACCOUNT_OWNER_OFFSET..TOKEN_ACCOUNT_OWNER_OFFSET + 32]
+        .try_into()
+        .unwrap();
+    let amount = u64::from_le_bytes(
+        data[TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8]
+            .try_into()
+            .unwrap(),
+    );
+    Ok((mint, owner, amount))
+}
+
+/// Whether a token account read with `read_token_account` is frozen.
+pub fn is_frozen_token_account(info: &AccountInfo) -> Result<bool, ProgramError> {
+    let state = *info
+        .try_borrow_data()?
+        .get(TOKEN_ACCOUNT_STATE_OFFSET)
+        .ok_or(AttestationServiceError::InvalidTokenAccount)?;
+    Ok(state == TOKEN_ACCOUNT_FROZEN)
+}
@@ -0,0 +1,202 @@
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::Transfer;
+use pinocchio_token::{
+    extensions::metadata::{Field, UpdateField},
+    TOKEN_2022_PROGRAM_ID,
+};
+
+use crate::{
+    constants::{sas_pda, EXPIRY_METADATA_KEY, SAS_SEED, SIGNER_METADATA_KEY},
+    error::AttestationServiceError,
+    state::{
+        iter_field_names, split_fields, AttestationRef, SchemaDataTypes, SchemaRef,
+        TOKEN_POLICY_NON_TRANSFERABLE, TOKEN_POLICY_PERMANENT_DELEGATE,
+    },
+};
+
+// Sizes of the parts of a Token-2022 mint account: the mint padded to the
+// size of a token account followed by the account type, and each extension
+// with its 4 byte type and length header.
+const MINT_ACCOUNT_LEN: usize = 166;
+const POINTER_EXTENSION_LEN: usize = 4 + 64;
+const MINT_CLOSE_AUTHORITY_EXTENSION_LEN: usize = 4 + 32;
+const NON_TRANSFERABLE_EXTENSION_LEN: usize = 4;
+const PERMANENT_DELEGATE_EXTENSION_LEN: usize = 4 + 32;
+const TOKEN_GROUP_EXTENSION_LEN: usize = 4 + 80;
+const TOKEN_GROUP_MEMBER_EXTENSION_LEN: usize = 4 + 72;
+
+/// Token-2022 `ExtensionType` of the PermanentDelegate extension.
+pub const PERMANENT_DELEGATE_EXTENSION_TYPE: u16 = 12;
+
+/// Size of a tokenized Schema's group mint before its TokenGroup and
+/// TokenMetadata extensions are initialized: the mint with the GroupPointer
+/// and MetadataPointer extensions.
+pub const SCHEMA_MINT_BASE_LEN: usize = MINT_ACCOUNT_LEN + 2 * POINTER_EXTENSION_LEN;
+
+/// Size of a tokenized Schema's group mint with a TokenGroup and the token
+/// metadata.
+pub fn schema_mint_space(
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    additional: &[(&str, &str)],
+) -> usize {
+    SCHEMA_MINT_BASE_LEN
+        + TOKEN_GROUP_EXTENSION_LEN
+        + token_metadata_space(name, symbol, uri, additional)
+}
+
+/// Size of a tokenized Attestation's mint before its TokenGroupMember and
+/// TokenMetadata extensions are initialized: the mint with the
+/// GroupMemberPointer, MetadataPointer and MintCloseAuthority extensions, and
+/// the NonTransferable and PermanentDelegate extensions if `token_policy`
+/// enables them.
+pub fn attestation_mint_base_len(token_policy: u8) -> usize {
+    let mut len = MINT_ACCOUNT_LEN + 2 * POINTER_EXTENSION_LEN + MINT_CLOSE_AUTHORITY_EXTENSION_LEN;
+    if token_policy & TOKEN_POLICY_NON_TRANSFERABLE != 0 {
+        len += NON_TRANSFERABLE_EXTENSION_LEN;
+    }
+    if token_policy & TOKEN_POLICY_PERMANENT_DELEGATE != 0 {
+        len += PERMANENT_DELEGATE_EXTENSION_LEN;
+    }
+    len
+}
+
+/// Size of a tokenized Attestation's mint as a member of the Schema's group
+/// with the token metadata.
+pub fn attestation_mint_space(
+    token_policy: u8,
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    additional: &[(&str, &str)],
+) -> usize {
+    attestation_mint_base_len(token_policy)
+        + TOKEN_GROUP_MEMBER_EXTENSION_LEN
+        + token_metadata_space(name, symbol, uri, additional)
+}
+
+/// Whether the Token-2022 `mint_info` has an extension of `extension_type`.
+/// The token policy is read from the mint, where it is enforced, so it holds
+/// for tokens minted before the Schema's policy was changed.
+pub fn mint_has_extension(
+    mint_info: &AccountInfo,
+    extension_type: u16,
+) -> Result<bool, ProgramError> {
+    if !mint_info.is_owned_by(&TOKEN_2022_PROGRAM_ID) {
+        return Err(AttestationServiceError::InvalidMint.into());
+    }
+    let data = mint_info.try_borrow_data()?;
+    // Extensions follow the padded mint and the account type as type, length
+    // and value entries.
+    let mut offset = MINT_ACCOUNT_LEN;
+    while let Some(header) = data.get(offset..offset + 4) {
+        let entry_type = u16::from_le_bytes([header[0], header[1]]);
+        if entry_type == extension_type {
+            return Ok(true);
+        }
+        offset += 4 + u16::from_le_bytes([header[2], header[3]]) as usize;
+    }
+    Ok(false)
+}
+
+/// Size of a TokenMetadata extension with its header: the update authority,
+/// the mint, the u32 length prefixed name, symbol and uri, and the additional
+/// metadata as a u32 length prefixed list of key and value strings.
+pub fn token_metadata_space(
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    additional: &[(&str, &str)],
+) -> usize {
+    let additional_len: usize = additional
+        .iter()
+        .map(|(key, value)| 4 + key.len() + 4 + value.len())
+        .sum();
+    4 + 32 + 32 + (4 + name.len()) + (4 + symbol.len()) + (4 + uri.len()) + 4 + additional_len
+}
+
+/// Mirror an Attestation into the additional token metadata of its mint: its
+/// expiry, its signer and the Schema's `token_metadata_fields`, keyed by field
+/// name. Called once when the token is minted; Attestations cannot be updated
+/// or renewed, so the values never change afterwards. The payer tops up the
+/// mint's lamports for the size of the metadata.
+pub fn write_token_metadata(
+    payer_info: &AccountInfo,
+    attestation_mint_info: &AccountInfo,
+    sas_pda_info: &AccountInfo,
+    attestation: &AttestationRef,
+    schema: &SchemaRef,
+) -> ProgramResult {
+    let bump_seed = [sas_pda::BUMP];
+    let sas_pda_seeds = [Seed::from(SAS_SEED), Seed::from(&bump_seed)];
+    let update_field = |key: &str, value: &str| {
+        UpdateField {
+            metadata: attestation_mint_info,
+            update_authority: sas_pda_info,
+            field: Field::Key(key),
+            value,
+        }
+        .invoke_signed(&[Signer::from(&sas_pda_seeds)])
+    };
+
+    let mut value = String::new();
+    let _ = write!(value, "{}", attestation.expiry);
+    update_field(EXPIRY_METADATA_KEY, &value)?;
+    update_field(
+        SIGNER_METADATA_KEY,
+        &bs58::encode(attestation.signer).into_string(),
+    )?;
+
+    if !schema.token_metadata_fields.is_empty() {
+        let names: Vec<&[u8]> = iter_field_names(schema.field_names).collect();
+        let field_values = split_fields(attestation.data, schema.layout)?;
+        for field_index in schema.token_metadata_fields {
+            let index = *field_index as usize;
+            let (Some(name), Some(data_type), Some(field_value)) = (
+                names.get(index),
+                schema.layout.get(index),
+                field_values.get(index),
+            ) else {
+                return Err(AttestationServiceError::InvalidTokenMetadataField.into());
+            };
+            let key =
+                core::str::from_utf8(name).map_err(|_| AttestationServiceError::InvalidUtf8)?;
+            value.clear();
+            SchemaDataTypes::try_from(*data_type)?.write_display(field_value, &mut value)?;
+            update_field(key, &value)?;
+        }
+    }
+
+    // The token program resizes the mint for the metadata, but does not fund
+    // its rent.
+    top_up_rent(payer_info, attestation_mint_info)
+}
+
+/// Transfer the lamports `account_info` needs to be rent exempt at its
+/// current size from the payer, if any.
+pub fn top_up_rent(payer_info: &AccountInfo, account_info: &AccountInfo) -> ProgramResult {
+    let rent_diff = Rent::get()?
+        .minimum_balance(account_info.data_len())
+        .saturating_sub(account_info.lamports());
+    if rent_diff > 0 {
+        Transfer {
+            from: payer_info,
+            to: account_info,
+            lamports: rent_diff,
+        }
+        .invoke()?;
+    }
+
+    Ok(())
+}
@@ -1,7 +1,15 @@
 pub mod account_checks;
 pub mod data_utils;
+pub mod fee_utils;
+pub mod metadata_utils;
 pub mod pda_utils;
+pub mod resolver_utils;
+pub mod signature_checks;
 
 pub use account_checks::*;
 pub use data_utils::*;
+pub use fee_utils::*;
+pub use metadata_utils::*;
 pub use pda_utils::*;
+pub use resolver_utils::*;
+pub use signature_checks::*;
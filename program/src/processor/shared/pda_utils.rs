@@ -1,12 +1,40 @@
 use pinocchio::{
     account_info::AccountInfo,
     instruction::{Seed, Signer},
-    pubkey::Pubkey,
+    pubkey::{checked_create_program_address, Pubkey, MAX_SEEDS},
     sysvars::rent::Rent,
     ProgramResult,
 };
 use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
 
+/// Whether `key` is the program address derived from `seeds` with the
+/// canonical bump, which `seeds` must end with. Cheaper than deriving the
+/// address with `find_program_address` since only the given bump and the ones
+/// above it are tried, and the canonical bump is usually 255 or close to it.
+pub fn is_program_address(key: &Pubkey, seeds: &[&[u8]], program_id: &Pubkey) -> bool {
+    let Some((bump_seed, other_seeds)) = seeds.split_last() else {
+        return false;
+    };
+    let &[bump] = *bump_seed else {
+        return false;
+    };
+    if other_seeds.len() >= MAX_SEEDS
+        || !checked_create_program_address(seeds, program_id).is_ok_and(|address| address.eq(key))
+    {
+        return false;
+    }
+
+    // The bump is canonical only if no higher bump derives a program address.
+    let mut higher_seeds: [&[u8]; MAX_SEEDS] = [&[]; MAX_SEEDS];
+    higher_seeds[..other_seeds.len()].copy_from_slice(other_seeds);
+    (bump..u8::MAX).all(|higher_bump| {
+        let higher_bump_seed = [higher_bump + 1];
+        let mut candidate = higher_seeds;
+        candidate[other_seeds.len()] = &higher_bump_seed;
+        checked_create_program_address(&candidate[..=other_seeds.len()], program_id).is_err()
+    })
+}
+
 /// Create a PDA account for the given seeds.
 pub fn create_pda_account<const N: usize>(
     payer: &AccountInfo,
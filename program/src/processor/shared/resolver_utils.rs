@@ -0,0 +1,121 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{AccountMeta, Instruction, Seed, Signer},
+    program::slice_invoke_signed,
+    program_error::ProgramError,
+    pubkey::{checked_create_program_address, Pubkey},
+    ProgramResult,
+};
+
+use crate::{
+    constants::RESOLVER_AUTHORITY_SEED, error::AttestationServiceError, state::NO_RESOLVER_PROGRAM,
+};
+
+/// Point in an Attestation's lifecycle a resolver program is invoked at. Sent
+/// as the first byte of the resolver instruction data, followed by the
+/// Attestation account data.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResolverHook {
+    Create = 0,
+    Close = 1,
+    /// An authorized signer updated the Attestation, which carries the new
+    /// data: `TokenizeAttestation` set its `token_account`.
+    Update = 2,
+}
+
+/// Split `remaining_accounts` at `resolver_program`, returning the accounts
+/// before it and the resolver accounts: the resolver program, the resolver
+/// authority and the accounts forwarded to the resolver. The resolver
+/// accounts are empty when there is no resolver.
+pub fn split_resolver_accounts<'a>(
+    remaining_accounts: &'a [AccountInfo],
+    resolver_program: &Pubkey,
+) -> Result<(&'a [AccountInfo], &'a [AccountInfo]), ProgramError> {
+    if resolver_program.eq(&NO_RESOLVER_PROGRAM) {
+        return Ok((remaining_accounts, &[]));
+    }
+    let index = remaining_accounts
+        .iter()
+        .position(|account| account.key().eq(resolver_program))
+        .ok_or(AttestationServiceError::InvalidResolver)?;
+    Ok(remaining_accounts.split_at(index))
+}
+
+/// Bump of the resolver authority PDA of `resolver_program`,
+/// `["resolverAuthority", resolver_program]`, if `key` is that PDA.
+fn resolver_authority_bump(key: &Pubkey, resolver_program: &Pubkey) -> Option<u8> {
+    // The canonical bump is the highest one deriving a program address.
+    (0..=u8::MAX)
+        .rev()
+        .find_map(|bump| {
+            checked_create_program_address(
+                &[RESOLVER_AUTHORITY_SEED, resolver_program, &[bump]],
+                &crate::ID,
+            )
+            .ok()
+            .map(|address| (address.eq(key), bump))
+        })
+        .and_then(|(matches, bump)| matches.then_some(bump))
+}
+
+/// Invoke the resolver program leading `resolver_accounts` for `hook`, signed
+/// by the resolver authority PDA of that resolver program so it can tell the
+/// invocation comes from this program. Each resolver program has its own
+/// authority, so a resolver cannot forward the signer to invoke another
+/// resolver. The resolver receives the resolver authority, the Attestation
+/// and the forwarded accounts, and rejects the Attestation by failing. Does
+/// nothing when `resolver_accounts` is empty.
+pub fn invoke_resolver(
+    hook: ResolverHook,
+    attestation_info: &AccountInfo,
+    resolver_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let [resolver_program, resolver_authority_info, forwarded_accounts @ ..] = resolver_accounts
+    else {
+        return match resolver_accounts {
+            [] => Ok(()),
+            _ => Err(ProgramError::NotEnoughAccountKeys),
+        };
+    };
+
+    // Validate the resolver authority PDA of the resolver program.
+    let bump = resolver_authority_bump(resolver_authority_info.key(), resolver_program.key())
+        .ok_or(AttestationServiceError::InvalidResolver)?;
+
+    let mut data = Vec::with_capacity(1 + attestation_info.data_len());
+    data.push(hook as u8);
+    data.extend_from_slice(&attestation_info.try_borrow_data()?);
+
+    let mut account_metas = Vec::with_capacity(2 + forwarded_accounts.len());
+    let mut account_infos = Vec::with_capacity(2 + forwarded_accounts.len());
+    account_metas.push(AccountMeta::new(resolver_authority_info.key(), false, true));
+    account_infos.push(resolver_authority_info);
+    account_metas.push(AccountMeta::new(attestation_info.key(), false, false));
+    account_infos.push(attestation_info);
+    for account in forwarded_accounts {
+        account_metas.push(AccountMeta::new(
+            account.key(),
+            account.is_writable(),
+            account.is_signer(),
+        ));
+        account_infos.push(account);
+    }
+
+    slice_invoke_signed(
+        &Instruction {
+            program_id: resolver_program.key(),
+            accounts: &account_metas,
+            data: &data,
+        },
+        &account_infos,
+        &[Signer::from(&[
+            Seed::from(RESOLVER_AUTHORITY_SEED),
+            Seed::from(resolver_program.key()),
+            Seed::from(&[bump]),
+        ])],
+    )
+}
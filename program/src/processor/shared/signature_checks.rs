@@ -0,0 +1,248 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    constants::{
+        ED25519_PROGRAM_ID, EIP191_MESSAGE_PREFIX, SECP256K1_PROGRAM_ID, SECP256R1_PROGRAM_ID,
+    },
+    error::AttestationServiceError,
+    state::{ethereum_address, passkey_signer, SignerKind, PASSKEY_LEN},
+};
+
+use super::{verify_instructions_sysvar, DataReader};
+
+// Size of an account meta in a serialized sysvar instruction: flags - 1, pubkey - 32
+const SERIALIZED_ACCOUNT_META_LEN: usize = 1 + 32;
+// Instruction index a signature verification instruction uses to refer to its
+// own data.
+const CURRENT_INSTRUCTION_INDEX: u16 = u16::MAX;
+
+/// Instruction of the current transaction, read from the instructions sysvar.
+pub struct IntrospectedInstruction<'a> {
+    pub program_id: &'a Pubkey,
+    pub data: &'a [u8],
+}
+
+/// Index of the instruction currently executing, stored in the last two bytes
+/// of the instructions sysvar.
+pub fn load_current_index(sysvar_data: &[u8]) -> Result<usize, ProgramError> {
+    let index = sysvar_data
+        .last_chunk::<2>()
+        .ok_or(AttestationServiceError::UnexpectedEndOfData)?;
+    Ok(u16::from_le_bytes(*index) as usize)
+}
+
+/// Read the instruction at `index` from the instructions sysvar.
+pub fn load_instruction_at(
+    sysvar_data: &[u8],
+    index: usize,
+) -> Result<IntrospectedInstruction, ProgramError> {
+    let mut reader = DataReader::new(sysvar_data);
+    let instruction_count = reader.read_u16()? as usize;
+    if index >= instruction_count {
+        return Err(ProgramError::InvalidArgument);
+    }
+    reader.read_bytes(index * 2)?;
+    let instruction_offset = reader.read_u16()? as usize;
+
+    let mut reader = DataReader::new(
+        sysvar_data
+            .get(instruction_offset..)
+            .ok_or(AttestationServiceError::UnexpectedEndOfData)?,
+    );
+    let account_count = reader.read_u16()? as usize;
+    reader.read_bytes(account_count * SERIALIZED_ACCOUNT_META_LEN)?;
+    let program_id = reader.read_pubkey_ref()?;
+    let data_len = reader.read_u16()? as usize;
+    let data = reader.read_bytes(data_len)?;
+
+    Ok(IntrospectedInstruction { program_id, data })
+}
+
+/// Verify that the instruction before the current one is a signature
+/// verification instruction checking a single signature by `signer` over
+/// `message`, and return the kind of key that signed.
+///
+/// Ed25519 signers sign `message` itself. Ethereum signers, stored as
+/// zero-padded addresses, sign it as an EIP-191 personal message. Secp256r1
+/// signers, stored as the [`passkey_signer`] of their public key, sign
+/// `message` itself.
+///
+/// # Arguments
+/// * `instructions_sysvar` - The instructions sysvar account.
+/// * `signer` - Key that must have signed the message.
+/// * `message` - Message that must have been signed.
+///
+/// # Returns
+/// * `Result<SignerKind, ProgramError>` - The kind of key that signed
+pub fn verify_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<SignerKind, ProgramError> {
+    verify_instructions_sysvar(instructions_sysvar)?;
+    let sysvar_data = instructions_sysvar.try_borrow_data()?;
+
+    let index = load_current_index(&sysvar_data)?
+        .checked_sub(1)
+        .ok_or(AttestationServiceError::InvalidSignature)?;
+    let instruction = load_instruction_at(&sysvar_data, index)?;
+    let (verified, signer_kind) = match *instruction.program_id {
+        ED25519_PROGRAM_ID => {
+            let (public_key, signed_message) = read_signed_data(&instruction, 32)?;
+            (
+                public_key == signer && signed_message == message,
+                SignerKind::Ed25519,
+            )
+        }
+        SECP256K1_PROGRAM_ID => {
+            let (address, signed_message) = read_secp256k1_signed_data(&instruction, index)?;
+            (
+                ethereum_address(signer) == Some(address)
+                    && signed_message == eip191_message(message),
+                SignerKind::Secp256k1,
+            )
+        }
+        SECP256R1_PROGRAM_ID => {
+            let (public_key, signed_message) = read_signed_data(&instruction, PASSKEY_LEN)?;
+            (
+                passkey_signer(public_key).eq(signer) && signed_message == message,
+                SignerKind::Secp256r1,
+            )
+        }
+        _ => (false, SignerKind::Ed25519),
+    };
+    if !verified {
+        return Err(AttestationServiceError::InvalidSignature.into());
+    }
+
+    Ok(signer_kind)
+}
+
+/// Public key and message of the single signature checked by an Ed25519 or
+/// secp256r1 program instruction, which share a data layout.
+fn read_signed_data<'a>(
+    instruction: &IntrospectedInstruction<'a>,
+    public_key_len: usize,
+) -> Result<(&'a [u8], &'a [u8]), ProgramError> {
+    // Instruction data layout
+    // signature count - 1
+    // padding - 1
+    // signature offset, signature instruction index - 2, 2
+    // public key offset, public key instruction index - 2, 2
+    // message offset, message size, message instruction index - 2, 2, 2
+    let mut reader = DataReader::new(instruction.data);
+    if reader.read_u8()? != 1 {
+        return Err(AttestationServiceError::InvalidSignature.into());
+    }
+    reader.read_u8()?;
+    let _signature_offset = reader.read_u16()?;
+    let signature_instruction_index = reader.read_u16()?;
+    let public_key_offset = reader.read_u16()? as usize;
+    let public_key_instruction_index = reader.read_u16()?;
+    let message_offset = reader.read_u16()? as usize;
+    let message_size = reader.read_u16()? as usize;
+    let message_instruction_index = reader.read_u16()?;
+
+    // Signature, public key and message must all come from the instruction
+    // itself, so they are the ones read below.
+    if [
+        signature_instruction_index,
+        public_key_instruction_index,
+        message_instruction_index,
+    ]
+    .iter()
+    .any(|instruction_index| *instruction_index != CURRENT_INSTRUCTION_INDEX)
+    {
+        return Err(AttestationServiceError::InvalidSignature.into());
+    }
+
+    signed_data_at(
+        instruction.data,
+        public_key_offset,
+        public_key_len,
+        message_offset,
+        message_size,
+    )
+}
+
+/// Ethereum address and message of the single signature checked by the
+/// secp256k1 program instruction at `index`.
+fn read_secp256k1_signed_data<'a>(
+    instruction: &IntrospectedInstruction<'a>,
+    index: usize,
+) -> Result<(&'a [u8], &'a [u8]), ProgramError> {
+    // Secp256k1 instruction data layout
+    // signature count - 1
+    // signature offset, signature instruction index - 2, 1
+    // address offset, address instruction index - 2, 1
+    // message offset, message size, message instruction index - 2, 2, 1
+    let mut reader = DataReader::new(instruction.data);
+    if reader.read_u8()? != 1 {
+        return Err(AttestationServiceError::InvalidSignature.into());
+    }
+    let _signature_offset = reader.read_u16()?;
+    let signature_instruction_index = reader.read_u8()?;
+    let address_offset = reader.read_u16()? as usize;
+    let address_instruction_index = reader.read_u8()?;
+    let message_offset = reader.read_u16()? as usize;
+    let message_size = reader.read_u16()? as usize;
+    let message_instruction_index = reader.read_u8()?;
+
+    // The secp256k1 program has no index for "this instruction", so all
+    // three must point at its own absolute index.
+    if [
+        signature_instruction_index,
+        address_instruction_index,
+        message_instruction_index,
+    ]
+    .iter()
+    .any(|instruction_index| *instruction_index as usize != index)
+    {
+        return Err(AttestationServiceError::InvalidSignature.into());
+    }
+
+    signed_data_at(
+        instruction.data,
+        address_offset,
+        20,
+        message_offset,
+        message_size,
+    )
+}
+
+fn signed_data_at(
+    data: &[u8],
+    signer_offset: usize,
+    signer_len: usize,
+    message_offset: usize,
+    message_size: usize,
+) -> Result<(&[u8], &[u8]), ProgramError> {
+    let signer = data
+        .get(signer_offset..signer_offset + signer_len)
+        .ok_or(AttestationServiceError::InvalidSignature)?;
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(AttestationServiceError::InvalidSignature)?;
+    Ok((signer, message))
+}
+
+/// `message` prefixed as an EIP-191 personal message, which wallets hash
+/// with keccak256 before signing.
+pub fn eip191_message(message: &[u8]) -> Vec<u8> {
+    let mut prefixed = Vec::with_capacity(EIP191_MESSAGE_PREFIX.len() + 20 + message.len());
+    prefixed.extend_from_slice(EIP191_MESSAGE_PREFIX);
+    let len = message.len();
+    let mut divisor = 1;
+    while divisor * 10 <= len {
+        divisor *= 10;
+    }
+    while divisor > 0 {
+        prefixed.push(b'0' + (len / divisor % 10) as u8);
+        divisor /= 10;
+    }
+    prefixed.extend_from_slice(message);
+    prefixed
+}
@@ -0,0 +1,139 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    error::AttestationServiceError,
+    processor::{
+        invoke_resolver, mint_attestation_token, split_remaining_accounts, split_resolver_accounts,
+        verify_current_layout, verify_owner_mutability, verify_signer, verify_system_program,
+        AttestationTokenArgs, ResolverHook,
+    },
+    require_len,
+    state::{discriminator::AccountSerialize, Attestation, CredentialRef, SchemaRef},
+};
+
+#[inline(always)]
+pub fn process_tokenize_attestation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let (accounts, remaining_accounts) = split_remaining_accounts(accounts, 13)?;
+    let [payer_info, authorized_signer, credential_info, schema_info, attestation_info, system_program, _schema_mint_info, _attestation_mint_info, _sas_pda_info, recipient_token_account_info, _recipient_info, _token_program, _ata_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authorized_signer, false)?;
+
+    // Validate Credential, Schema and Attestation are owned by our program
+    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(schema_info, program_id, false)?;
+    verify_owner_mutability(attestation_info, program_id, true)?;
+    verify_current_layout::<Attestation>(attestation_info)?;
+    // Validate: system program
+    verify_system_program(system_program)?;
+
+    let credential_data = credential_info.try_borrow_data()?;
+    let credential = CredentialRef::try_from_bytes(&credential_data)?;
+
+    // Validate Credential is not paused
+    if credential.is_paused {
+        return Err(AttestationServiceError::CredentialPaused.into());
+    }
+
+    // Check that one of credential's authorized signers have signed.
+    credential.validate_authorized_signer(authorized_signer.key())?;
+
+    let schema_data = schema_info.try_borrow_data()?;
+    let schema = SchemaRef::try_from_bytes(&schema_data)?;
+
+    // Validate Schema is not paused
+    if schema.is_paused {
+        return Err(AttestationServiceError::SchemaPaused.into());
+    }
+
+    // Validate Schema is owned by Credential
+    if schema.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+    drop(schema_data); // Drop immutable borrow.
+    drop(credential_data); // Drop immutable borrow.
+
+    let mut attestation = Attestation::try_from_bytes(&attestation_info.try_borrow_data()?)?;
+
+    // Validate Attestation belongs to the Credential and Schema.
+    if attestation.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+    if attestation.schema.ne(schema_info.key()) {
+        return Err(AttestationServiceError::InvalidSchema.into());
+    }
+
+    // Validate the Attestation has not expired, as when creating it with a
+    // token.
+    let now = Clock::get()?.unix_timestamp;
+    if attestation.expiry != 0 && attestation.expiry < now {
+        return Err(AttestationServiceError::InvalidAttestationData.into());
+    }
+
+    // Validate the Attestation does not have a token yet.
+    if attestation.token_account.ne(&Pubkey::default()) {
+        return Err(AttestationServiceError::AttestationAlreadyTokenized.into());
+    }
+
+    // Record the token account before minting, as closing the Attestation
+    // requires it for tokenized Attestations.
+    attestation.token_account = *recipient_token_account_info.key();
+    attestation_info
+        .try_borrow_mut_data()?
+        .copy_from_slice(&attestation.to_bytes());
+
+    // Mint the Attestation token to the recipient. The Schema must be
+    // tokenized for its group mint to accept the Attestation mint.
+    mint_attestation_token(program_id, payer_info, &accounts[3..], &args)?;
+
+    // Let the resolver the Attestation was created with reject the update.
+    // The resolver accounts are passed after the other accounts.
+    let (_, resolver_accounts) =
+        split_resolver_accounts(remaining_accounts, &attestation.resolver_program)?;
+    invoke_resolver(ResolverHook::Update, attestation_info, resolver_accounts)
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<AttestationTokenArgs, ProgramError> {
+    let mut offset: usize = 0;
+
+    // Each field is a u32 length prefixed string.
+    let mut fields: [&str; 3] = [""; 3];
+    for field in fields.iter_mut() {
+        require_len!(data, offset + 4);
+        let len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+
+        require_len!(data, offset + len);
+        *field = core::str::from_utf8(&data[offset..offset + len])
+            .map_err(|_| AttestationServiceError::InvalidUtf8)?;
+        offset += len;
+    }
+    let [name, uri, symbol] = fields;
+
+    require_len!(data, offset + 2);
+    let schema_mint_bump = data[offset];
+    let attestation_mint_bump = data[offset + 1];
+
+    Ok(AttestationTokenArgs {
+        name,
+        uri,
+        symbol,
+        schema_mint_bump,
+        attestation_mint_bump,
+    })
+}
@@ -1,3 +1,7 @@
+extern crate alloc;
+
+use alloc::string::String;
+use core::fmt::Write;
 use pinocchio::{
     account_info::AccountInfo,
     instruction::{Seed, Signer},
@@ -8,22 +12,30 @@ use pinocchio::{
 };
 use pinocchio_token::{
     extensions::{
-        group_pointer::Initialize as InitializeGroupPointer, token_group::InitializeGroup,
+        group_pointer::Initialize as InitializeGroupPointer,
+        metadata::{Field, InitializeTokenMetadata, UpdateField},
+        metadata_pointer::Initialize as InitializeMetadataPointer,
+        token_group::InitializeGroup,
     },
     instructions::{InitializeMint2, TokenProgramVariant},
     TOKEN_2022_PROGRAM_ID,
 };
-use solana_program::pubkey::Pubkey as SolanaPubkey;
 
 use crate::{
-    constants::{sas_pda, SAS_SEED, SCHEMA_MINT_SEED},
+    constants::{
+        sas_pda, CREDENTIAL_METADATA_KEY, SAS_SEED, SCHEMA_METADATA_KEY, SCHEMA_MINT_SEED,
+        VERSION_METADATA_KEY,
+    },
     error::AttestationServiceError,
-    processor::{create_pda_account, verify_signer, verify_system_program},
+    processor::{create_pda_account, is_program_address, verify_signer, verify_system_program},
     require_len,
-    state::{Credential, Schema},
+    state::{discriminator::AccountSerialize, Credential, Schema, DEFAULT_TOKEN_POLICY},
 };
 
-use super::{verify_owner_mutability, verify_token22_program};
+use super::{
+    schema_mint_space, verify_current_layout, verify_owner_mutability, verify_token22_program,
+    SCHEMA_MINT_BASE_LEN,
+};
 
 #[inline(always)]
 pub fn process_tokenize_schema(
@@ -42,7 +54,9 @@ pub fn process_tokenize_schema(
     verify_signer(authority_info, false)?;
     // Validate Credential and Schema are owned by our program
     verify_owner_mutability(credential_info, program_id, false)?;
-    verify_owner_mutability(schema_info, program_id, false)?;
+    verify_owner_mutability(schema_info, program_id, true)?;
+    // Legacy accounts must be migrated before they are modified.
+    verify_current_layout::<Schema>(schema_info)?;
     // Validate: system program
     verify_system_program(system_program)?;
     verify_token22_program(token_program)?;
@@ -53,18 +67,32 @@ pub fn process_tokenize_schema(
         return Err(ProgramError::IncorrectAuthority);
     }
 
+    // Validate Credential is not paused
+    if credential.is_paused {
+        return Err(AttestationServiceError::CredentialPaused.into());
+    }
+
     // Validate Schema is owned by Credential
-    let schema = Schema::try_from_bytes(&schema_info.try_borrow_data()?)?;
+    let mut schema = Schema::try_from_bytes(&schema_info.try_borrow_data()?)?;
     if schema.credential.ne(credential_info.key()) {
         return Err(AttestationServiceError::InvalidCredential.into());
     }
 
+    // Store the token policy applied to the mints of the Schema's
+    // Attestations. The mint can only be created once, so it cannot change.
+    schema.token_policy = args.token_policy;
+    schema.validate_token_policy()?;
+    schema_info
+        .try_borrow_mut_data()?
+        .copy_from_slice(&schema.to_bytes());
+
     // Validate that mint to initialize matches expected PDA
-    let (mint_pda, mint_bump) = SolanaPubkey::find_program_address(
-        &[SCHEMA_MINT_SEED, schema_info.key()],
-        &SolanaPubkey::from(*program_id),
-    );
-    if mint_info.key().ne(&mint_pda.to_bytes()) {
+    let mint_bump_seed = [args.mint_bump];
+    if !is_program_address(
+        mint_info.key(),
+        &[SCHEMA_MINT_SEED, schema_info.key(), &mint_bump_seed],
+        program_id,
+    ) {
         return Err(AttestationServiceError::InvalidMint.into());
     }
 
@@ -73,19 +101,30 @@ pub fn process_tokenize_schema(
         return Err(AttestationServiceError::InvalidProgramSigner.into());
     }
 
+    let credential_value = bs58::encode(credential_info.key()).into_string();
+    let schema_value = bs58::encode(schema_info.key()).into_string();
+    let mut version_value = String::new();
+    let _ = write!(version_value, "{}", schema.version);
+    let additional_metadata = [
+        (CREDENTIAL_METADATA_KEY, credential_value.as_str()),
+        (SCHEMA_METADATA_KEY, schema_value.as_str()),
+        (VERSION_METADATA_KEY, version_value.as_str()),
+    ];
+    let mint_space = schema_mint_space(args.name, args.symbol, args.uri, &additional_metadata);
+
     // Initialize new account owned by token_program.
     create_pda_account(
         payer_info,
         &Rent::get()?,
-        234, // Size before Group Extension
+        SCHEMA_MINT_BASE_LEN, // Size before Group and TokenMetadata Extensions
         &TOKEN_2022_PROGRAM_ID,
         mint_info,
         [
             Seed::from(SCHEMA_MINT_SEED),
             Seed::from(schema_info.key()),
-            Seed::from(&[mint_bump]),
+            Seed::from(&mint_bump_seed),
         ],
-        Some(318), // Size after Group Extension
+        Some(mint_space), // Size after Group and TokenMetadata Extensions
     )?;
 
     // Initialize GroupPointer extension.
@@ -96,6 +135,14 @@ pub fn process_tokenize_schema(
     }
     .invoke()?;
 
+    // Initialize MetadataPointer extension.
+    InitializeMetadataPointer {
+        mint: mint_info,
+        authority: Some(*sas_pda_info.key()),
+        metadata_address: Some(*mint_info.key()),
+    }
+    .invoke()?;
+
     // Initialize Mint on created account.
     InitializeMint2 {
         mint: mint_info,
@@ -117,16 +164,74 @@ pub fn process_tokenize_schema(
     }
     .invoke_signed(&[Signer::from(&sas_pda_seeds)])?;
 
+    // Initialize TokenMetadata extension.
+    InitializeTokenMetadata {
+        metadata: mint_info,
+        update_authority: sas_pda_info,
+        mint: mint_info,
+        mint_authority: sas_pda_info,
+        name: args.name,
+        symbol: args.symbol,
+        uri: args.uri,
+    }
+    .invoke_signed(&[Signer::from(&sas_pda_seeds)])?;
+
+    // Set credential, schema and version metadata using UpdateField extension.
+    for (key, value) in additional_metadata {
+        UpdateField {
+            metadata: mint_info,
+            update_authority: sas_pda_info,
+            field: Field::Key(key),
+            value,
+        }
+        .invoke_signed(&[Signer::from(&sas_pda_seeds)])?;
+    }
+
     Ok(())
 }
 
-struct TokenizeSchemaArgs {
+struct TokenizeSchemaArgs<'a> {
     max_size: u64,
+    mint_bump: u8,
+    token_policy: u8,
+    name: &'a str,
+    uri: &'a str,
+    symbol: &'a str,
 }
 
 fn process_instruction_data(data: &[u8]) -> Result<TokenizeSchemaArgs, ProgramError> {
-    require_len!(data, 8);
+    require_len!(data, 9);
     let max_size = u64::from_le_bytes(data[0..8].try_into().unwrap());
-
-    Ok(TokenizeSchemaArgs { max_size })
+    let mint_bump = data[8];
+    // Optional so instructions encoded before token policies were added
+    // still issue non-transferable tokens the program can burn.
+    let token_policy = data.get(9).copied().unwrap_or(DEFAULT_TOKEN_POLICY);
+
+    // Optional so instructions encoded before Schema token metadata was added
+    // still tokenize Schemas, with an empty name, uri and symbol. Each field
+    // is a u32 length prefixed string.
+    let mut offset: usize = 10;
+    let mut fields: [&str; 3] = [""; 3];
+    if data.len() > offset {
+        for field in fields.iter_mut() {
+            require_len!(data, offset + 4);
+            let len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+            offset += 4;
+
+            require_len!(data, offset + len);
+            *field = core::str::from_utf8(&data[offset..offset + len])
+                .map_err(|_| AttestationServiceError::InvalidUtf8)?;
+            offset += len;
+        }
+    }
+    let [name, uri, symbol] = fields;
+
+    Ok(TokenizeSchemaArgs {
+        max_size,
+        mint_bump,
+        token_policy,
+        name,
+        uri,
+        symbol,
+    })
 }
@@ -0,0 +1,119 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::Transfer;
+
+use crate::{
+    processor::{
+        verify_current_layout, verify_owner_mutability, verify_signer, verify_system_program,
+    },
+    require_len,
+    state::{discriminator::AccountSerialize, Credential},
+};
+
+#[inline(always)]
+pub fn process_update_credential_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [payer_info, authority_info, credential_info, system_program] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authority_info, false)?;
+    // Validate: system program
+    verify_system_program(system_program)?;
+    // Verify program ownership, mutability and PDAs.
+    verify_owner_mutability(credential_info, program_id, true)?;
+    // Legacy accounts must be migrated before they are modified.
+    verify_current_layout::<Credential>(credential_info)?;
+
+    let data = credential_info.try_borrow_data()?;
+    let mut credential = Credential::try_from_bytes(&data)?;
+    drop(data); // Drop immutable borrow.
+
+    // Verify that signer matches credential authority.
+    if credential.authority.ne(authority_info.key()) {
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    let prev_metadata_len: usize = credential.metadata().iter().map(|f| f.len()).sum();
+
+    // Update metadata on struct.
+    credential.display_name = args.display_name.to_vec();
+    credential.description = args.description.to_vec();
+    credential.uri = args.uri.to_vec();
+    credential.logo_uri = args.logo_uri.to_vec();
+    credential.website = args.website.to_vec();
+    credential.validate_metadata()?;
+
+    // Resize account if needed.
+    let new_metadata_len: usize = credential.metadata().iter().map(|f| f.len()).sum();
+    if new_metadata_len != prev_metadata_len {
+        let previous_space = credential_info.data_len();
+        let new_space = previous_space + new_metadata_len - prev_metadata_len;
+        credential_info.realloc(new_space, false)?;
+        let diff = new_space.saturating_sub(previous_space);
+        if diff > 0 {
+            // top up lamports to account for additional rent.
+            let rent = Rent::get()?;
+            let min_rent = rent.minimum_balance(new_space);
+            let current_rent = credential_info.lamports();
+            let rent_diff = min_rent.saturating_sub(current_rent);
+            if rent_diff > 0 {
+                Transfer {
+                    from: payer_info,
+                    to: credential_info,
+                    lamports: rent_diff,
+                }
+                .invoke()?;
+            }
+        }
+    }
+
+    // Write updated data.
+    let mut credential_data = credential_info.try_borrow_mut_data()?;
+    credential_data.copy_from_slice(&credential.to_bytes());
+
+    Ok(())
+}
+
+struct UpdateCredentialMetadataArgs<'a> {
+    display_name: &'a [u8],
+    description: &'a [u8],
+    uri: &'a [u8],
+    logo_uri: &'a [u8],
+    website: &'a [u8],
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<UpdateCredentialMetadataArgs, ProgramError> {
+    let mut offset: usize = 0;
+
+    // Each field is a u32 length prefixed string.
+    let mut fields: [&[u8]; 5] = [&[]; 5];
+    for field in fields.iter_mut() {
+        require_len!(data, offset + 4);
+        let len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+
+        require_len!(data, offset + len);
+        *field = &data[offset..offset + len];
+        offset += len;
+    }
+    let [display_name, description, uri, logo_uri, website] = fields;
+
+    Ok(UpdateCredentialMetadataArgs {
+        display_name,
+        description,
+        uri,
+        logo_uri,
+        website,
+    })
+}
@@ -0,0 +1,122 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    ProgramResult,
+};
+use pinocchio_token::extensions::metadata::{Field, UpdateField};
+
+use crate::{
+    constants::{sas_pda, SAS_SEED, SCHEMA_MINT_SEED},
+    error::AttestationServiceError,
+    processor::{
+        is_program_address, top_up_rent, verify_owner_mutability, verify_signer,
+        verify_system_program, verify_token22_program,
+    },
+    require_len,
+    state::{Credential, Schema},
+};
+
+#[inline(always)]
+pub fn process_update_schema_token_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [payer_info, authority_info, credential_info, schema_info, mint_info, sas_pda_info, system_program, token_program] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authority_info, false)?;
+    // Validate Credential and Schema are owned by our program
+    verify_owner_mutability(credential_info, program_id, false)?;
+    verify_owner_mutability(schema_info, program_id, false)?;
+    verify_system_program(system_program)?;
+    verify_token22_program(token_program)?;
+
+    // Verify signer matches credential authority.
+    let credential = &Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
+    if credential.authority.ne(authority_info.key()) {
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    // Validate Schema is owned by Credential
+    let schema = Schema::try_from_bytes(&schema_info.try_borrow_data()?)?;
+    if schema.credential.ne(credential_info.key()) {
+        return Err(AttestationServiceError::InvalidCredential.into());
+    }
+
+    // Validate that mint matches expected PDA
+    if !is_program_address(
+        mint_info.key(),
+        &[SCHEMA_MINT_SEED, schema_info.key(), &[args.mint_bump]],
+        program_id,
+    ) {
+        return Err(AttestationServiceError::InvalidMint.into());
+    }
+
+    // Validate that sas_pda matches
+    if sas_pda_info.key().ne(&sas_pda::ID) {
+        return Err(AttestationServiceError::InvalidProgramSigner.into());
+    }
+
+    let bump_seed = [sas_pda::BUMP];
+    let sas_pda_seeds = [Seed::from(SAS_SEED), Seed::from(&bump_seed)];
+    for (field, value) in [
+        (Field::Name, args.name),
+        (Field::Symbol, args.symbol),
+        (Field::Uri, args.uri),
+    ] {
+        UpdateField {
+            metadata: mint_info,
+            update_authority: sas_pda_info,
+            field,
+            value,
+        }
+        .invoke_signed(&[Signer::from(&sas_pda_seeds)])?;
+    }
+
+    // The token program resizes the mint for the metadata, but does not fund
+    // its rent.
+    top_up_rent(payer_info, mint_info)
+}
+
+struct UpdateSchemaTokenMetadataArgs<'a> {
+    name: &'a str,
+    uri: &'a str,
+    symbol: &'a str,
+    mint_bump: u8,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<UpdateSchemaTokenMetadataArgs, ProgramError> {
+    let mut offset: usize = 0;
+
+    // Each field is a u32 length prefixed string.
+    let mut fields: [&str; 3] = [""; 3];
+    for field in fields.iter_mut() {
+        require_len!(data, offset + 4);
+        let len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+
+        require_len!(data, offset + len);
+        *field = core::str::from_utf8(&data[offset..offset + len])
+            .map_err(|_| AttestationServiceError::InvalidUtf8)?;
+        offset += len;
+    }
+    let [name, uri, symbol] = fields;
+
+    require_len!(data, offset + 1);
+    let mint_bump = data[offset];
+
+    Ok(UpdateSchemaTokenMetadataArgs {
+        name,
+        uri,
+        symbol,
+        mint_bump,
+    })
+}
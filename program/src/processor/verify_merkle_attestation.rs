@@ -0,0 +1,92 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    error::AttestationServiceError,
+    processor::verify_owner_mutability,
+    require_len,
+    state::{merkle_leaf, MerkleAttestationBatch},
+};
+
+#[inline(always)]
+pub fn process_verify_merkle_attestation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [merkle_batch_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate batch is owned by our program. Revoked batches are closed.
+    verify_owner_mutability(merkle_batch_info, program_id, false)?;
+    let merkle_batch =
+        MerkleAttestationBatch::try_from_bytes(&merkle_batch_info.try_borrow_data()?)?;
+
+    // Validate the Attestation has not expired
+    let clock = Clock::get()?;
+    if args.expiry < clock.unix_timestamp && args.expiry != 0 {
+        return Err(AttestationServiceError::AttestationExpired.into());
+    }
+
+    // Validate the leaf is in the batch
+    let leaf = merkle_leaf(args.subject, args.data, args.expiry);
+    if !merkle_batch.verify_proof(leaf, &args.proof) {
+        return Err(AttestationServiceError::InvalidMerkleProof.into());
+    }
+
+    Ok(())
+}
+
+struct VerifyMerkleAttestationArgs<'a> {
+    subject: &'a Pubkey,
+    data: &'a [u8],
+    expiry: i64,
+    proof: Vec<[u8; 32]>,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<VerifyMerkleAttestationArgs, ProgramError> {
+    let mut offset: usize = 0;
+
+    require_len!(data, 32);
+    let subject: &Pubkey = data[offset..offset + 32].try_into().unwrap();
+    offset += 32;
+
+    require_len!(data, offset + 4);
+    let data_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+    offset += 4;
+
+    require_len!(data, offset + data_len);
+    let data_bytes = &data[offset..offset + data_len];
+    offset += data_len;
+
+    require_len!(data, offset + 8);
+    let expiry = i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+    offset += 8;
+
+    require_len!(data, offset + 4);
+    let proof_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+    offset += 4;
+
+    require_len!(data, offset + proof_len * 32);
+    let proof = data[offset..offset + proof_len * 32]
+        .chunks_exact(32)
+        .map(|node| node.try_into().unwrap())
+        .collect();
+
+    Ok(VerifyMerkleAttestationArgs {
+        subject,
+        data: data_bytes,
+        expiry,
+        proof,
+    })
+}
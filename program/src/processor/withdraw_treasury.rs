@@ -0,0 +1,111 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::Transfer;
+
+use crate::{
+    constants::TREASURY_SEED,
+    error::AttestationServiceError,
+    processor::{
+        is_program_address, read_token_account, transfer_tokens_checked, verify_owner_mutability,
+        verify_signer, verify_system_program,
+    },
+    require_len,
+    state::Credential,
+};
+
+#[inline(always)]
+pub fn process_withdraw_treasury(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args = process_instruction_data(instruction_data)?;
+    let [authority_info, credential_info, treasury_info, destination_info, system_program, token_accounts @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validate: authority should have signed
+    verify_signer(authority_info, false)?;
+    // Validate: system program
+    verify_system_program(system_program)?;
+    // Verify Credential is owned by current program.
+    verify_owner_mutability(credential_info, program_id, false)?;
+
+    let credential = &Credential::try_from_bytes(&credential_info.try_borrow_data()?)?;
+
+    // Verify signer matches credential authority.
+    if credential.authority.ne(authority_info.key()) {
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    // Validate treasury PDA is correct
+    let bump_seed = [args.treasury_bump];
+    if !is_program_address(
+        treasury_info.key(),
+        &[TREASURY_SEED, credential_info.key(), &bump_seed],
+        program_id,
+    ) {
+        return Err(AttestationServiceError::InvalidTreasury.into());
+    }
+    let treasury_seeds = [
+        Seed::from(TREASURY_SEED),
+        Seed::from(credential_info.key()),
+        Seed::from(&bump_seed),
+    ];
+
+    match token_accounts {
+        // Drain lamports, keeping the treasury rent exempt so it can keep
+        // receiving fees smaller than the minimum balance.
+        [] => {
+            let lamports = treasury_info
+                .lamports()
+                .saturating_sub(Rent::get()?.minimum_balance(0));
+            if lamports > 0 {
+                Transfer {
+                    from: treasury_info,
+                    to: destination_info,
+                    lamports,
+                }
+                .invoke_signed(&[Signer::from(&treasury_seeds)])?;
+            }
+        }
+        // Drain the treasury token account of a fee mint.
+        [treasury_token_account_info, destination_token_account_info, mint_info, token_program, ..] =>
+        {
+            let (_mint, _owner, amount) = read_token_account(treasury_token_account_info)?;
+            if amount > 0 {
+                transfer_tokens_checked(
+                    treasury_token_account_info,
+                    mint_info,
+                    destination_token_account_info,
+                    treasury_info,
+                    token_program,
+                    amount,
+                    &[Signer::from(&treasury_seeds)],
+                )?;
+            }
+        }
+        _ => return Err(ProgramError::NotEnoughAccountKeys),
+    }
+
+    Ok(())
+}
+
+struct WithdrawTreasuryArgs {
+    treasury_bump: u8,
+}
+
+fn process_instruction_data(data: &[u8]) -> Result<WithdrawTreasuryArgs, ProgramError> {
+    require_len!(data, 1);
+    let treasury_bump = data[0];
+
+    Ok(WithdrawTreasuryArgs { treasury_bump })
+}
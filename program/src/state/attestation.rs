@@ -2,17 +2,23 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 use pinocchio::{msg, program_error::ProgramError, pubkey::Pubkey};
+use pinocchio_log::log;
 use shank::ShankAccount;
 
-use crate::error::AttestationServiceError;
+use crate::{error::AttestationServiceError, processor::DataReader};
+
+/// `ref_attestation` of Attestations that do not reference another one.
+pub const NO_REF_ATTESTATION: Pubkey = [0; 32];
 
 use super::{
-    discriminator::{AccountSerialize, AttestationAccountDiscriminators, Discriminator},
-    SchemaDataTypes,
+    discriminator::{
+        read_layout_version, AccountSerialize, AttestationAccountDiscriminators, Discriminator,
+    },
+    FieldConstraints, SchemaDataTypes, SignerKind, NO_RESOLVER_PROGRAM,
 };
 
 // PDA ["attestation", credential, schema, nonce]
-#[derive(Clone, Debug, PartialEq, ShankAccount)]
+#[derive(Clone, Debug, Default, PartialEq, ShankAccount)]
 #[repr(C)]
 pub struct Attestation {
     /// A pubkey that may either be randomly generated OR associated with a User's wallet
@@ -29,198 +35,278 @@ pub struct Attestation {
     pub expiry: i64,
     /// The pubkey of Attestation token account if created. Otherwise set to default pubkey.
     pub token_account: Pubkey,
+    /// Canonical bump of the Attestation PDA
+    pub bump: u8,
+    /// Kind of key `signer` is. 0 = Ed25519, 1 = Secp256k1 (Ethereum address), 2 = Secp256r1 (passkey)
+    pub signer_kind: u8,
+    /// Attestation this one references. Set to default pubkey when there is none.
+    pub ref_attestation: Pubkey,
+    /// Number of open Attestations that reference this one
+    pub ref_count: u32,
+    /// Resolver program of the Schema when the Attestation was created, which
+    /// is invoked again when it is closed. Set to default pubkey when there is none.
+    pub resolver_program: Pubkey,
 }
 
+// Layout versions
+// 0 - nonce, credential, schema, data, signer, expiry, token_account
+// 1 - adds bump
+// 2 - adds signer_kind
+// 3 - adds ref_attestation and ref_count
+// 4 - adds resolver_program
+
 impl Discriminator for Attestation {
     const DISCRIMINATOR: u8 = AttestationAccountDiscriminators::AttestationDiscriminator as u8;
+    const LEGACY_DISCRIMINATOR: Option<u8> =
+        Some(AttestationAccountDiscriminators::LegacyAttestationDiscriminator as u8);
+    const LAYOUT_VERSION: u8 = 4;
 }
 
 impl AccountSerialize for Attestation {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        self.as_view().to_bytes_inner()
+    }
+}
+
+impl Attestation {
+    /// Borrow the Attestation as an `AttestationRef`.
+    pub fn as_view(&self) -> AttestationRef<'_> {
+        AttestationRef {
+            nonce: &self.nonce,
+            credential: &self.credential,
+            schema: &self.schema,
+            data: &self.data,
+            signer: &self.signer,
+            expiry: self.expiry,
+            token_account: &self.token_account,
+            bump: self.bump,
+            signer_kind: self.signer_kind,
+            ref_attestation: &self.ref_attestation,
+            ref_count: self.ref_count,
+            resolver_program: &self.resolver_program,
+        }
+    }
+
+    /// See [`AttestationRef::validate_data`].
+    pub fn validate_data(
+        &self,
+        layout: &[u8],
+        field_constraints: &[u8],
+    ) -> Result<(), ProgramError> {
+        self.as_view().validate_data(layout, field_constraints)
+    }
+
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        AttestationRef::try_from_bytes(data).map(Self::from)
+    }
+}
+
+/// Borrowed view of Attestation account data that reads fields in place.
+/// Can also be built from borrowed parts to serialize a new Attestation
+/// without copying its data.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AttestationRef<'a> {
+    pub nonce: &'a Pubkey,
+    pub credential: &'a Pubkey,
+    pub schema: &'a Pubkey,
+    pub data: &'a [u8],
+    pub signer: &'a Pubkey,
+    pub expiry: i64,
+    pub token_account: &'a Pubkey,
+    /// Zero for legacy accounts that have not been migrated.
+    pub bump: u8,
+    pub signer_kind: u8,
+    pub ref_attestation: &'a Pubkey,
+    pub ref_count: u32,
+    pub resolver_program: &'a Pubkey,
+}
+
+impl Discriminator for AttestationRef<'_> {
+    const DISCRIMINATOR: u8 = Attestation::DISCRIMINATOR;
+    const LEGACY_DISCRIMINATOR: Option<u8> = Attestation::LEGACY_DISCRIMINATOR;
+    const LAYOUT_VERSION: u8 = Attestation::LAYOUT_VERSION;
+}
+
+impl AccountSerialize for AttestationRef<'_> {
     fn to_bytes_inner(&self) -> Vec<u8> {
         let mut data = Vec::new();
         data.extend_from_slice(self.nonce.as_ref());
         data.extend_from_slice(self.credential.as_ref());
         data.extend_from_slice(self.schema.as_ref());
         data.extend_from_slice(&(self.data.len() as u32).to_le_bytes());
-        data.extend_from_slice(self.data.as_ref());
+        data.extend_from_slice(self.data);
         data.extend_from_slice(self.signer.as_ref());
         data.extend_from_slice(&self.expiry.to_le_bytes());
         data.extend_from_slice(self.token_account.as_ref());
+        data.push(self.bump);
+        data.push(self.signer_kind);
+        data.extend_from_slice(self.ref_attestation.as_ref());
+        data.extend_from_slice(&self.ref_count.to_le_bytes());
+        data.extend_from_slice(self.resolver_program.as_ref());
 
         data
     }
 }
 
-#[inline]
-fn get_size_of_vec(offset: usize, element_size: usize, data: &Vec<u8>) -> usize {
-    let len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-    4 + len * element_size
-}
+impl<'a> AttestationRef<'a> {
+    pub fn try_from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let mut reader = DataReader::new(data);
 
-impl Attestation {
-    /// Validate the data in the Attestation conforms to the Schema's
-    /// layout.
-    pub fn validate_data(&self, layout: Vec<u8>) -> Result<(), ProgramError> {
-        // Iterate over the data and ensure there are no overflows.
-        // If we do not overflow and match with the end of the data,
-        // then we can assume the data is valid for the schema.
-        let mut data_offset = 0;
-        for data_type in layout {
-            let schema_data_type: SchemaDataTypes = data_type.into();
-            match schema_data_type {
-                // u8 -> u128
-                SchemaDataTypes::U8 => data_offset += 1,
-                SchemaDataTypes::U16 => data_offset += 2,
-                SchemaDataTypes::U32 => data_offset += 4,
-                SchemaDataTypes::U64 => data_offset += 8,
-                SchemaDataTypes::U128 => data_offset += 16,
-                // i8 -> i128
-                SchemaDataTypes::I8 => data_offset += 1,
-                SchemaDataTypes::I16 => data_offset += 2,
-                SchemaDataTypes::I32 => data_offset += 4,
-                SchemaDataTypes::I64 => data_offset += 8,
-                SchemaDataTypes::I128 => data_offset += 16,
-                // bool
-                SchemaDataTypes::Bool => data_offset += 1,
-                // char
-                SchemaDataTypes::Char => data_offset += 4,
-                // String
-                SchemaDataTypes::String => {
-                    data_offset += get_size_of_vec(data_offset, 1, &self.data)
-                }
-                // Vec<u8> -> Vec<u128>
-                SchemaDataTypes::VecU8 => {
-                    data_offset += get_size_of_vec(data_offset, 1, &self.data)
-                }
-                SchemaDataTypes::VecU16 => {
-                    data_offset += get_size_of_vec(data_offset, 2, &self.data)
-                }
-                SchemaDataTypes::VecU32 => {
-                    data_offset += get_size_of_vec(data_offset, 4, &self.data)
-                }
-                SchemaDataTypes::VecU64 => {
-                    data_offset += get_size_of_vec(data_offset, 8, &self.data)
-                }
-                SchemaDataTypes::VecU128 => {
-                    data_offset += get_size_of_vec(data_offset, 16, &self.data)
-                }
-                // Vec<i8> -> Vec<i128>
-                SchemaDataTypes::VecI8 => {
-                    data_offset += get_size_of_vec(data_offset, 1, &self.data)
-                }
-                SchemaDataTypes::VecI16 => {
-                    data_offset += get_size_of_vec(data_offset, 2, &self.data)
-                }
-                SchemaDataTypes::VecI32 => {
-                    data_offset += get_size_of_vec(data_offset, 4, &self.data)
-                }
-                SchemaDataTypes::VecI64 => {
-                    data_offset += get_size_of_vec(data_offset, 8, &self.data)
-                }
-                SchemaDataTypes::VecI128 => {
-                    data_offset += get_size_of_vec(data_offset, 16, &self.data)
-                }
-                // Vec<bool>
-                SchemaDataTypes::VecBool => {
-                    data_offset += get_size_of_vec(data_offset, 1, &self.data)
-                }
-                // Vec<char>
-                SchemaDataTypes::VecChar => {
-                    data_offset += get_size_of_vec(data_offset, 4, &self.data)
-                }
-                // Vec<String>
-                SchemaDataTypes::VecString => {
-                    let len = u32::from_le_bytes(
-                        self.data[data_offset..data_offset + 4].try_into().unwrap(),
-                    ) as usize;
-                    data_offset += 4;
-                    // must iterate over the strings using their len
-                    for _ in 0..len {
-                        let string_len = u32::from_le_bytes(
-                            self.data[data_offset..data_offset + 4].try_into().unwrap(),
-                        ) as usize;
-                        data_offset += 4 + string_len;
-                    }
-                }
-            }
-
-            // Check data size at end of each iteration and error if offset exceeds the data length.
-            if data_offset > self.data.len() {
-                return Err(AttestationServiceError::InvalidAttestationData.into());
-            }
-        }
-        if data_offset != self.data.len() {
-            return Err(AttestationServiceError::InvalidAttestationData.into());
-        }
-        Ok(())
-    }
-
-    pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
-        // Check discriminator
-        if data[0] != Self::DISCRIMINATOR {
+        // Check discriminator and layout version
+        let Some(layout_version) = read_layout_version::<Self>(&mut reader)? else {
             msg!("Invalid Attestation Data");
             return Err(ProgramError::InvalidAccountData);
-        }
-
-        // Start offset after Discriminator
-        let mut offset: usize = 1;
-
-        let nonce: Pubkey = data[offset..offset + 32].try_into().unwrap();
-        offset += 32;
-
-        let credential: Pubkey = data[offset..offset + 32].try_into().unwrap();
-        offset += 32;
+        };
 
-        let schema: Pubkey = data[offset..offset + 32].try_into().unwrap();
-        offset += 32;
+        Ok(Self {
+            nonce: reader.read_pubkey_ref()?,
+            credential: reader.read_pubkey_ref()?,
+            schema: reader.read_pubkey_ref()?,
+            data: reader.read_vec()?,
+            signer: reader.read_pubkey_ref()?,
+            expiry: reader.read_i64()?,
+            token_account: reader.read_pubkey_ref()?,
+            bump: if layout_version >= 1 {
+                reader.read_u8()?
+            } else {
+                0
+            },
+            signer_kind: if layout_version >= 2 {
+                reader.read_u8()?
+            } else {
+                SignerKind::Ed25519 as u8
+            },
+            ref_attestation: if layout_version >= 3 {
+                reader.read_pubkey_ref()?
+            } else {
+                &NO_REF_ATTESTATION
+            },
+            ref_count: if layout_version >= 3 {
+                reader.read_u32()?
+            } else {
+                0
+            },
+            resolver_program: if layout_version >= 4 {
+                reader.read_pubkey_ref()?
+            } else {
+                &NO_RESOLVER_PROGRAM
+            },
+        })
+    }
 
-        let data_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-        offset += 4;
-        let attestation_data = data[offset..offset + data_len].to_vec();
-        offset += data_len;
+    /// Validate the data in the Attestation conforms to the Schema's
+    /// layout, is canonically encoded and satisfies the Schema's field
+    /// constraints.
+    pub fn validate_data(
+        &self,
+        layout: &[u8],
+        field_constraints: &[u8],
+    ) -> Result<(), ProgramError> {
+        validate_data(self.data, layout, field_constraints)
+    }
+}
 
-        let signer: Pubkey = data[offset..offset + 32].try_into().unwrap();
-        offset += 32;
+/// Validate Attestation `data` conforms to the Schema's layout, is
+/// canonically encoded and satisfies the Schema's field constraints.
+pub fn validate_data(
+    data: &[u8],
+    layout: &[u8],
+    field_constraints: &[u8],
+) -> Result<(), ProgramError> {
+    // Iterate over the data and ensure there are no overflows.
+    // If we do not overflow and match with the end of the data,
+    // then we can assume the data is valid for the schema.
+    let mut field_offsets = Vec::with_capacity(layout.len() + 1);
+    let mut data_offset = 0;
+    for data_type in layout {
+        field_offsets.push(data_offset);
+        let schema_data_type = SchemaDataTypes::try_from(*data_type)?;
+        // Error if the field overflows the data length.
+        let size = schema_data_type
+            .size_of(data, data_offset)
+            .ok_or(AttestationServiceError::InvalidAttestationData)?;
+        // Reject encodings that clients would decode differently or not at all.
+        if !schema_data_type.is_canonical(&data[data_offset..data_offset + size]) {
+            log!(
+                "Field {} is not canonically encoded",
+                field_offsets.len() - 1
+            );
+            return Err(AttestationServiceError::InvalidAttestationData.into());
+        }
+        data_offset += size;
+    }
+    if data_offset != data.len() {
+        return Err(AttestationServiceError::InvalidAttestationData.into());
+    }
+    field_offsets.push(data_offset);
 
-        let expiry = i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
-        offset += 8;
+    for entry in FieldConstraints::new(field_constraints) {
+        let (field_index, constraint) = entry?;
+        let index = field_index as usize;
+        let (Some(data_type), Some(&start), Some(&end)) = (
+            layout.get(index),
+            field_offsets.get(index),
+            field_offsets.get(index + 1),
+        ) else {
+            return Err(AttestationServiceError::InvalidFieldConstraint.into());
+        };
+        let data_type = SchemaDataTypes::try_from(*data_type)?;
+        if !constraint.is_satisfied_by(&data_type, &data[start..end]) {
+            log!("Field {} does not satisfy Schema constraint", field_index);
+            return Err(AttestationServiceError::FieldConstraintViolation.into());
+        }
+    }
+    Ok(())
+}
 
-        let token_account: Pubkey = data[offset..offset + 32].try_into().unwrap();
+/// Split Attestation `data` that conforms to the Schema's layout into the
+/// encoding of each field.
+pub fn split_fields<'a>(data: &'a [u8], layout: &[u8]) -> Result<Vec<&'a [u8]>, ProgramError> {
+    let mut fields = Vec::with_capacity(layout.len());
+    let mut offset = 0;
+    for data_type in layout {
+        let size = SchemaDataTypes::try_from(*data_type)?
+            .size_of(data, offset)
+            .ok_or(AttestationServiceError::InvalidAttestationData)?;
+        fields.push(&data[offset..offset + size]);
+        offset += size;
+    }
+    Ok(fields)
+}
 
-        Ok(Self {
-            nonce,
-            credential,
-            schema,
-            data: attestation_data,
-            signer,
-            expiry,
-            token_account,
-        })
+impl From<AttestationRef<'_>> for Attestation {
+    fn from(attestation: AttestationRef<'_>) -> Self {
+        Self {
+            nonce: *attestation.nonce,
+            credential: *attestation.credential,
+            schema: *attestation.schema,
+            data: attestation.data.to_vec(),
+            signer: *attestation.signer,
+            expiry: attestation.expiry,
+            token_account: *attestation.token_account,
+            bump: attestation.bump,
+            signer_kind: attestation.signer_kind,
+            ref_attestation: *attestation.ref_attestation,
+            ref_count: attestation.ref_count,
+            resolver_program: *attestation.resolver_program,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::processor::to_serialized_vec;
+    use crate::{processor::to_serialized_vec, state::FieldConstraintType};
 
     use super::*;
 
     #[test]
     fn attestation_validate_data() {
-        let mut attestation = Attestation {
-            nonce: Pubkey::default(),
-            credential: Pubkey::default(),
-            schema: Pubkey::default(),
-            data: Vec::new(),
-            signer: Pubkey::default(),
-            expiry: 0,
-            token_account: Pubkey::default(),
-        };
+        let mut attestation = Attestation::default();
 
         // u8
         let layout = alloc::vec![0];
         attestation.data = alloc::vec![10];
-        assert!(attestation.validate_data(layout).is_ok());
+        assert!(attestation.validate_data(&layout, &[]).is_ok());
 
         // u8, Vec<String>, u128
         let layout = alloc::vec![0, 25, 4];
@@ -237,18 +323,304 @@ mod tests {
         );
         data.extend(199u128.to_le_bytes());
         attestation.data = data;
-        assert!(attestation.validate_data(layout).is_ok());
+        assert!(attestation.validate_data(&layout, &[]).is_ok());
 
         // u8
         let layout = alloc::vec![0];
         attestation.data = Vec::new();
         // Should fail when attestion has no data
-        assert!(attestation.validate_data(layout).is_err());
+        assert!(attestation.validate_data(&layout, &[]).is_err());
 
         // u16
         let layout = alloc::vec![1];
         attestation.data = Vec::new();
         // Should fail when attestion has no data
-        assert!(attestation.validate_data(layout).is_err());
+        assert!(attestation.validate_data(&layout, &[]).is_err());
+    }
+    #[test]
+    fn attestation_validate_data_field_constraints() {
+        let mut attestation = Attestation::default();
+
+        // u8 age, String country, i16 score
+        let layout = alloc::vec![0, 12, 6];
+        let mut constraints: Vec<u8> = Vec::new();
+        // age in [18, 120]
+        constraints.extend([0, FieldConstraintType::Range as u8]);
+        constraints.extend(18u128.to_le_bytes());
+        constraints.extend(120u128.to_le_bytes());
+        // country is at most 2 bytes
+        constraints.extend([1, FieldConstraintType::MaxLength as u8]);
+        constraints.extend(2u32.to_le_bytes());
+        // country is one of "US" or "DE"
+        constraints.extend([1, FieldConstraintType::AllowedValues as u8]);
+        constraints.extend(2u32.to_le_bytes());
+        for country in ["US", "DE"] {
+            let value = to_serialized_vec(country.as_bytes());
+            constraints.extend((value.len() as u32).to_le_bytes());
+            constraints.extend(value);
+        }
+        // score in [-10, 10]
+        constraints.extend([2, FieldConstraintType::Range as u8]);
+        constraints.extend((-10i128).to_le_bytes());
+        constraints.extend(10i128.to_le_bytes());
+
+        let encode = |age: u8, country: &str, score: i16| {
+            let mut data = alloc::vec![age];
+            data.extend(to_serialized_vec(country.as_bytes()));
+            data.extend(score.to_le_bytes());
+            data
+        };
+
+        attestation.data = encode(30, "US", -10);
+        assert!(attestation.validate_data(&layout, &constraints).is_ok());
+
+        // Age out of range
+        attestation.data = encode(17, "US", 0);
+        assert_eq!(
+            attestation.validate_data(&layout, &constraints),
+            Err(AttestationServiceError::FieldConstraintViolation.into())
+        );
+
+        // Country too long
+        attestation.data = encode(30, "USA", 0);
+        assert_eq!(
+            attestation.validate_data(&layout, &constraints),
+            Err(AttestationServiceError::FieldConstraintViolation.into())
+        );
+
+        // Country not allowed
+        attestation.data = encode(30, "FR", 0);
+        assert_eq!(
+            attestation.validate_data(&layout, &constraints),
+            Err(AttestationServiceError::FieldConstraintViolation.into())
+        );
+
+        // Negative score out of range
+        attestation.data = encode(30, "DE", -11);
+        assert_eq!(
+            attestation.validate_data(&layout, &constraints),
+            Err(AttestationServiceError::FieldConstraintViolation.into())
+        );
+
+        // Constraint referencing a field outside the layout
+        let constraints = [3, FieldConstraintType::MaxLength as u8, 1, 0, 0, 0];
+        attestation.data = encode(30, "DE", 0);
+        assert_eq!(
+            attestation.validate_data(&layout, &constraints),
+            Err(AttestationServiceError::InvalidFieldConstraint.into())
+        );
+    }
+
+    #[test]
+    fn attestation_validate_data_canonical() {
+        let mut attestation = Attestation::default();
+        let invalid = Err(AttestationServiceError::InvalidAttestationData.into());
+
+        // Bool
+        let layout = alloc::vec![SchemaDataTypes::Bool as u8];
+        attestation.data = alloc::vec![1];
+        assert!(attestation.validate_data(&layout, &[]).is_ok());
+        attestation.data = alloc::vec![2];
+        assert_eq!(attestation.validate_data(&layout, &[]), invalid);
+
+        // Char
+        let layout = alloc::vec![SchemaDataTypes::Char as u8];
+        attestation.data = ('é' as u32).to_le_bytes().to_vec();
+        assert!(attestation.validate_data(&layout, &[]).is_ok());
+        // Surrogate code point
+        attestation.data = 0xD800u32.to_le_bytes().to_vec();
+        assert_eq!(attestation.validate_data(&layout, &[]), invalid);
+        attestation.data = 0x110000u32.to_le_bytes().to_vec();
+        assert_eq!(attestation.validate_data(&layout, &[]), invalid);
+
+        // String
+        let layout = alloc::vec![SchemaDataTypes::String as u8];
+        attestation.data = to_serialized_vec("test".as_bytes());
+        assert!(attestation.validate_data(&layout, &[]).is_ok());
+        attestation.data = to_serialized_vec(&[0xff, 0xfe]);
+        assert_eq!(attestation.validate_data(&layout, &[]), invalid);
+
+        // Vec<bool>
+        let layout = alloc::vec![SchemaDataTypes::VecBool as u8];
+        attestation.data = to_serialized_vec(&[0, 1, 1]);
+        assert!(attestation.validate_data(&layout, &[]).is_ok());
+        attestation.data = to_serialized_vec(&[0, 1, 3]);
+        assert_eq!(attestation.validate_data(&layout, &[]), invalid);
+
+        // Vec<char>
+        let layout = alloc::vec![SchemaDataTypes::VecChar as u8];
+        let mut data = 2u32.to_le_bytes().to_vec();
+        data.extend(('a' as u32).to_le_bytes());
+        data.extend(0xDFFFu32.to_le_bytes());
+        attestation.data = data;
+        assert_eq!(attestation.validate_data(&layout, &[]), invalid);
+
+        // Vec<String>
+        let layout = alloc::vec![SchemaDataTypes::VecString as u8];
+        let mut data = 2u32.to_le_bytes().to_vec();
+        data.extend(to_serialized_vec("ok".as_bytes()));
+        data.extend(to_serialized_vec(&[0xc3, 0x28]));
+        attestation.data = data;
+        assert_eq!(attestation.validate_data(&layout, &[]), invalid);
+    }
+
+    #[test]
+    fn attestation_field_display() {
+        // u8, i16, bool, String, Vec<i8>, Vec<String>
+        let layout = alloc::vec![
+            SchemaDataTypes::U8 as u8,
+            SchemaDataTypes::I16 as u8,
+            SchemaDataTypes::Bool as u8,
+            SchemaDataTypes::String as u8,
+            SchemaDataTypes::VecI8 as u8,
+            SchemaDataTypes::VecString as u8,
+        ];
+        let mut data = alloc::vec![200];
+        data.extend((-300i16).to_le_bytes());
+        data.push(1);
+        data.extend(to_serialized_vec("DE".as_bytes()));
+        data.extend(to_serialized_vec(&[1, 0xff]));
+        data.extend(2u32.to_le_bytes());
+        data.extend(to_serialized_vec("a".as_bytes()));
+        data.extend(to_serialized_vec("bc".as_bytes()));
+
+        let fields = split_fields(&data, &layout).unwrap();
+        let displayed: Vec<_> = fields
+            .iter()
+            .zip(&layout)
+            .map(|(value, data_type)| {
+                let mut out = alloc::string::String::new();
+                SchemaDataTypes::try_from(*data_type)
+                    .unwrap()
+                    .write_display(value, &mut out)
+                    .unwrap();
+                out
+            })
+            .collect();
+        assert_eq!(displayed, ["200", "-300", "true", "DE", "[1,-1]", "[a,bc]"]);
+
+        // Data that does not conform to the layout.
+        assert_eq!(
+            split_fields(&data[..3], &layout),
+            Err(AttestationServiceError::InvalidAttestationData.into())
+        );
+
+        // Malformed values error instead of panicking.
+        let invalid = Err(AttestationServiceError::InvalidAttestationData.into());
+        for (data_type, value) in [
+            (SchemaDataTypes::Char, &[0x41][..]),
+            (SchemaDataTypes::String, &[1, 0][..]),
+            (SchemaDataTypes::U8, &[][..]),
+            (SchemaDataTypes::I32, &[0; 17][..]),
+            (SchemaDataTypes::VecU16, &[1, 0, 0, 0, 1][..]),
+            (SchemaDataTypes::VecString, &[1, 0, 0, 0, 9, 0, 0, 0][..]),
+        ] {
+            let mut out = alloc::string::String::new();
+            assert_eq!(data_type.write_display(value, &mut out), invalid);
+        }
+    }
+
+    #[test]
+    fn attestation_try_from_bytes_truncated() {
+        let attestation = Attestation {
+            data: alloc::vec![1, 2, 3],
+            ..Attestation::default()
+        };
+        let bytes = attestation.to_bytes();
+        assert_eq!(Attestation::try_from_bytes(&bytes), Ok(attestation.clone()));
+        assert_eq!(
+            AttestationRef::try_from_bytes(&bytes),
+            Ok(attestation.as_view())
+        );
+
+        // Every truncation errors instead of panicking.
+        for len in 0..bytes.len() {
+            assert!(Attestation::try_from_bytes(&bytes[..len]).is_err());
+        }
+
+        // Data length prefix pointing past the end of the account.
+        let mut bytes = bytes;
+        bytes[98..102].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(
+            Attestation::try_from_bytes(&bytes),
+            Err(AttestationServiceError::UnexpectedEndOfData.into())
+        );
+
+        // Unknown data types in the layout are rejected.
+        assert_eq!(
+            attestation.validate_data(&[SchemaDataTypes::max() + 1], &[]),
+            Err(AttestationServiceError::InvalidSchemaDataType.into())
+        );
+    }
+
+    #[test]
+    fn attestation_try_from_bytes_legacy() {
+        let attestation = Attestation {
+            data: alloc::vec![1, 2, 3],
+            expiry: 10,
+            bump: 255,
+            signer_kind: SignerKind::Secp256k1 as u8,
+            ..Attestation::default()
+        };
+
+        // Legacy accounts have no layout version byte, bump, signer kind,
+        // reference or resolver.
+        let mut legacy = alloc::vec![Attestation::LEGACY_DISCRIMINATOR.unwrap()];
+        legacy.extend(attestation.to_bytes_inner());
+        legacy.truncate(legacy.len() - 2 - 32 - 4 - 32);
+        assert_eq!(
+            Attestation::try_from_bytes(&legacy),
+            Ok(Attestation {
+                bump: 0,
+                signer_kind: 0,
+                ..attestation.clone()
+            })
+        );
+
+        // Layout version 1 has a bump but no signer kind.
+        let mut v1 = attestation.to_bytes();
+        v1[1] = 1;
+        v1.truncate(v1.len() - 1 - 32 - 4 - 32);
+        assert_eq!(
+            Attestation::try_from_bytes(&v1),
+            Ok(Attestation {
+                signer_kind: 0,
+                ..attestation.clone()
+            })
+        );
+
+        // Layout version 2 has a signer kind but no reference.
+        let referencing = Attestation {
+            ref_attestation: Pubkey::from([1; 32]),
+            ref_count: 2,
+            ..attestation.clone()
+        };
+        let mut v2 = referencing.to_bytes();
+        v2[1] = 2;
+        v2.truncate(v2.len() - 32 - 4 - 32);
+        assert_eq!(Attestation::try_from_bytes(&v2), Ok(attestation.clone()));
+
+        // Layout version 3 has a reference but no resolver.
+        let resolved = Attestation {
+            resolver_program: Pubkey::from([2; 32]),
+            ..referencing.clone()
+        };
+        let mut v3 = resolved.to_bytes();
+        v3[1] = 3;
+        v3.truncate(v3.len() - 32);
+        assert_eq!(Attestation::try_from_bytes(&v3), Ok(referencing.clone()));
+
+        // Unknown layout versions are rejected.
+        let mut bytes = attestation.to_bytes();
+        bytes[1] = Attestation::LAYOUT_VERSION + 1;
+        assert_eq!(
+            Attestation::try_from_bytes(&bytes),
+            Err(ProgramError::InvalidAccountData)
+        );
+        bytes[1] = 0;
+        assert_eq!(
+            Attestation::try_from_bytes(&bytes),
+            Err(ProgramError::InvalidAccountData)
+        );
     }
 }
@@ -0,0 +1,79 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{msg, program_error::ProgramError, pubkey::Pubkey};
+use shank::ShankAccount;
+
+use crate::processor::DataReader;
+
+use super::discriminator::{
+    read_layout_version, AccountSerialize, AttestationAccountDiscriminators, Discriminator,
+};
+
+// PDA ["attestationRequest", credential, schema, nonce]
+#[derive(Clone, Debug, PartialEq, ShankAccount)]
+#[repr(C)]
+pub struct AttestationRequest {
+    /// Account that requested the Attestation and paid for the request. It is
+    /// refunded when the request is approved or rejected.
+    pub subject: Pubkey,
+    /// Nonce of the Attestation created when the request is approved
+    pub nonce: Pubkey,
+    /// Credential the Attestation is requested from
+    pub credential: Pubkey,
+    /// Reference to the Schema the proposed data adheres to
+    pub schema: Pubkey,
+    /// Proposed Attestation data, validated against the Schema
+    pub data: Vec<u8>,
+    /// Proposed expiry of the Attestation. 0 means never expired
+    pub expiry: i64,
+    /// Canonical bump of the AttestationRequest PDA
+    pub bump: u8,
+}
+
+// Layout versions
+// 1 - subject, nonce, credential, schema, data, expiry, bump
+
+impl Discriminator for AttestationRequest {
+    const DISCRIMINATOR: u8 =
+        AttestationAccountDiscriminators::AttestationRequestDiscriminator as u8;
+    const LAYOUT_VERSION: u8 = 1;
+}
+
+impl AccountSerialize for AttestationRequest {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(self.subject.as_ref());
+        data.extend_from_slice(self.nonce.as_ref());
+        data.extend_from_slice(self.credential.as_ref());
+        data.extend_from_slice(self.schema.as_ref());
+        data.extend_from_slice(&(self.data.len() as u32).to_le_bytes());
+        data.extend_from_slice(&self.data);
+        data.extend_from_slice(&self.expiry.to_le_bytes());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl AttestationRequest {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        let mut reader = DataReader::new(data);
+
+        // Check discriminator and layout version
+        if read_layout_version::<Self>(&mut reader)?.is_none() {
+            msg!("Invalid AttestationRequest Data");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            subject: reader.read_pubkey()?,
+            nonce: reader.read_pubkey()?,
+            credential: reader.read_pubkey()?,
+            schema: reader.read_pubkey()?,
+            data: reader.read_vec()?.to_vec(),
+            expiry: reader.read_i64()?,
+            bump: reader.read_u8()?,
+        })
+    }
+}
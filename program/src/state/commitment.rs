@@ -0,0 +1,77 @@
+use pinocchio::program_error::ProgramError;
+
+use crate::error::AttestationServiceError;
+
+/// Modulus of the BN254 scalar field, big-endian. Poseidon commitments are
+/// elements of this field, so they are below it.
+pub const BN254_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// How Attestation data is stored under a Schema.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommitmentScheme {
+    /// Data is stored in plaintext and validated against the Schema layout.
+    None = 0,
+    /// Data is `sha256(data || salt)`, where `data` is encoded with the Schema
+    /// layout and `salt` is 32 random bytes kept by the holder.
+    Sha256 = 1,
+    /// Data is one `sha256(field || salt)` per field, ordered by the Schema
+    /// layout, with a separate salt per field so fields can be disclosed
+    /// individually.
+    Sha256PerField = 2,
+    /// Data is a Poseidon (BN254) commitment over the field elements of the
+    /// data and a salt, computed by the client so the data stays off-chain.
+    /// The program only checks it is a canonical field element, big-endian.
+    Poseidon = 3,
+}
+
+impl CommitmentScheme {
+    /// Length in bytes of the Attestation data stored under this scheme for
+    /// a Schema with `field_count` fields, or None when data is stored in
+    /// plaintext.
+    pub fn commitment_len(&self, field_count: usize) -> Option<usize> {
+        match self {
+            CommitmentScheme::None => None,
+            CommitmentScheme::Sha256 => Some(32),
+            CommitmentScheme::Sha256PerField => field_count.checked_mul(32),
+            CommitmentScheme::Poseidon => Some(32),
+        }
+    }
+
+    /// Whether Attestations are created from plaintext data that is validated
+    /// against the Schema, rather than from a commitment computed by the client.
+    pub fn validates_data(&self) -> bool {
+        matches!(self, CommitmentScheme::None)
+    }
+
+    /// Whether `data` can be a commitment of this scheme for a Schema with
+    /// `field_count` fields: it has the commitment length and, for Poseidon,
+    /// is below the BN254 modulus.
+    pub fn is_commitment(&self, field_count: usize, data: &[u8]) -> bool {
+        if self.commitment_len(field_count) != Some(data.len()) {
+            return false;
+        }
+        match self {
+            // Equal length slices compare as big-endian integers.
+            CommitmentScheme::Poseidon => data < BN254_MODULUS.as_slice(),
+            _ => true,
+        }
+    }
+}
+
+impl TryFrom<u8> for CommitmentScheme {
+    type Error = ProgramError;
+
+    fn try_from(byte: u8) -> Result<CommitmentScheme, ProgramError> {
+        match byte {
+            0 => Ok(CommitmentScheme::None),
+            1 => Ok(CommitmentScheme::Sha256),
+            2 => Ok(CommitmentScheme::Sha256PerField),
+            3 => Ok(CommitmentScheme::Poseidon),
+            _ => Err(AttestationServiceError::InvalidCommitmentScheme.into()),
+        }
+    }
+}
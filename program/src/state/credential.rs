@@ -5,9 +5,18 @@ use pinocchio::{msg, program_error::ProgramError, pubkey::Pubkey};
 use pinocchio_log::log;
 use shank::ShankAccount;
 
-use crate::error::AttestationServiceError;
+use crate::{
+    constants::{MAX_DESCRIPTION_LEN, MAX_DISPLAY_NAME_LEN, MAX_NAME_LEN, MAX_URI_LEN},
+    error::AttestationServiceError,
+    processor::{validate_name, validate_utf8, DataReader},
+};
 
-use super::discriminator::{AccountSerialize, AttestationAccountDiscriminators, Discriminator};
+use super::{
+    discriminator::{
+        read_layout_version, AccountSerialize, AttestationAccountDiscriminators, Discriminator,
+    },
+    ethereum_address, passkey_signer, SignerKind, PASSKEY_LEN,
+};
 
 // PDA ["credential", authority, name]
 /// Tracks the authorized signers of for schemas and their attestations.
@@ -21,10 +30,39 @@ pub struct Credential {
     pub name: Vec<u8>,
     /// List of signers that are allowed to "attest"
     pub authorized_signers: Vec<Pubkey>,
+    /// Canonical bump of the Credential PDA
+    pub bump: u8,
+    /// UTF-8 encoded name shown to users in place of the PDA seed name
+    pub display_name: Vec<u8>,
+    /// UTF-8 encoded description of the issuer
+    pub description: Vec<u8>,
+    /// URI of the issuer's off-chain profile
+    pub uri: Vec<u8>,
+    /// URI of the issuer's logo
+    pub logo_uri: Vec<u8>,
+    /// URL of the issuer's website
+    pub website: Vec<u8>,
+    /// Whether issuance under this credential is paused
+    pub is_paused: bool,
+    /// Compressed secp256r1 public keys of passkeys that are allowed to "attest"
+    pub passkey_signers: Vec<[u8; 33]>,
+    /// SignerKind of each of the `authorized_signers`, in the same order
+    pub authorized_signer_kinds: Vec<u8>,
 }
 
+// Layout versions
+// 0 - authority, name, authorized_signers
+// 1 - adds bump
+// 2 - adds display_name, description, uri, logo_uri and website
+// 3 - adds is_paused
+// 4 - adds passkey_signers
+// 5 - adds authorized_signer_kinds
+
 impl Discriminator for Credential {
     const DISCRIMINATOR: u8 = AttestationAccountDiscriminators::CredentialDiscriminator as u8;
+    const LEGACY_DISCRIMINATOR: Option<u8> =
+        Some(AttestationAccountDiscriminators::LegacyCredentialDiscriminator as u8);
+    const LAYOUT_VERSION: u8 = 5;
 }
 
 impl AccountSerialize for Credential {
@@ -43,59 +81,264 @@ impl AccountSerialize for Credential {
             data.extend_from_slice(signer.as_ref());
         }
 
+        data.push(self.bump);
+
+        // Metadata encoding
+        for field in self.metadata() {
+            data.extend(&(field.len() as u32).to_le_bytes());
+            data.extend_from_slice(field);
+        }
+
+        data.push(self.is_paused as u8);
+
+        // Passkey signers encoding
+        data.extend_from_slice(&(self.passkey_signers.len() as u32).to_le_bytes());
+        for passkey in &self.passkey_signers {
+            data.extend_from_slice(passkey);
+        }
+
+        // Authorized signer kinds encoding
+        data.extend_from_slice(&(self.authorized_signer_kinds.len() as u32).to_le_bytes());
+        data.extend_from_slice(&self.authorized_signer_kinds);
+
         data
     }
 }
 
 impl Credential {
-    pub fn validate_authority(&self, authority: &Pubkey) -> Result<(), ProgramError> {
-        if self.authority.ne(authority) {
-            log!("Authority Mismatch");
-            return Err(ProgramError::InvalidAccountData);
+    /// Validate the name is non-empty, UTF-8 encoded and fits in a PDA seed.
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        validate_name(&self.name, MAX_NAME_LEN)
+    }
+
+    /// Validate there is a kind for each authorized signer, and that it is
+    /// ed25519, or secp256k1 for a zero-padded Ethereum address. Passkeys are
+    /// stored in `passkey_signers` instead.
+    pub fn validate_authorized_signers(&self) -> Result<(), ProgramError> {
+        if self.authorized_signer_kinds.len() != self.authorized_signers.len() {
+            return Err(AttestationServiceError::InvalidSignerKind.into());
+        }
+        for (signer, kind) in self
+            .authorized_signers
+            .iter()
+            .zip(&self.authorized_signer_kinds)
+        {
+            let valid = match *kind {
+                kind if kind == SignerKind::Ed25519 as u8 => true,
+                kind if kind == SignerKind::Secp256k1 as u8 => ethereum_address(signer).is_some(),
+                _ => false,
+            };
+            if !valid {
+                return Err(AttestationServiceError::InvalidSignerKind.into());
+            }
         }
         Ok(())
     }
 
-    /// Validate the signer is one of the authorized signers.
-    pub fn validate_authorized_signer(&self, signer: &Pubkey) -> Result<(), ProgramError> {
-        if !self.authorized_signers.contains(signer) {
-            return Err(AttestationServiceError::SignerNotAuthorized.into());
+    /// Validate the metadata is UTF-8 encoded and within length limits.
+    pub fn validate_metadata(&self) -> Result<(), ProgramError> {
+        validate_utf8(&self.display_name, MAX_DISPLAY_NAME_LEN)?;
+        validate_utf8(&self.description, MAX_DESCRIPTION_LEN)?;
+        validate_utf8(&self.uri, MAX_URI_LEN)?;
+        validate_utf8(&self.logo_uri, MAX_URI_LEN)?;
+        validate_utf8(&self.website, MAX_URI_LEN)
+    }
+
+    /// Metadata fields in serialization order.
+    pub fn metadata(&self) -> [&[u8]; 5] {
+        [
+            &self.display_name,
+            &self.description,
+            &self.uri,
+            &self.logo_uri,
+            &self.website,
+        ]
+    }
+
+    pub fn validate_authority(&self, authority: &Pubkey) -> Result<(), ProgramError> {
+        if self.authority.ne(authority) {
+            log!("Authority Mismatch");
+            return Err(ProgramError::InvalidAccountData);
         }
         Ok(())
     }
 
     pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
-        // Check discriminator
-        if data[0] != Self::DISCRIMINATOR {
-            msg!("Invalid Credential Data");
-            return Err(ProgramError::InvalidAccountData);
-        }
+        CredentialRef::try_from_bytes(data).map(Self::from)
+    }
+}
 
-        // Start offset after Discriminator
-        let mut offset: usize = 1;
+/// Borrowed view of Credential account data that reads fields in place.
+pub struct CredentialRef<'a> {
+    pub authority: &'a Pubkey,
+    pub name: &'a [u8],
+    /// Serialized authorized signers, 32 bytes each.
+    authorized_signers: &'a [u8],
+    /// Zero for legacy accounts that have not been migrated.
+    pub bump: u8,
+    pub display_name: &'a [u8],
+    pub description: &'a [u8],
+    pub uri: &'a [u8],
+    pub logo_uri: &'a [u8],
+    pub website: &'a [u8],
+    pub is_paused: bool,
+    /// Serialized passkey signers, 33 bytes each.
+    passkey_signers: &'a [u8],
+    /// Kind of each authorized signer. None for accounts written before
+    /// layout version 5.
+    authorized_signer_kinds: Option<&'a [u8]>,
+}
 
-        let authority: Pubkey = data[offset..offset + 32].try_into().unwrap();
-        offset += 32;
+impl<'a> CredentialRef<'a> {
+    pub fn try_from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let mut reader = DataReader::new(data);
 
-        let name_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-        offset += 4;
-        let name = data[offset..offset + name_len].to_vec();
-        offset += name_len;
+        // Check discriminator and layout version
+        let Some(layout_version) = read_layout_version::<Credential>(&mut reader)? else {
+            msg!("Invalid Credential Data");
+            return Err(ProgramError::InvalidAccountData);
+        };
 
-        let signers_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-        offset += 4;
+        let authority = reader.read_pubkey_ref()?;
+        let name = reader.read_vec()?;
 
-        let mut authorized_signers: Vec<Pubkey> = Vec::new();
-        for _ in 0..signers_len {
-            let signer: Pubkey = data[offset..offset + 32].try_into().unwrap();
-            authorized_signers.push(signer);
-            offset += 32;
+        let signers_len = reader.read_u32()? as usize;
+        let authorized_signers = reader.read_bytes(
+            signers_len
+                .checked_mul(32)
+                .ok_or(AttestationServiceError::ArithmeticOverflow)?,
+        )?;
+        let bump = if layout_version >= 1 {
+            reader.read_u8()?
+        } else {
+            0
+        };
+        // Metadata is empty for accounts written before layout version 2.
+        let mut metadata: [&[u8]; 5] = [&[]; 5];
+        if layout_version >= 2 {
+            for field in metadata.iter_mut() {
+                *field = reader.read_vec()?;
+            }
         }
+        let [display_name, description, uri, logo_uri, website] = metadata;
+        let is_paused = layout_version >= 3 && reader.read_u8()? == 1;
+        let passkey_signers = if layout_version >= 4 {
+            let passkeys_len = reader.read_u32()? as usize;
+            reader.read_bytes(
+                passkeys_len
+                    .checked_mul(PASSKEY_LEN)
+                    .ok_or(AttestationServiceError::ArithmeticOverflow)?,
+            )?
+        } else {
+            &[]
+        };
+        let authorized_signer_kinds = if layout_version >= 5 {
+            let kinds = reader.read_vec()?;
+            if kinds.len() != signers_len {
+                msg!("Invalid Credential Data");
+                return Err(ProgramError::InvalidAccountData);
+            }
+            Some(kinds)
+        } else {
+            None
+        };
 
         Ok(Self {
             authority,
             name,
             authorized_signers,
+            bump,
+            display_name,
+            description,
+            uri,
+            logo_uri,
+            website,
+            is_paused,
+            passkey_signers,
+            authorized_signer_kinds,
         })
     }
+
+    pub fn authorized_signers(&self) -> impl Iterator<Item = &'a Pubkey> {
+        self.authorized_signers
+            .chunks_exact(32)
+            .filter_map(|signer| signer.first_chunk())
+    }
+
+    /// Authorized signers with their SignerKind. Accounts written before
+    /// layout version 5 stored Ethereum addresses without a kind, so their
+    /// zero-padded signers are secp256k1 until the account is migrated.
+    pub fn authorized_signers_with_kinds(&self) -> impl Iterator<Item = (&'a Pubkey, u8)> {
+        let kinds = self.authorized_signer_kinds;
+        self.authorized_signers()
+            .enumerate()
+            .map(move |(index, signer)| match kinds {
+                Some(kinds) => (signer, kinds[index]),
+                None if ethereum_address(signer).is_some() => (signer, SignerKind::Secp256k1 as u8),
+                None => (signer, SignerKind::Ed25519 as u8),
+            })
+    }
+
+    pub fn passkey_signers(&self) -> impl Iterator<Item = &'a [u8; PASSKEY_LEN]> {
+        self.passkey_signers
+            .chunks_exact(PASSKEY_LEN)
+            .filter_map(|passkey| passkey.first_chunk())
+    }
+
+    /// Validate the signer of the transaction is one of the ed25519
+    /// authorized signers.
+    pub fn validate_authorized_signer(&self, signer: &Pubkey) -> Result<(), ProgramError> {
+        self.validate_signer(signer, SignerKind::Ed25519)
+    }
+
+    /// Validate the signer, a key of `signer_kind`, is authorized. Passkeys
+    /// are matched by their [`passkey_signer`].
+    pub fn validate_signer(
+        &self,
+        signer: &Pubkey,
+        signer_kind: SignerKind,
+    ) -> Result<(), ProgramError> {
+        match signer_kind {
+            SignerKind::Secp256r1 => {
+                if !self
+                    .passkey_signers()
+                    .any(|passkey| passkey_signer(passkey) == *signer)
+                {
+                    return Err(AttestationServiceError::SignerNotAuthorized.into());
+                }
+                Ok(())
+            }
+            SignerKind::Ed25519 | SignerKind::Secp256k1 => {
+                if !self
+                    .authorized_signers_with_kinds()
+                    .any(|(s, kind)| s == signer && kind == signer_kind as u8)
+                {
+                    return Err(AttestationServiceError::SignerNotAuthorized.into());
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl From<CredentialRef<'_>> for Credential {
+    fn from(credential: CredentialRef<'_>) -> Self {
+        Self {
+            authority: *credential.authority,
+            name: credential.name.to_vec(),
+            authorized_signers: credential.authorized_signers().copied().collect(),
+            bump: credential.bump,
+            display_name: credential.display_name.to_vec(),
+            description: credential.description.to_vec(),
+            uri: credential.uri.to_vec(),
+            logo_uri: credential.logo_uri.to_vec(),
+            website: credential.website.to_vec(),
+            is_paused: credential.is_paused,
+            passkey_signers: credential.passkey_signers().copied().collect(),
+            authorized_signer_kinds: credential
+                .authorized_signers_with_kinds()
+                .map(|(_, kind)| kind)
+                .collect(),
+        }
+    }
 }
@@ -1,23 +1,69 @@
 extern crate alloc;
 
 use alloc::vec::Vec;
+use pinocchio::program_error::ProgramError;
+
+use crate::processor::DataReader;
+
 pub trait Discriminator {
     const DISCRIMINATOR: u8;
+    /// Discriminator of accounts written before the header carried a layout
+    /// version. None for account types added after it.
+    const LEGACY_DISCRIMINATOR: Option<u8> = None;
+    /// Current layout version, written after the discriminator.
+    const LAYOUT_VERSION: u8;
 }
 
 #[repr(u8)]
 pub enum AttestationAccountDiscriminators {
-    CredentialDiscriminator = 0,
-    SchemaDiscriminator = 1,
-    AttestationDiscriminator = 2,
+    LegacyCredentialDiscriminator = 0,
+    LegacySchemaDiscriminator = 1,
+    LegacyAttestationDiscriminator = 2,
+    CredentialDiscriminator = 3,
+    SchemaDiscriminator = 4,
+    AttestationDiscriminator = 5,
+    MerkleAttestationBatchDiscriminator = 6,
+    AttestationRequestDiscriminator = 7,
+}
+
+/// Layout version of accounts that predate the versioned header.
+pub const LEGACY_LAYOUT_VERSION: u8 = 0;
+
+/// Read the account header and return the layout version of the account.
+/// Legacy accounts have no version byte and are reported as
+/// `LEGACY_LAYOUT_VERSION`. Returns `None` if the discriminator does not
+/// match or the layout version is unknown.
+pub fn read_layout_version<T: Discriminator>(
+    reader: &mut DataReader,
+) -> Result<Option<u8>, ProgramError> {
+    let discriminator = reader.read_u8()?;
+    if Some(discriminator) == T::LEGACY_DISCRIMINATOR {
+        return Ok(Some(LEGACY_LAYOUT_VERSION));
+    }
+    if discriminator != T::DISCRIMINATOR {
+        return Ok(None);
+    }
+
+    let layout_version = reader.read_u8()?;
+    if layout_version == LEGACY_LAYOUT_VERSION || layout_version > T::LAYOUT_VERSION {
+        return Ok(None);
+    }
+    Ok(Some(layout_version))
+}
+
+/// Check whether the account data starts with the current header of `T`.
+pub fn is_current_layout<T: Discriminator>(data: &[u8]) -> bool {
+    data.first_chunk::<2>() == Some(&[T::DISCRIMINATOR, T::LAYOUT_VERSION])
 }
 
 pub trait AccountSerialize: Discriminator {
-    /// Serialize the struct with the Discriminator prepended.
+    /// Serialize the struct with the account header prepended.
     fn to_bytes(&self) -> Vec<u8> {
         let mut data = Vec::new();
         // Discriminator
         data.push(Self::DISCRIMINATOR);
+        // Layout version
+        data.push(Self::LAYOUT_VERSION);
 
         data.extend(self.to_bytes_inner());
 
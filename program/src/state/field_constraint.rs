@@ -0,0 +1,183 @@
+use pinocchio::program_error::ProgramError;
+
+use crate::error::AttestationServiceError;
+
+use super::SchemaDataTypes;
+
+#[repr(u8)]
+pub enum FieldConstraintType {
+    Range = 0,
+    MaxLength = 1,
+    AllowedValues = 2,
+}
+
+/// A constraint on a single Schema field.
+///
+/// Serialized as `field_index: u8`, `constraint_type: u8`, followed by the
+/// constraint payload:
+/// * `Range` - 16 byte little-endian min and max, inclusive. Read as u128 for
+///   unsigned fields and i128 for signed fields.
+/// * `MaxLength` - u32 max byte length of a String or element count of a Vec.
+/// * `AllowedValues` - u32 count, followed by each value in the field's own
+///   encoding, prefixed by its u32 byte length.
+pub enum FieldConstraint<'a> {
+    Range { min: [u8; 16], max: [u8; 16] },
+    MaxLength(u32),
+    AllowedValues { count: u32, values: &'a [u8] },
+}
+
+/// Iterator over the serialized `(field_index, FieldConstraint)` entries of a Schema.
+pub struct FieldConstraints<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> FieldConstraints<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    fn read(&mut self, len: usize) -> Result<&'a [u8], ProgramError> {
+        let end = self
+            .offset
+            .checked_add(len)
+            .ok_or(AttestationServiceError::InvalidFieldConstraint)?;
+        let bytes = self
+            .data
+            .get(self.offset..end)
+            .ok_or(AttestationServiceError::InvalidFieldConstraint)?;
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, ProgramError> {
+        Ok(u32::from_le_bytes(self.read(4)?.try_into().unwrap()))
+    }
+
+    fn read_entry(&mut self) -> Result<(u8, FieldConstraint<'a>), ProgramError> {
+        let header = self.read(2)?;
+        let (field_index, constraint_type) = (header[0], header[1]);
+
+        let constraint = match constraint_type {
+            t if t == FieldConstraintType::Range as u8 => FieldConstraint::Range {
+                min: self.read(16)?.try_into().unwrap(),
+                max: self.read(16)?.try_into().unwrap(),
+            },
+            t if t == FieldConstraintType::MaxLength as u8 => {
+                FieldConstraint::MaxLength(self.read_u32()?)
+            }
+            t if t == FieldConstraintType::AllowedValues as u8 => {
+                let count = self.read_u32()?;
+                let start = self.offset;
+                for _ in 0..count {
+                    let value_len = self.read_u32()? as usize;
+                    self.read(value_len)?;
+                }
+                FieldConstraint::AllowedValues {
+                    count,
+                    values: &self.data[start..self.offset],
+                }
+            }
+            _ => return Err(AttestationServiceError::InvalidFieldConstraint.into()),
+        };
+
+        Ok((field_index, constraint))
+    }
+}
+
+impl<'a> Iterator for FieldConstraints<'a> {
+    type Item = Result<(u8, FieldConstraint<'a>), ProgramError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+        let entry = self.read_entry();
+        if entry.is_err() {
+            // Stop iterating on malformed data.
+            self.offset = self.data.len();
+        }
+        Some(entry)
+    }
+}
+
+/// Iterator over the length-prefixed values of an `AllowedValues` constraint.
+struct AllowedValues<'a> {
+    values: &'a [u8],
+}
+
+impl<'a> Iterator for AllowedValues<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Bounds were checked when the constraint was read.
+        let (len_bytes, rest) = self.values.split_first_chunk::<4>()?;
+        let (value, rest) = rest.split_at(u32::from_le_bytes(*len_bytes) as usize);
+        self.values = rest;
+        Some(value)
+    }
+}
+
+impl FieldConstraint<'_> {
+    /// Validate the constraint can be applied to a field of the given type.
+    pub fn validate(&self, data_type: &SchemaDataTypes) -> Result<(), ProgramError> {
+        let is_valid = match self {
+            FieldConstraint::Range { min, max } => match data_type.integer_signedness() {
+                Some(true) => i128::from_le_bytes(*min) <= i128::from_le_bytes(*max),
+                Some(false) => u128::from_le_bytes(*min) <= u128::from_le_bytes(*max),
+                None => false,
+            },
+            FieldConstraint::MaxLength(_) => data_type.is_variable_length(),
+            FieldConstraint::AllowedValues { count, values } => {
+                // Every allowed value must be a complete encoding of the field type.
+                *count > 0
+                    && (AllowedValues { values }).all(|value| {
+                        data_type.size_of(value, 0) == Some(value.len())
+                            && data_type.is_canonical(value)
+                    })
+            }
+        };
+
+        if !is_valid {
+            return Err(AttestationServiceError::InvalidFieldConstraint.into());
+        }
+        Ok(())
+    }
+
+    /// Check the encoded field value satisfies the constraint.
+    pub fn is_satisfied_by(&self, data_type: &SchemaDataTypes, field: &[u8]) -> bool {
+        match self {
+            FieldConstraint::Range { min, max } => {
+                if field.len() > 16 {
+                    return false;
+                }
+                let mut value = [0u8; 16];
+                value[..field.len()].copy_from_slice(field);
+                match data_type.integer_signedness() {
+                    Some(true) => {
+                        // Sign extend negative values.
+                        if field.last().is_some_and(|b| b & 0x80 != 0) {
+                            value[field.len()..].fill(0xff);
+                        }
+                        let value = i128::from_le_bytes(value);
+                        i128::from_le_bytes(*min) <= value && value <= i128::from_le_bytes(*max)
+                    }
+                    Some(false) => {
+                        let value = u128::from_le_bytes(value);
+                        u128::from_le_bytes(*min) <= value && value <= u128::from_le_bytes(*max)
+                    }
+                    None => false,
+                }
+            }
+            FieldConstraint::MaxLength(max_len) => {
+                // Strings and Vecs are prefixed with their u32 length.
+                field
+                    .first_chunk::<4>()
+                    .is_some_and(|len| u32::from_le_bytes(*len) <= *max_len)
+            }
+            FieldConstraint::AllowedValues { values, .. } => {
+                (AllowedValues { values }).any(|value| value == field)
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use pinocchio::{msg, program_error::ProgramError, pubkey::Pubkey};
+use shank::ShankAccount;
+use solana_sha256_hasher::hashv;
+
+use crate::processor::DataReader;
+
+use super::discriminator::{
+    read_layout_version, AccountSerialize, AttestationAccountDiscriminators, Discriminator,
+};
+
+/// Domain separation prefixes so a leaf can never be passed off as a node.
+pub const MERKLE_LEAF_PREFIX: &[u8] = &[0];
+pub const MERKLE_NODE_PREFIX: &[u8] = &[1];
+
+// PDA ["merkleBatch", credential, schema, nonce]
+#[derive(Clone, Debug, PartialEq, ShankAccount)]
+#[repr(C)]
+pub struct MerkleAttestationBatch {
+    /// A pubkey that distinguishes batches issued under the same Schema
+    pub nonce: Pubkey,
+    /// Credential the batched Attestations are related to
+    pub credential: Pubkey,
+    /// Reference to the Schema the batched Attestations adhere to
+    pub schema: Pubkey,
+    /// Merkle root over the `(subject, data, expiry)` leaves of the batch
+    pub root: [u8; 32],
+    /// The pubkey of the signer. Must be one of the `authorized_signer`s at time of attestation
+    pub signer: Pubkey,
+    /// Canonical bump of the MerkleAttestationBatch PDA
+    pub bump: u8,
+}
+
+// Layout versions
+// 1 - nonce, credential, schema, root, signer, bump
+
+impl Discriminator for MerkleAttestationBatch {
+    const DISCRIMINATOR: u8 =
+        AttestationAccountDiscriminators::MerkleAttestationBatchDiscriminator as u8;
+    const LAYOUT_VERSION: u8 = 1;
+}
+
+impl AccountSerialize for MerkleAttestationBatch {
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(self.nonce.as_ref());
+        data.extend_from_slice(self.credential.as_ref());
+        data.extend_from_slice(self.schema.as_ref());
+        data.extend_from_slice(self.root.as_ref());
+        data.extend_from_slice(self.signer.as_ref());
+        data.push(self.bump);
+
+        data
+    }
+}
+
+impl MerkleAttestationBatch {
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        let mut reader = DataReader::new(data);
+
+        // Check discriminator and layout version
+        if read_layout_version::<Self>(&mut reader)?.is_none() {
+            msg!("Invalid MerkleAttestationBatch Data");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self {
+            nonce: reader.read_pubkey()?,
+            credential: reader.read_pubkey()?,
+            schema: reader.read_pubkey()?,
+            root: reader.read_array()?,
+            signer: reader.read_pubkey()?,
+            bump: reader.read_u8()?,
+        })
+    }
+
+    /// Whether `proof`, the sibling hashes from the leaf up, proves that
+    /// `leaf` is in the tree with this batch's root.
+    pub fn verify_proof(&self, leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+        proof
+            .iter()
+            .fold(leaf, |node, sibling| merkle_node(&node, sibling))
+            == self.root
+    }
+}
+
+/// Hash of the `(subject, data, expiry)` leaf of a batched Attestation.
+pub fn merkle_leaf(subject: &Pubkey, data: &[u8], expiry: i64) -> [u8; 32] {
+    hashv(&[
+        MERKLE_LEAF_PREFIX,
+        subject,
+        &(data.len() as u32).to_le_bytes(),
+        data,
+        &expiry.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Hash of an inner node. Children are sorted so proofs need no direction bits.
+pub fn merkle_node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[MERKLE_NODE_PREFIX, left, right]).to_bytes()
+}
@@ -1,8 +1,18 @@
 pub mod attestation;
+pub mod attestation_request;
+pub mod commitment;
 pub mod credential;
 pub mod discriminator;
+pub mod field_constraint;
+pub mod merkle_attestation_batch;
 pub mod schema;
+pub mod signer;
 
 pub use attestation::*;
+pub use attestation_request::*;
+pub use commitment::*;
 pub use credential::*;
+pub use field_constraint::*;
+pub use merkle_attestation_batch::*;
 pub use schema::*;
+pub use signer::*;
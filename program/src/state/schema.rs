@@ -1,13 +1,44 @@
 extern crate alloc;
 
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
 use pinocchio::{msg, program_error::ProgramError, pubkey::Pubkey};
 use pinocchio_log::log;
 use shank::ShankAccount;
 
-use crate::error::AttestationServiceError;
+use crate::{
+    constants::{MAX_DESCRIPTION_LEN, MAX_FIELD_NAME_LEN, MAX_NAME_LEN, RESERVED_METADATA_KEYS},
+    error::AttestationServiceError,
+    processor::{validate_name, validate_utf8, DataReader},
+};
 
-use super::discriminator::{AccountSerialize, AttestationAccountDiscriminators, Discriminator};
+use super::{
+    discriminator::{
+        read_layout_version, AccountSerialize, AttestationAccountDiscriminators, Discriminator,
+    },
+    CommitmentScheme, FieldConstraints,
+};
+
+/// `ref_schema` of Schemas whose Attestations may reference any Attestation,
+/// or none.
+pub const NO_REF_SCHEMA: Pubkey = [0; 32];
+
+/// `fee_mint` of Schemas whose fee is paid in lamports.
+pub const NO_FEE_MINT: Pubkey = [0; 32];
+
+/// `resolver_program` of Schemas without a resolver.
+pub const NO_RESOLVER_PROGRAM: Pubkey = [0; 32];
+
+/// `token_policy` flag: Attestation tokens cannot be transferred.
+pub const TOKEN_POLICY_NON_TRANSFERABLE: u8 = 1 << 0;
+/// `token_policy` flag: the program is the permanent delegate of Attestation
+/// tokens, so closing an Attestation burns its token wherever it is held.
+pub const TOKEN_POLICY_PERMANENT_DELEGATE: u8 = 1 << 1;
+/// `token_policy` flag: anyone can freeze the tokens of expired Attestations.
+pub const TOKEN_POLICY_FREEZE_ON_EXPIRY: u8 = 1 << 2;
+/// `token_policy` of Schemas that have not been tokenized with a policy.
+pub const DEFAULT_TOKEN_POLICY: u8 =
+    TOKEN_POLICY_NON_TRANSFERABLE | TOKEN_POLICY_PERMANENT_DELEGATE;
 
 #[repr(u8)]
 pub enum SchemaDataTypes {
@@ -43,11 +74,233 @@ impl SchemaDataTypes {
     pub fn max() -> u8 {
         SchemaDataTypes::VecString as u8
     }
+
+    /// Returns whether an integer type is signed, or None for non-integer types.
+    pub fn integer_signedness(&self) -> Option<bool> {
+        match self {
+            SchemaDataTypes::U8
+            | SchemaDataTypes::U16
+            | SchemaDataTypes::U32
+            | SchemaDataTypes::U64
+            | SchemaDataTypes::U128 => Some(false),
+            SchemaDataTypes::I8
+            | SchemaDataTypes::I16
+            | SchemaDataTypes::I32
+            | SchemaDataTypes::I64
+            | SchemaDataTypes::I128 => Some(true),
+            _ => None,
+        }
+    }
+
+    /// Whether the type is encoded with a u32 length prefix (String and Vec types).
+    pub fn is_variable_length(&self) -> bool {
+        matches!(self, SchemaDataTypes::String) || self.vec_element_size().is_some()
+    }
+
+    /// Size in bytes of each element of a Vec type, or None for other types.
+    /// Vec<String> elements are variable length and reported as 0.
+    fn vec_element_size(&self) -> Option<usize> {
+        match self {
+            SchemaDataTypes::VecU8 | SchemaDataTypes::VecI8 | SchemaDataTypes::VecBool => Some(1),
+            SchemaDataTypes::VecU16 | SchemaDataTypes::VecI16 => Some(2),
+            SchemaDataTypes::VecU32 | SchemaDataTypes::VecI32 | SchemaDataTypes::VecChar => Some(4),
+            SchemaDataTypes::VecU64 | SchemaDataTypes::VecI64 => Some(8),
+            SchemaDataTypes::VecU128 | SchemaDataTypes::VecI128 => Some(16),
+            SchemaDataTypes::VecString => Some(0),
+            _ => None,
+        }
+    }
+
+    /// Size in bytes of a value of this type encoded at `offset` in `data`.
+    /// Returns None if the encoding does not fit in `data`.
+    pub fn size_of(&self, data: &[u8], offset: usize) -> Option<usize> {
+        let size = match self {
+            // u8 -> u128, i8 -> i128, bool, char
+            SchemaDataTypes::U8 | SchemaDataTypes::I8 | SchemaDataTypes::Bool => 1,
+            SchemaDataTypes::U16 | SchemaDataTypes::I16 => 2,
+            SchemaDataTypes::U32 | SchemaDataTypes::I32 | SchemaDataTypes::Char => 4,
+            SchemaDataTypes::U64 | SchemaDataTypes::I64 => 8,
+            SchemaDataTypes::U128 | SchemaDataTypes::I128 => 16,
+            // String
+            SchemaDataTypes::String => 4 + read_len(data, offset)?,
+            // Vec<String>: must iterate over the strings using their len
+            SchemaDataTypes::VecString => {
+                let mut size = 4;
+                for _ in 0..read_len(data, offset)? {
+                    size += 4 + read_len(data, offset.checked_add(size)?)?;
+                    if offset.checked_add(size)? > data.len() {
+                        return None;
+                    }
+                }
+                size
+            }
+            // Vec<u8> -> Vec<u128>, Vec<i8> -> Vec<i128>, Vec<bool>, Vec<char>
+            _ => {
+                let element_size = self.vec_element_size()?;
+                read_len(data, offset)?
+                    .checked_mul(element_size)?
+                    .checked_add(4)?
+            }
+        };
+
+        if offset.checked_add(size)? > data.len() {
+            return None;
+        }
+        Some(size)
+    }
+
+    /// Whether `value`, a complete encoding of this type, is canonical: bools
+    /// are 0 or 1, chars are Unicode scalar values and strings are UTF-8.
+    pub fn is_canonical(&self, value: &[u8]) -> bool {
+        match self {
+            SchemaDataTypes::Bool => value.iter().all(|b| *b <= 1),
+            SchemaDataTypes::Char => is_char(value),
+            SchemaDataTypes::String => value
+                .get(4..)
+                .is_some_and(|s| core::str::from_utf8(s).is_ok()),
+            SchemaDataTypes::VecBool => value.get(4..).is_some_and(|v| v.iter().all(|b| *b <= 1)),
+            SchemaDataTypes::VecChar => value
+                .get(4..)
+                .is_some_and(|v| v.chunks_exact(4).all(is_char)),
+            SchemaDataTypes::VecString => {
+                let mut offset = 4;
+                while offset < value.len() {
+                    let Some(len) = read_len(value, offset) else {
+                        return false;
+                    };
+                    let Some(s) = value.get(offset + 4..offset + 4 + len) else {
+                        return false;
+                    };
+                    if core::str::from_utf8(s).is_err() {
+                        return false;
+                    }
+                    offset += 4 + len;
+                }
+                true
+            }
+            _ => true,
+        }
+    }
+
+    /// Type of each element of a Vec type, or None for other types.
+    fn vec_element_type(&self) -> Option<SchemaDataTypes> {
+        let element_type = match self {
+            SchemaDataTypes::VecU8 => SchemaDataTypes::U8,
+            SchemaDataTypes::VecU16 => SchemaDataTypes::U16,
+            SchemaDataTypes::VecU32 => SchemaDataTypes::U32,
+            SchemaDataTypes::VecU64 => SchemaDataTypes::U64,
+            SchemaDataTypes::VecU128 => SchemaDataTypes::U128,
+            SchemaDataTypes::VecI8 => SchemaDataTypes::I8,
+            SchemaDataTypes::VecI16 => SchemaDataTypes::I16,
+            SchemaDataTypes::VecI32 => SchemaDataTypes::I32,
+            SchemaDataTypes::VecI64 => SchemaDataTypes::I64,
+            SchemaDataTypes::VecI128 => SchemaDataTypes::I128,
+            SchemaDataTypes::VecBool => SchemaDataTypes::Bool,
+            SchemaDataTypes::VecChar => SchemaDataTypes::Char,
+            SchemaDataTypes::VecString => SchemaDataTypes::String,
+            _ => return None,
+        };
+        Some(element_type)
+    }
+
+    /// Append the display form of `value`, a complete canonical encoding of
+    /// this type, to `out`: integers in decimal, bools as `true` or `false`,
+    /// chars and strings as is, and Vecs as their items separated by commas
+    /// in brackets. Fails if `value` is malformed.
+    pub fn write_display(&self, value: &[u8], out: &mut String) -> Result<(), ProgramError> {
+        self.check_complete(value)?;
+        if let Some(element_type) = self.vec_element_type() {
+            out.push('[');
+            let mut offset = 4;
+            while offset < value.len() {
+                let size = element_type
+                    .size_of(value, offset)
+                    .ok_or(AttestationServiceError::InvalidAttestationData)?;
+                if offset > 4 {
+                    out.push(',');
+                }
+                element_type.write_display(&value[offset..offset + size], out)?;
+                offset += size;
+            }
+            out.push(']');
+            return Ok(());
+        }
+
+        match self {
+            SchemaDataTypes::Bool => out.push_str(if value[0] == 1 { "true" } else { "false" }),
+            SchemaDataTypes::Char => {
+                let code = u32::from_le_bytes([value[0], value[1], value[2], value[3]]);
+                out.push(
+                    char::from_u32(code).ok_or(AttestationServiceError::InvalidAttestationData)?,
+                );
+            }
+            SchemaDataTypes::String => {
+                out.push_str(
+                    core::str::from_utf8(&value[4..])
+                        .map_err(|_| AttestationServiceError::InvalidAttestationData)?,
+                );
+            }
+            _ if self.integer_signedness() == Some(true) => {
+                // Sign extend to 128 bits.
+                let fill = if value[value.len() - 1] & 0x80 != 0 {
+                    0xff
+                } else {
+                    0
+                };
+                let mut bytes = [fill; 16];
+                bytes[..value.len()].copy_from_slice(value);
+                let _ = write!(out, "{}", i128::from_le_bytes(bytes));
+            }
+            _ => {
+                let mut bytes = [0; 16];
+                bytes[..value.len()].copy_from_slice(value);
+                let _ = write!(out, "{}", u128::from_le_bytes(bytes));
+            }
+        }
+        Ok(())
+    }
+
+    /// Fail unless `value` is exactly one encoding of this type.
+    fn check_complete(&self, value: &[u8]) -> Result<(), ProgramError> {
+        if self.size_of(value, 0) != Some(value.len()) {
+            return Err(AttestationServiceError::InvalidAttestationData.into());
+        }
+        Ok(())
+    }
 }
 
-impl From<u8> for SchemaDataTypes {
-    fn from(byte: u8) -> SchemaDataTypes {
-        match byte {
+/// Iterate over the names in serialized Schema `field_names`, stopping at
+/// the first malformed entry.
+pub fn iter_field_names(field_names: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let mut offset = 0;
+    core::iter::from_fn(move || {
+        let name_len = read_len(field_names, offset)?;
+        let name = field_names.get(offset + 4..offset + 4 + name_len)?;
+        offset += 4 + name_len;
+        Some(name)
+    })
+}
+
+/// Whether the 4 byte little-endian value is a Unicode scalar value.
+#[inline]
+fn is_char(value: &[u8]) -> bool {
+    value
+        .try_into()
+        .is_ok_and(|bytes| char::from_u32(u32::from_le_bytes(bytes)).is_some())
+}
+
+/// Read the u32 length prefix at `offset`.
+#[inline]
+fn read_len(data: &[u8], offset: usize) -> Option<usize> {
+    let bytes = data.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_le_bytes(bytes.try_into().unwrap()) as usize)
+}
+
+impl TryFrom<u8> for SchemaDataTypes {
+    type Error = ProgramError;
+
+    fn try_from(byte: u8) -> Result<SchemaDataTypes, ProgramError> {
+        let data_type = match byte {
             0 => SchemaDataTypes::U8,
             1 => SchemaDataTypes::U16,
             2 => SchemaDataTypes::U32,
@@ -74,8 +327,9 @@ impl From<u8> for SchemaDataTypes {
             23 => SchemaDataTypes::VecBool,
             24 => SchemaDataTypes::VecChar,
             25 => SchemaDataTypes::VecString,
-            _ => panic!("Invalid u8 for SchemaDataTypes"),
-        }
+            _ => return Err(AttestationServiceError::InvalidSchemaDataType.into()),
+        };
+        Ok(data_type)
     }
 }
 
@@ -85,6 +339,31 @@ impl From<SchemaDataTypes> for u8 {
     }
 }
 
+/// What happens when an Attestation of a Schema is closed while other
+/// Attestations reference it.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RefClosePolicy {
+    /// The Attestation cannot be closed until no Attestation references it.
+    Block = 0,
+    /// The Attestation is closed, and its close event carries the number of
+    /// Attestations that still reference it so indexers can flag them. The
+    /// default.
+    AllowAndFlag = 1,
+}
+
+impl TryFrom<u8> for RefClosePolicy {
+    type Error = ProgramError;
+
+    fn try_from(byte: u8) -> Result<RefClosePolicy, ProgramError> {
+        match byte {
+            0 => Ok(RefClosePolicy::Block),
+            1 => Ok(RefClosePolicy::AllowAndFlag),
+            _ => Err(AttestationServiceError::InvalidRefClosePolicy.into()),
+        }
+    }
+}
+
 // PDA ["schema", credential, name, version]
 #[derive(Clone, Debug, PartialEq, ShankAccount)]
 #[repr(C)]
@@ -104,10 +383,54 @@ pub struct Schema {
     pub is_paused: bool,
     /// Version of this schema. Defaults to 1.
     pub version: u8,
+    /// Per-field constraints enforced on Attestation data, stored as serialized
+    /// `FieldConstraint` entries. Empty when the Schema has no constraints.
+    pub field_constraints: Vec<u8>,
+    /// Canonical bump of the Schema PDA
+    pub bump: u8,
+    /// How Attestation data is stored, as a `CommitmentScheme`. Attestations
+    /// under a commitment scheme store a commitment instead of the data.
+    pub commitment_scheme: u8,
+    /// Schema that Attestations of this Schema must reference an Attestation
+    /// of. Set to default pubkey when references are optional.
+    pub ref_schema: Pubkey,
+    /// What happens when a referenced Attestation of this Schema is closed,
+    /// as a `RefClosePolicy`.
+    pub ref_close_policy: u8,
+    /// Fee charged for each Attestation of this Schema, in lamports or in
+    /// base units of `fee_mint`. 0 means Attestations are free.
+    pub fee: u64,
+    /// Mint the fee is paid in. Set to default pubkey for lamport fees.
+    pub fee_mint: Pubkey,
+    /// Account the fee is paid to: the Credential's treasury PDA for lamport
+    /// fees, or a token account of `fee_mint` owned by it.
+    pub fee_treasury: Pubkey,
+    /// Program invoked when Attestations of this Schema are created or
+    /// closed, which can reject them. Set to default pubkey when there is none.
+    pub resolver_program: Pubkey,
+    /// Indexes of the fields mirrored into the token metadata of tokenized
+    /// Attestations, in order. Empty when only the expiry and signer are.
+    pub token_metadata_fields: Vec<u8>,
+    /// Token extensions applied to the mints of tokenized Attestations, as
+    /// `TOKEN_POLICY_*` flags. Set when the Schema is tokenized.
+    pub token_policy: u8,
 }
 
+// Layout versions
+// 0 - credential, name, description, layout, field_names, is_paused, version
+// 1 - adds field_constraints and bump
+// 2 - adds commitment_scheme
+// 3 - adds ref_schema and ref_close_policy
+// 4 - adds fee, fee_mint and fee_treasury
+// 5 - adds resolver_program
+// 6 - adds token_metadata_fields
+// 7 - adds token_policy
+
 impl Discriminator for Schema {
     const DISCRIMINATOR: u8 = AttestationAccountDiscriminators::SchemaDiscriminator as u8;
+    const LEGACY_DISCRIMINATOR: Option<u8> =
+        Some(AttestationAccountDiscriminators::LegacySchemaDiscriminator as u8);
+    const LAYOUT_VERSION: u8 = 7;
 }
 
 impl AccountSerialize for Schema {
@@ -124,6 +447,19 @@ impl AccountSerialize for Schema {
         data.extend_from_slice(self.field_names.as_ref());
         data.extend_from_slice(&[self.is_paused as u8]);
         data.extend_from_slice(&[self.version]);
+        data.extend(&(self.field_constraints.len() as u32).to_le_bytes());
+        data.extend_from_slice(self.field_constraints.as_ref());
+        data.push(self.bump);
+        data.push(self.commitment_scheme);
+        data.extend_from_slice(self.ref_schema.as_ref());
+        data.push(self.ref_close_policy);
+        data.extend_from_slice(&self.fee.to_le_bytes());
+        data.extend_from_slice(self.fee_mint.as_ref());
+        data.extend_from_slice(self.fee_treasury.as_ref());
+        data.extend_from_slice(self.resolver_program.as_ref());
+        data.extend(&(self.token_metadata_fields.len() as u32).to_le_bytes());
+        data.extend_from_slice(self.token_metadata_fields.as_ref());
+        data.push(self.token_policy);
 
         data
     }
@@ -131,58 +467,202 @@ impl AccountSerialize for Schema {
 
 impl Schema {
     pub fn validate(&self, field_names_count: u32) -> Result<(), ProgramError> {
+        validate_name(&self.name, MAX_NAME_LEN)?;
+        validate_utf8(&self.description, MAX_DESCRIPTION_LEN)?;
+        CommitmentScheme::try_from(self.commitment_scheme)?;
+        RefClosePolicy::try_from(self.ref_close_policy)?;
+
         for data_type in &self.layout {
-            if data_type > &SchemaDataTypes::max() {
-                return Err(AttestationServiceError::InvalidSchemaDataType.into());
-            }
+            SchemaDataTypes::try_from(*data_type)?;
         }
 
         // Expect number of field names to match number of fields in layout.
-        if field_names_count != u32::try_from(self.layout.len()).unwrap() {
+        if field_names_count as usize != self.layout.len() {
             log!("Field names does not match layout length");
             return Err(AttestationServiceError::InvalidSchema.into());
         }
-        Ok(())
+
+        self.validate_field_names()?;
+        self.validate_field_constraints()
     }
 
-    pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
-        // Check discriminator
-        if data[0] != Self::DISCRIMINATOR {
-            msg!("Invalid Schema Data");
-            return Err(ProgramError::InvalidAccountData);
+    /// Validate each field name is non-empty, UTF-8 encoded and unique.
+    pub fn validate_field_names(&self) -> Result<(), ProgramError> {
+        let mut names: Vec<&[u8]> = Vec::with_capacity(self.layout.len());
+        let mut offset = 0;
+        while offset < self.field_names.len() {
+            let name_len = read_len(&self.field_names, offset)
+                .ok_or(AttestationServiceError::InvalidSchema)?;
+            let name = self
+                .field_names
+                .get(offset + 4..offset + 4 + name_len)
+                .ok_or(AttestationServiceError::InvalidSchema)?;
+            validate_name(name, MAX_FIELD_NAME_LEN).inspect_err(|_| {
+                log!("Invalid field name at index {}", names.len());
+            })?;
+            if names.contains(&name) {
+                log!("Duplicate field name at index {}", names.len());
+                return Err(AttestationServiceError::DuplicateFieldName.into());
+            }
+            names.push(name);
+            offset += 4 + name_len;
         }
+        Ok(())
+    }
 
-        // Start offset after Discriminator
-        let mut offset: usize = 1;
+    /// Validate each field constraint references a field in the layout and
+    /// can be applied to that field's type.
+    pub fn validate_field_constraints(&self) -> Result<(), ProgramError> {
+        // Commitments computed by the client hide the data, so constraints
+        // could never be checked.
+        if !CommitmentScheme::try_from(self.commitment_scheme)?.validates_data()
+            && !self.field_constraints.is_empty()
+        {
+            log!("Field constraints are not supported with a commitment scheme");
+            return Err(AttestationServiceError::InvalidFieldConstraint.into());
+        }
+        for entry in FieldConstraints::new(&self.field_constraints) {
+            let (field_index, constraint) = entry?;
+            let data_type = SchemaDataTypes::try_from(
+                *self
+                    .layout
+                    .get(field_index as usize)
+                    .ok_or(AttestationServiceError::InvalidFieldConstraint)?,
+            )?;
+            constraint.validate(&data_type).inspect_err(|_| {
+                log!("Invalid constraint for field {}", field_index);
+            })?;
+        }
+        Ok(())
+    }
 
-        let credential: Pubkey = data[offset..offset + 32].try_into().unwrap();
-        offset += 32;
+    /// Validate each token metadata field is a field of a Schema storing
+    /// plaintext data, is mirrored once and is not named after a key every
+    /// tokenized Attestation has.
+    pub fn validate_token_metadata_fields(&self) -> Result<(), ProgramError> {
+        if self.token_metadata_fields.is_empty() {
+            return Ok(());
+        }
+        // Commitments do not reveal the field values.
+        if !matches!(
+            CommitmentScheme::try_from(self.commitment_scheme)?,
+            CommitmentScheme::None
+        ) {
+            log!("Token metadata fields are not supported with a commitment scheme");
+            return Err(AttestationServiceError::InvalidTokenMetadataField.into());
+        }
+        let names: Vec<&[u8]> = iter_field_names(&self.field_names).collect();
+        for (i, field_index) in self.token_metadata_fields.iter().enumerate() {
+            let name = names
+                .get(*field_index as usize)
+                .ok_or(AttestationServiceError::InvalidTokenMetadataField)?;
+            if self.token_metadata_fields[..i].contains(field_index)
+                || RESERVED_METADATA_KEYS
+                    .iter()
+                    .any(|key| key.as_bytes().eq(*name))
+            {
+                log!("Invalid token metadata field {}", field_index);
+                return Err(AttestationServiceError::InvalidTokenMetadataField.into());
+            }
+        }
+        Ok(())
+    }
 
-        let name_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-        offset += 4;
-        let name = data[offset..offset + name_len].to_vec();
-        offset += name_len;
+    /// Validate the token policy only sets known flags.
+    pub fn validate_token_policy(&self) -> Result<(), ProgramError> {
+        let flags = TOKEN_POLICY_NON_TRANSFERABLE
+            | TOKEN_POLICY_PERMANENT_DELEGATE
+            | TOKEN_POLICY_FREEZE_ON_EXPIRY;
+        if self.token_policy & !flags != 0 {
+            return Err(AttestationServiceError::InvalidTokenPolicy.into());
+        }
+        Ok(())
+    }
 
-        let desc_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-        offset += 4;
-        let description = data[offset..offset + desc_len].to_vec();
-        offset += desc_len;
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        SchemaRef::try_from_bytes(data).map(Self::from)
+    }
+}
 
-        let layout_len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-        offset += 4;
-        let layout = data[offset..offset + layout_len].to_vec();
-        offset += layout_len;
+/// Borrowed view of Schema account data that reads fields in place.
+pub struct SchemaRef<'a> {
+    pub credential: &'a Pubkey,
+    pub name: &'a [u8],
+    pub description: &'a [u8],
+    pub layout: &'a [u8],
+    pub field_names: &'a [u8],
+    pub is_paused: bool,
+    pub version: u8,
+    pub field_constraints: &'a [u8],
+    /// Zero for legacy accounts that have not been migrated.
+    pub bump: u8,
+    pub commitment_scheme: u8,
+    pub ref_schema: &'a Pubkey,
+    pub ref_close_policy: u8,
+    pub fee: u64,
+    pub fee_mint: &'a Pubkey,
+    pub fee_treasury: &'a Pubkey,
+    pub resolver_program: &'a Pubkey,
+    pub token_metadata_fields: &'a [u8],
+    pub token_policy: u8,
+}
 
-        let field_names_byte_len =
-            u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
-        offset += 4;
-        let field_names: Vec<u8> = data[offset..offset + field_names_byte_len].to_vec();
-        offset += field_names_byte_len;
+impl<'a> SchemaRef<'a> {
+    pub fn try_from_bytes(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let mut reader = DataReader::new(data);
 
-        let is_paused = data[offset] == 1;
-        offset += 1;
+        // Check discriminator and layout version
+        let Some(layout_version) = read_layout_version::<Schema>(&mut reader)? else {
+            msg!("Invalid Schema Data");
+            return Err(ProgramError::InvalidAccountData);
+        };
 
-        let version = data[offset];
+        let credential = reader.read_pubkey_ref()?;
+        let name = reader.read_vec()?;
+        let description = reader.read_vec()?;
+        let layout = reader.read_vec()?;
+        let field_names = reader.read_vec()?;
+        let is_paused = reader.read_u8()? == 1;
+        let version = reader.read_u8()?;
+        let (field_constraints, bump) = if layout_version >= 1 {
+            (reader.read_vec()?, reader.read_u8()?)
+        } else {
+            (&[][..], 0)
+        };
+        let commitment_scheme = if layout_version >= 2 {
+            reader.read_u8()?
+        } else {
+            CommitmentScheme::None as u8
+        };
+        let (ref_schema, ref_close_policy) = if layout_version >= 3 {
+            (reader.read_pubkey_ref()?, reader.read_u8()?)
+        } else {
+            (&NO_REF_SCHEMA, RefClosePolicy::AllowAndFlag as u8)
+        };
+        let (fee, fee_mint, fee_treasury) = if layout_version >= 4 {
+            (
+                reader.read_u64()?,
+                reader.read_pubkey_ref()?,
+                reader.read_pubkey_ref()?,
+            )
+        } else {
+            (0, &NO_FEE_MINT, &[0; 32])
+        };
+        let resolver_program = if layout_version >= 5 {
+            reader.read_pubkey_ref()?
+        } else {
+            &NO_RESOLVER_PROGRAM
+        };
+        let token_metadata_fields = if layout_version >= 6 {
+            reader.read_vec()?
+        } else {
+            &[]
+        };
+        let token_policy = if layout_version >= 7 {
+            reader.read_u8()?
+        } else {
+            DEFAULT_TOKEN_POLICY
+        };
 
         Ok(Self {
             credential,
@@ -192,6 +672,42 @@ impl Schema {
             field_names,
             is_paused,
             version,
+            field_constraints,
+            bump,
+            commitment_scheme,
+            ref_schema,
+            ref_close_policy,
+            fee,
+            fee_mint,
+            fee_treasury,
+            resolver_program,
+            token_metadata_fields,
+            token_policy,
         })
     }
 }
+
+impl From<SchemaRef<'_>> for Schema {
+    fn from(schema: SchemaRef<'_>) -> Self {
+        Self {
+            credential: *schema.credential,
+            name: schema.name.to_vec(),
+            description: schema.description.to_vec(),
+            layout: schema.layout.to_vec(),
+            field_names: schema.field_names.to_vec(),
+            is_paused: schema.is_paused,
+            version: schema.version,
+            field_constraints: schema.field_constraints.to_vec(),
+            bump: schema.bump,
+            commitment_scheme: schema.commitment_scheme,
+            ref_schema: *schema.ref_schema,
+            ref_close_policy: schema.ref_close_policy,
+            fee: schema.fee,
+            fee_mint: *schema.fee_mint,
+            fee_treasury: *schema.fee_treasury,
+            resolver_program: *schema.resolver_program,
+            token_metadata_fields: schema.token_metadata_fields.to_vec(),
+            token_policy: schema.token_policy,
+        }
+    }
+}
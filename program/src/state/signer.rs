@@ -0,0 +1,34 @@
+use pinocchio::pubkey::Pubkey;
+use solana_sha256_hasher::hashv;
+
+/// Zero bytes before a 20 byte Ethereum address stored as a Pubkey, matching
+/// how EVM contracts store addresses in a `bytes32`.
+pub const ETHEREUM_ADDRESS_PADDING: usize = 12;
+
+/// Length of a compressed secp256r1 public key.
+pub const PASSKEY_LEN: usize = 33;
+
+/// Kind of key that approved an Attestation.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignerKind {
+    /// Solana ed25519 key, signing the transaction or an attestation message.
+    Ed25519 = 0,
+    /// Ethereum address, signing an EIP-191 attestation message with secp256k1.
+    Secp256k1 = 1,
+    /// Passkey, signing a WebAuthn assertion with secp256r1. Stored as
+    /// [`passkey_signer`] of its public key.
+    Secp256r1 = 2,
+}
+
+/// Ethereum address stored in `signer`, if it is a zero-padded 20 byte address.
+pub fn ethereum_address(signer: &Pubkey) -> Option<&[u8]> {
+    let (padding, address) = signer.split_at(ETHEREUM_ADDRESS_PADDING);
+    padding.iter().all(|byte| *byte == 0).then_some(address)
+}
+
+/// Signer of Attestations approved with the passkey `public_key`, the sha256
+/// hash of the compressed key, which does not fit in a Pubkey.
+pub fn passkey_signer(public_key: &[u8]) -> Pubkey {
+    hashv(&[public_key]).to_bytes()
+}
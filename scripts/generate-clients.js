@@ -51,7 +51,7 @@ function preserveConfigFiles() {
 const sasCodama = codama.createFromRoot(anchorIdl.rootNodeFromAnchor(sasIdl));
 sasCodama.update(
   codama.bottomUpTransformerVisitor([
-    // add 1 byte discriminator
+    // add account header: 1 byte discriminator and 1 byte layout version
     {
       select: "[accountNode]",
       transform: (node) => {
@@ -66,6 +66,10 @@ sasCodama.update(
                 name: "discriminator",
                 type: codama.numberTypeNode("u8"),
               }),
+              codama.structFieldTypeNode({
+                name: "layoutVersion",
+                type: codama.numberTypeNode("u8"),
+              }),
               ...node.data.fields,
             ],
           },
@@ -96,6 +100,62 @@ sasCodama.update(
   ]),
 );
 
+sasCodama.update(
+  codama.updateInstructionsVisitor({
+    // Authorized signers are ed25519 keys unless their kinds are set.
+    createCredential: {
+      arguments: {
+        signerKinds: { defaultValue: codama.bytesValueNode("base16", "") },
+      },
+    },
+    changeAuthorizedSigners: {
+      arguments: {
+        signerKinds: { defaultValue: codama.bytesValueNode("base16", "") },
+      },
+    },
+    createSchema: {
+      arguments: {
+        // Schemas store plaintext Attestation data unless a scheme is chosen.
+        commitmentScheme: { defaultValue: codama.numberValueNode(0) },
+        // References are optional and do not block closing unless configured.
+        refSchema: {
+          defaultValue: codama.publicKeyValueNode(
+            "11111111111111111111111111111111",
+          ),
+        },
+        refClosePolicy: { defaultValue: codama.numberValueNode(1) },
+        // Schemas have no resolver unless one is set.
+        resolverProgram: {
+          defaultValue: codama.publicKeyValueNode(
+            "11111111111111111111111111111111",
+          ),
+        },
+        // Schemas have no field constraints unless they are set.
+        fieldConstraints: { defaultValue: codama.bytesValueNode("base16", "") },
+      },
+    },
+    changeSchemaVersion: {
+      arguments: {
+        // New versions have no field constraints unless they are set.
+        fieldConstraints: { defaultValue: codama.bytesValueNode("base16", "") },
+      },
+    },
+    tokenizeSchema: {
+      arguments: {
+        // Tokens are non-transferable with the program as permanent delegate
+        // unless a policy is chosen.
+        tokenPolicy: { defaultValue: codama.numberValueNode(3) },
+      },
+    },
+    createTokenizedAttestation: {
+      arguments: {
+        // Ignored by the program, which computes the mint space.
+        mintAccountSpace: { defaultValue: codama.numberValueNode(0) },
+      },
+    },
+  }),
+);
+
 const configPreserver = preserveConfigFiles();
 
 sasCodama.accept(